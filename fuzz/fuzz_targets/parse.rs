@@ -0,0 +1,25 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use torrent_title_parser::Parser;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(title) = std::str::from_utf8(data) else {
+        return;
+    };
+    let parser = Parser::default();
+    let parsed = parser.parse(title);
+    let traced = parser.parse_traced(title);
+    assert_eq!(parsed.ok(), traced.ok().map(|(parsed, _)| parsed));
+
+    let Ok(detailed) = parser.parse_detailed(title) else {
+        return;
+    };
+    for span in detailed.spans.iter().map(|span| &span.span).chain([&detailed.title_span]) {
+        assert!(
+            title.get(span.clone()).is_some(),
+            "span {:?} doesn't lie on char boundaries of the input",
+            span
+        );
+    }
+});
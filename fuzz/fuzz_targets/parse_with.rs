@@ -0,0 +1,31 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use torrent_title_parser::{ParseOptions, Parser};
+
+const FIELDS: [&str; 8] = [
+    "resolution",
+    "year",
+    "seasons",
+    "episodes",
+    "languages",
+    "group",
+    "quality",
+    "volumes",
+];
+
+fuzz_target!(|data: &[u8]| {
+    // the first byte picks the requested fields
+    let Some((&mask, rest)) = data.split_first() else {
+        return;
+    };
+    let Ok(title) = std::str::from_utf8(rest) else {
+        return;
+    };
+    let fields = FIELDS
+        .iter()
+        .enumerate()
+        .filter(|(bit, _)| mask & (1 << bit) != 0)
+        .map(|(_, field)| *field);
+    let _ = Parser::default().parse_with(title, &ParseOptions::new().fields(fields));
+});
@@ -0,0 +1,196 @@
+use std::io::{self, BufRead, BufWriter, Write};
+use std::process::ExitCode;
+
+use clap::{Parser as ClapParser, Subcommand, ValueEnum};
+use serde_json::{Map, Value};
+use torrent_title_parser::{parse_title, Parser};
+
+const DEFAULT_COLUMNS: &str = "title,year,resolution,quality,codec,seasons,episodes,group";
+const DEFAULT_RULE_COLUMNS: &str = "order,name,kind,pattern,flags,transform";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    /// One JSON object per line
+    Json,
+    /// Tab separated values with the selected columns
+    Tsv,
+    /// Human readable list of all extracted fields
+    Table,
+}
+
+/// Parse torrent titles passed as arguments or one per line on stdin
+#[derive(Debug, ClapParser)]
+#[command(name = "ttp", version, about, args_conflicts_with_subcommands = true)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Titles to parse (reads stdin if none are given)
+    titles: Vec<String>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Json, global = true)]
+    format: OutputFormat,
+
+    /// Comma separated fields to output (tsv and table only)
+    #[arg(short, long, value_delimiter = ',', global = true)]
+    columns: Vec<String>,
+
+    /// Print a header line with the column names (tsv only)
+    #[arg(long, global = true)]
+    header: bool,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Print the built-in handlers in the order they run: name, regex, flags, transform and options
+    Rules,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    match args.command {
+        Some(Command::Rules) => print_rules(&args),
+        None => parse_titles(&args),
+    }
+}
+
+fn print_rules(args: &Args) -> ExitCode {
+    let columns = if args.columns.is_empty() && args.format == OutputFormat::Tsv {
+        DEFAULT_RULE_COLUMNS.split(',').map(str::to_string).collect()
+    } else {
+        args.columns.clone()
+    };
+
+    let mut out = BufWriter::new(io::stdout().lock());
+    if args.header && args.format == OutputFormat::Tsv {
+        let _ = writeln!(out, "{}", columns.join("\t"));
+    }
+
+    for info in Parser::default().handler_infos() {
+        let label = format!("{} {}", info.order, info.name);
+        let Value::Object(mut fields) = serde_json::to_value(&info).expect("HandlerInfo is always serializable") else {
+            unreachable!("HandlerInfo serializes to an object");
+        };
+        if let (Some(transform), false) = (&info.transform, args.format == OutputFormat::Json) {
+            fields.insert("transform".to_string(), Value::String(transform.to_string()));
+        }
+
+        let written = match args.format {
+            OutputFormat::Json => writeln!(out, "{}", Value::Object(fields)),
+            OutputFormat::Tsv => write_tsv_row(&mut out, &fields, &columns),
+            OutputFormat::Table => write_table(&mut out, &label, &fields, &columns),
+        };
+        if written.is_err() {
+            break;
+        }
+    }
+
+    if out.flush().is_err() {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn parse_titles(args: &Args) -> ExitCode {
+    let titles: Box<dyn Iterator<Item = io::Result<String>>> = if args.titles.is_empty() {
+        Box::new(io::stdin().lock().lines())
+    } else {
+        Box::new(args.titles.clone().into_iter().map(Ok))
+    };
+
+    let columns = if args.columns.is_empty() && args.format == OutputFormat::Tsv {
+        DEFAULT_COLUMNS.split(',').map(str::to_string).collect()
+    } else {
+        args.columns.clone()
+    };
+
+    let mut out = BufWriter::new(io::stdout().lock());
+    let mut failed = false;
+
+    if args.header && args.format == OutputFormat::Tsv {
+        let _ = writeln!(out, "{}", columns.join("\t"));
+    }
+
+    for raw_title in titles {
+        let raw_title = match raw_title {
+            Ok(raw_title) => raw_title,
+            Err(err) => {
+                eprintln!("ttp: failed to read input: {}", err);
+                return ExitCode::FAILURE;
+            }
+        };
+        let raw_title = raw_title.trim();
+        if raw_title.is_empty() {
+            continue;
+        }
+
+        let parsed = match parse_title(raw_title) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                eprintln!("ttp: {}: {}", raw_title, err);
+                failed = true;
+                continue;
+            }
+        };
+        let Value::Object(fields) = serde_json::to_value(&parsed).expect("ParsedTitle is always serializable") else {
+            unreachable!("ParsedTitle serializes to an object");
+        };
+
+        let written = match args.format {
+            OutputFormat::Json => writeln!(out, "{}", Value::Object(fields)),
+            OutputFormat::Tsv => write_tsv_row(&mut out, &fields, &columns),
+            OutputFormat::Table => write_table(&mut out, raw_title, &fields, &columns),
+        };
+        if written.is_err() {
+            // stdout was closed (e.g. piped into `head`)
+            break;
+        }
+    }
+
+    if out.flush().is_err() || failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn format_value(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Array(values)) => values.iter().map(|v| format_value(Some(v))).collect::<Vec<_>>().join(","),
+        Some(value) => value.to_string(),
+    }
+}
+
+fn write_tsv_row(out: &mut impl Write, fields: &Map<String, Value>, columns: &[String]) -> io::Result<()> {
+    let row: Vec<String> = columns
+        .iter()
+        .map(|column| format_value(fields.get(column)).replace(['\t', '\n'], " "))
+        .collect();
+    writeln!(out, "{}", row.join("\t"))
+}
+
+fn write_table(out: &mut impl Write, raw_title: &str, fields: &Map<String, Value>, columns: &[String]) -> io::Result<()> {
+    let rows: Vec<(&str, String)> = if columns.is_empty() {
+        fields
+            .iter()
+            .map(|(key, value)| (key.as_str(), format_value(Some(value))))
+            .collect()
+    } else {
+        columns
+            .iter()
+            .map(|column| (column.as_str(), format_value(fields.get(column))))
+            .collect()
+    };
+    let width = rows.iter().map(|(key, _)| key.len()).max().unwrap_or(0);
+
+    writeln!(out, "{}", raw_title)?;
+    for (key, value) in rows {
+        writeln!(out, "  {:width$}  {}", key, value, width = width)?;
+    }
+    writeln!(out)
+}
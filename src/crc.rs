@@ -0,0 +1,67 @@
+//! Checking local files against the CRC32 in their release name.
+
+use std::fs::File;
+use std::io::{self, BufReader, Read};
+use std::path::Path;
+
+use crate::{Crc32, ParsedTitle};
+
+/// Lookup table for the reflected IEEE polynomial used by zip, PNG and fansub checksums
+const TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+impl Crc32 {
+    /// CRC32 of a byte slice
+    pub fn of_bytes(bytes: &[u8]) -> Crc32 {
+        Crc32(!update(!0, bytes))
+    }
+
+    /// CRC32 of everything the reader yields, read in 64 KiB chunks
+    pub fn of_reader(mut reader: impl Read) -> io::Result<Crc32> {
+        let mut crc = !0;
+        let mut buffer = [0u8; 64 * 1024];
+        loop {
+            match reader.read(&mut buffer) {
+                Ok(0) => return Ok(Crc32(!crc)),
+                Ok(read) => crc = update(crc, &buffer[..read]),
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// CRC32 of the file at `path`
+    pub fn of_file(path: impl AsRef<Path>) -> io::Result<Crc32> {
+        Crc32::of_reader(BufReader::new(File::open(path)?))
+    }
+}
+
+impl ParsedTitle {
+    /// Compare the file's CRC32 with the parsed checksum, `None` if the title had none
+    pub fn verify_checksum(&self, path: impl AsRef<Path>) -> io::Result<Option<bool>> {
+        let Some(expected) = self.checksum else {
+            return Ok(None);
+        };
+        Ok(Some(Crc32::of_file(path)? == expected))
+    }
+}
+
+fn update(mut crc: u32, bytes: &[u8]) -> u32 {
+    for &byte in bytes {
+        crc = TABLE[((crc ^ byte as u32) & 0xFF) as usize] ^ (crc >> 8);
+    }
+    crc
+}
@@ -1 +1,2 @@
 pub mod regex;
+pub mod text;
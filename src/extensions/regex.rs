@@ -107,14 +107,10 @@ impl<'a> StringMatch<'a> {
     }
 
     pub fn group(&self, idx: usize) -> Option<GroupMatch<'a>> {
-        if let Some(group_range) = self.safe_group(idx) {
-            Some(GroupMatch {
-                range: group_range,
-                full_input: self.full_input,
-            })
-        } else {
-            None
-        }
+        self.safe_group(idx).map(|group_range| GroupMatch {
+            range: group_range,
+            full_input: self.full_input,
+        })
     }
 
     #[inline]
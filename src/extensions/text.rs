@@ -0,0 +1,56 @@
+/// Largest char boundary of `s` at or before `index`, so positions computed by handlers can be used to slice safely
+pub fn floor_char_boundary(s: &str, index: usize) -> usize {
+    let mut index = index.min(s.len());
+    while !s.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+/// Smallest char boundary of `s` at or after `index` (the length of `s` past its end)
+pub fn ceil_char_boundary(s: &str, index: usize) -> usize {
+    let mut index = index.min(s.len());
+    while !s.is_char_boundary(index) {
+        index += 1;
+    }
+    index
+}
+
+/// Decode `%XX` escapes (e.g. in a magnet link), invalid escapes are kept as they are
+pub fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let escaped = bytes
+            .get(index + 1..index + 3)
+            .filter(|hex| bytes[index] == b'%' && hex.iter().all(u8::is_ascii_hexdigit))
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                index += 3;
+            }
+            None => {
+                decoded.push(bytes[index]);
+                index += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::percent_decode;
+
+    #[test]
+    fn test_percent_decode_needs_two_hex_digits() {
+        assert_eq!(percent_decode("100%25%20Beef"), "100% Beef");
+        // "+A" parses as a hex number, but isn't an escape
+        assert_eq!(percent_decode("%+A"), "%+A");
+        assert_eq!(percent_decode("%G1"), "%G1");
+        assert_eq!(percent_decode("50%"), "50%");
+    }
+}
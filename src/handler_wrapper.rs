@@ -1,23 +1,56 @@
 use std::collections::HashMap;
+use std::ops::Range;
 
 use lazy_static::lazy_static;
-use regress::Regex;
+use regress::{Flags, Regex};
 
-use crate::{extensions::regex::RegexStringExt, Codec, Network, ParsedTitle, Quality};
+use crate::trace::SkipReason;
+use crate::transforms::{Transform, TransformInfo};
+use crate::{
+    extensions::regex::RegexStringExt, Codec, Confidence, Crc32, FieldValue, Network, ParsedTitle, Quality, RemakeCountry, Resolution,
+};
 
+/// A match recorded by a handler, keyed by handler name in [`HandlerContext::matched`]
 #[derive(Debug)]
 pub struct Match {
     pub raw_match: String,
+    /// Byte index in the working title, moved along when the parser cuts text out in front of it
     pub match_index: usize,
 }
 
+/// State passed to a handler: the current (possibly already shortened) title and the result built so far
 pub struct HandlerContext<'a> {
     pub title: &'a str,
     pub result: &'a mut ParsedTitle,
     pub matched: &'a mut HashMap<String, Match>,
-    // end_of_title: &'a mut usize,
+    pub(crate) skipped: &'a mut Option<SkipReason>,
+    /// False if the prefilter found none of the literals the handler's regex requires
+    pub(crate) can_match: bool,
+    /// Byte index in `title` where the title ends according to the handlers that ran so far.
+    /// Custom handlers that read it should be built with [`Handler::with_end_of_title_dependency`].
+    pub end_of_title: usize,
+    /// Keys set with [`HandlerContext::set_extra`] and the part of `title` they were taken from
+    pub(crate) extra_spans: &'a mut Vec<(String, Range<usize>)>,
 }
 
+impl HandlerContext<'_> {
+    /// Record why the handler didn't match although its pattern was found, shown in [`crate::Parser::parse_traced`]
+    pub fn report_skip(&mut self, reason: SkipReason) {
+        *self.skipped = Some(reason);
+    }
+
+    /// Store a value for a field that [`ParsedTitle`] has no member for, in [`ParsedTitle::extra`].
+    ///
+    /// `source` is the byte range in `title` the value was taken from, it shows up in the detailed spans under `key`.
+    pub fn set_extra(&mut self, key: impl Into<String>, value: impl Into<FieldValue>, source: Range<usize>) {
+        let key = key.into();
+        self.result.extra.insert(key.clone(), value.into());
+        self.extra_spans.retain(|(existing, _)| *existing != key);
+        self.extra_spans.push((key, source));
+    }
+}
+
+/// Returned by a handler that matched, telling the parser where the match is and what to do with it
 #[derive(Debug)]
 pub struct HandlerResult {
     pub raw_match: String,
@@ -26,10 +59,16 @@ pub struct HandlerResult {
     pub skip_from_title: bool,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct RegexHandlerOptions {
+    /// Don't run if the field already has a value
     pub skip_if_already_found: bool,
+    /// Don't move the end of the title to this match
     pub skip_from_title: bool,
+    /// Ignore the match if it comes before all other matches
     pub skip_if_first: bool,
+    /// Remove the match from the title for following handlers
     pub remove: bool,
 }
 
@@ -44,39 +83,155 @@ impl Default for RegexHandlerOptions {
     }
 }
 
+/// Compiled regex of a handler together with the pattern it was built from
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    regex: Regex,
+    source: Option<String>,
+    case_insensitive: bool,
+}
+
+impl Pattern {
+    pub fn new(source: &str) -> Result<Self, regress::Error> {
+        Self::with_case_insensitive(source, false)
+    }
+
+    pub fn case_insensitive(source: &str) -> Result<Self, regress::Error> {
+        Self::with_case_insensitive(source, true)
+    }
+
+    pub fn with_case_insensitive(source: &str, case_insensitive: bool) -> Result<Self, regress::Error> {
+        let flags = Flags {
+            icase: case_insensitive,
+            ..Default::default()
+        };
+        Ok(Pattern {
+            regex: Regex::with_flags(source, flags)?,
+            source: Some(source.to_string()),
+            case_insensitive,
+        })
+    }
+
+    pub fn regex(&self) -> &Regex {
+        &self.regex
+    }
+
+    /// The pattern source, unknown if the handler was created from an already compiled [`Regex`]
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
+    pub fn is_case_insensitive(&self) -> bool {
+        self.case_insensitive
+    }
+}
+
+impl From<Regex> for Pattern {
+    fn from(regex: Regex) -> Self {
+        Pattern {
+            regex,
+            source: None,
+            case_insensitive: false,
+        }
+    }
+}
+
+/// Description of a handler as data, see [`crate::Parser::handler_infos`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct HandlerInfo {
+    /// Position in the parser, handlers run in this order
+    pub order: usize,
+    pub name: String,
+    /// "regex" for handlers created with [`Handler::from_regex`], "custom" for everything else
+    pub kind: &'static str,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub pattern: Option<String>,
+    /// Regex flags in ECMAScript notation ("i" for case-insensitive)
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub flags: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub transform: Option<TransformInfo>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub options: Option<RegexHandlerOptions>,
+    pub confidence: Confidence,
+}
+
+/// Regex specific part of a handler, kept to describe it as data
+struct RegexDescription {
+    source: Option<String>,
+    case_insensitive: bool,
+    transform: TransformInfo,
+    options: RegexHandlerOptions,
+}
+
 lazy_static! {
     static ref BEFORE_TITLE_MATCH_REGEX: Regex = Regex::new(r"^\[(.*?)\]").unwrap();
 }
 
 type HandlerFn = dyn Fn(HandlerContext) -> Option<HandlerResult> + Send + Sync;
 
+/// A named parsing rule that can be added to a [`crate::Parser`]
 pub struct Handler {
     name: String,
     handler: Box<HandlerFn>,
+    confidence: Confidence,
+    regex: Option<RegexDescription>,
+    dependencies: Option<Vec<String>>,
+    reads_end_of_title: bool,
 }
 
 impl Handler {
-    pub fn new_old(name: String, handler: Box<HandlerFn>) -> Self {
-        Handler { name, handler }
-    }
-
     pub fn new<F>(name: &str, handler: F) -> Self
     where
         F: Fn(HandlerContext) -> Option<HandlerResult> + Send + Sync + 'static,
     {
-        Handler::new_old(name.to_string(), Box::new(handler))
+        Handler {
+            name: name.to_string(),
+            handler: Box::new(handler),
+            confidence: Confidence::Exact,
+            regex: None,
+            dependencies: None,
+            reads_end_of_title: false,
+        }
     }
 
-    pub fn from_regex<T: PropertyIsSet + TrimIfString>(
-        name: &'static str,
+    pub fn from_regex<T>(
+        name: &str,
         accessor: impl Fn(&mut ParsedTitle) -> &mut T + Send + Sync + 'static,
-        regex: Regex,
-        transform: impl Fn(&str, &T) -> Option<T> + Send + Sync + 'static,
+        regex: impl Into<Pattern>,
+        transform: impl Into<Transform<T>>,
         options: RegexHandlerOptions,
-    ) -> Self {
-        let handler = Box::new(move |context: HandlerContext| {
+    ) -> Self
+    where
+        T: PropertyIsSet + TrimIfString + 'static,
+    {
+        let pattern = regex.into();
+        let transform = transform.into();
+        let description = RegexDescription {
+            source: pattern.source,
+            case_insensitive: pattern.case_insensitive,
+            transform: transform.info().clone(),
+            options,
+        };
+        let regex = pattern.regex;
+
+        // matches that only count if they aren't the first one are a guess by definition
+        let confidence = if options.skip_if_first {
+            Confidence::Heuristic
+        } else {
+            Confidence::Exact
+        };
+
+        let field_name = name.to_string();
+        let handler = move |context: HandlerContext| {
             let field = accessor(context.result);
             if field.is_set() && options.skip_if_already_found {
+                *context.skipped = Some(SkipReason::AlreadyFound);
+                return None;
+            }
+
+            if !context.can_match {
                 return None;
             }
 
@@ -84,7 +239,8 @@ impl Handler {
                 let raw_match = m.as_str(); // will always succeed (as it is equal to whole match)
                 let clean_match = m.group(1).map(|m| m.as_str()).unwrap_or(raw_match);
 
-                let Some(transformed) = transform(clean_match, field) else {
+                let Some(transformed) = transform.apply(clean_match, field) else {
+                    *context.skipped = Some(SkipReason::TransformRejected);
                     return None;
                 };
 
@@ -98,17 +254,13 @@ impl Handler {
                     false
                 };
 
-                let other_matches = context
-                    .matched
-                    .iter()
-                    .filter(|(k, _)| k.as_str() != name)
-                    .collect::<HashMap<_, _>>();
+                let other_matches = context.matched.iter().filter(|(k, _)| **k != field_name).collect::<HashMap<_, _>>();
                 let is_skip_if_first =
                     options.skip_if_first && !other_matches.is_empty() && other_matches.iter().all(|(_, v)| m.start() < v.match_index);
 
                 if !is_skip_if_first {
                     context.matched.insert(
-                        name.to_string(),
+                        field_name.clone(),
                         Match {
                             raw_match: raw_match.to_string(),
                             match_index: m.start(),
@@ -117,27 +269,100 @@ impl Handler {
                     // set the extracted data
                     *field = transformed;
 
-                    return Some(HandlerResult {
+                    Some(HandlerResult {
                         raw_match: raw_match.to_string(),
                         match_index: m.start(),
                         remove: options.remove,
                         skip_from_title: is_before_title || options.skip_from_title,
-                    });
+                    })
                 } else {
+                    *context.skipped = Some(SkipReason::SkipIfFirst);
                     None
                 }
             } else {
                 None
             }
-        });
+        };
+
+        let mut handler = Self::new(name, handler).with_confidence(confidence);
+        handler.regex = Some(description);
+        handler
+    }
 
-        Self::new(&name, handler)
+    /// Set how reliable the matches of this handler are (defaults to [`Confidence::Exact`])
+    pub fn with_confidence(mut self, confidence: Confidence) -> Self {
+        self.confidence = confidence;
+        self
+    }
+
+    /// Declare the names of the handlers whose entries in [`HandlerContext::matched`] a custom handler reads.
+    ///
+    /// Lets [`crate::ParseOptions::fields`] skip unrelated handlers. Without it a custom handler is assumed to read
+    /// all matches. Regex handlers know their dependencies already.
+    pub fn with_dependencies(mut self, names: &[&str]) -> Self {
+        self.dependencies = Some(names.iter().map(|name| name.to_string()).collect());
+        self
+    }
+
+    /// Declare that a custom handler reads [`HandlerContext::end_of_title`].
+    ///
+    /// With [`crate::ParseOptions::fields`] every earlier handler that can move the end of the title then runs too.
+    pub fn with_end_of_title_dependency(mut self) -> Self {
+        self.reads_end_of_title = true;
+        self
     }
 
     pub fn get_name(&self) -> &str {
         &self.name
     }
 
+    pub fn get_confidence(&self) -> Confidence {
+        self.confidence
+    }
+
+    /// Whether the handler may cut text out of the title (custom handlers always may)
+    pub(crate) fn may_remove_text(&self) -> bool {
+        self.regex.as_ref().is_none_or(|regex| regex.options.remove)
+    }
+
+    /// Whether a match can move [`HandlerContext::end_of_title`]
+    pub(crate) fn may_cut_title(&self) -> bool {
+        self.regex.as_ref().is_none_or(|regex| !regex.options.skip_from_title)
+    }
+
+    pub(crate) fn reads_end_of_title(&self) -> bool {
+        self.reads_end_of_title
+    }
+
+    /// Source of the regex, if the handler was created from a [`Pattern`] that has one
+    pub(crate) fn pattern_source(&self) -> Option<&str> {
+        self.regex.as_ref()?.source.as_deref()
+    }
+
+    /// Names of the handlers whose matches this handler reads, `None` if it may read any of them
+    pub(crate) fn reads_matches(&self) -> Option<&[String]> {
+        match &self.regex {
+            Some(regex) if regex.options.skip_if_first => None,
+            Some(_) => Some(&[]),
+            None => self.dependencies.as_deref(),
+        }
+    }
+
+    /// Describe the handler as data, `order` is its position in the parser
+    pub fn info(&self, order: usize) -> HandlerInfo {
+        let regex = self.regex.as_ref();
+        HandlerInfo {
+            order,
+            name: self.name.clone(),
+            kind: if regex.is_some() { "regex" } else { "custom" },
+            pattern: regex.and_then(|regex| regex.source.clone()),
+            flags: regex.map(|regex| if regex.case_insensitive { "i" } else { "" }.to_string()),
+            transform: regex.map(|regex| regex.transform.clone()),
+            options: regex.map(|regex| regex.options),
+            confidence: self.confidence,
+        }
+    }
+
     pub fn call(&self, context: HandlerContext) -> Option<HandlerResult> {
         (self.handler)(context)
     }
@@ -212,6 +437,18 @@ impl TrimIfString for i32 {
     }
 }
 
+impl TrimIfString for u8 {
+    fn trim_if_string(self) -> u8 {
+        self
+    }
+}
+
+impl TrimIfString for Crc32 {
+    fn trim_if_string(self) -> Crc32 {
+        self
+    }
+}
+
 impl TrimIfString for Codec {
     fn trim_if_string(self) -> Codec {
         self
@@ -224,12 +461,24 @@ impl TrimIfString for Quality {
     }
 }
 
+impl TrimIfString for Resolution {
+    fn trim_if_string(self) -> Resolution {
+        self
+    }
+}
+
 impl TrimIfString for Network {
     fn trim_if_string(self) -> Network {
         self
     }
 }
 
+impl TrimIfString for RemakeCountry {
+    fn trim_if_string(self) -> RemakeCountry {
+        self
+    }
+}
+
 impl<T> TrimIfString for Vec<T> {
     fn trim_if_string(self) -> Vec<T> {
         self
@@ -2,8 +2,10 @@ use regress::Regex;
 use std::cmp::min;
 
 use crate::extensions::regex::RegexStringExt;
-use crate::handler_wrapper::{Handler, HandlerResult, Match, RegexHandlerOptions};
-use crate::{transforms, Codec, Language, Network, Quality};
+use crate::extensions::text::floor_char_boundary;
+use crate::handler_wrapper::{Handler, HandlerResult, Match, Pattern, RegexHandlerOptions};
+use crate::transforms::{self, Transform};
+use crate::{AudioChannels, AudioCodec, Codec, Confidence, Edition, HdrFormat, Language, Network, Quality, Resolution};
 use lazy_static::lazy_static;
 
 pub fn add_default_handlers(parser: &mut super::Parser) {
@@ -11,8 +13,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "adult",
         |t| &mut t.adult,
-        Regex::case_insensitive(r"\b(?:xxx|xx)\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\b(?:xxx|xx)\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: true,
             skip_from_title: true,
@@ -25,8 +27,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "scene",
         |t| &mut t.scene,
-        Regex::new(r"^(?=.*(\b\d{3,4}p\b).*([_. ]WEB[_. ])(?!DL)\b)|\b(-CAKES|-GGEZ|-GGWP|-GLHF|-GOSSIP|-NAISU|-KOGI|-PECULATE|-SLOT|-EDITH|-ETHEL|-ELEANOR|-B2B|-SPAMnEGGS|-FTP|-DiRT|-SYNCOPY|-BAE|-SuccessfulCrab|-NHTFS|-SURCODE|-B0MBARDIERS)").unwrap(),
-        transforms::true_if_found,
+        Pattern::new(r"^(?=.*(\b\d{3,4}p\b).*([_. ]WEB[_. ])(?!DL)\b)|\b(-CAKES|-GGEZ|-GGWP|-GLHF|-GOSSIP|-NAISU|-KOGI|-PECULATE|-SLOT|-EDITH|-ETHEL|-ELEANOR|-B2B|-SPAMnEGGS|-FTP|-DiRT|-SYNCOPY|-BAE|-SuccessfulCrab|-NHTFS|-SURCODE|-B0MBARDIERS)").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: false,
             ..Default::default()
@@ -37,8 +39,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "extras",
         |t| &mut t.extras,
-        Regex::case_insensitive(r"\bNCED\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("NCED"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bNCED\b").unwrap(),
+        transforms::uniq_concat("NCED"),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -47,8 +49,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "extras",
         |t| &mut t.extras,
-        Regex::case_insensitive(r"\bNCOP\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("NCOP"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bNCOP\b").unwrap(),
+        transforms::uniq_concat("NCOP"),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -57,8 +59,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "extras",
         |t| &mut t.extras,
-        Regex::case_insensitive(r"\b(?:Deleted[ .-]*)?Scene(?:s)?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("Deleted Scene"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:Deleted[ .-]*)?Scene(?:s)?\b").unwrap(),
+        transforms::uniq_concat("Deleted Scene"),
         RegexHandlerOptions {
             remove: false,
             ..Default::default()
@@ -67,9 +69,9 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "extras",
         |t| &mut t.extras,
-        Regex::case_insensitive(r"(?:(?<=\b(?:19\d{2}|20\d{2})\b.*)\b(?:Featurettes?)\b|\bFeaturettes?\b(?!.*\b(?:19\d{2}|20\d{2})\b))")
+        Pattern::case_insensitive(r"(?:(?<=\b(?:19\d{2}|20\d{2})\b.*)\b(?:Featurettes?)\b|\bFeaturettes?\b(?!.*\b(?:19\d{2}|20\d{2})\b))")
             .unwrap(),
-        transforms::chain_transforms(transforms::replace_value("Featurette"), transforms::uniq_concat),
+        transforms::uniq_concat("Featurette"),
         RegexHandlerOptions {
             skip_from_title: true,
             remove: false,
@@ -79,8 +81,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "extras",
         |t| &mut t.extras,
-        Regex::case_insensitive(r"(?:(?<=\b(?:19\d{2}|20\d{2})\b.*)\b(?:Sample)\b|\b(?:Sample)\b(?!.*\b(?:19\d{2}|20\d{2})\b))").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("Sample"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"(?:(?<=\b(?:19\d{2}|20\d{2})\b.*)\b(?:Sample)\b|\b(?:Sample)\b(?!.*\b(?:19\d{2}|20\d{2})\b))").unwrap(),
+        transforms::uniq_concat("Sample"),
         RegexHandlerOptions {
             skip_from_title: true,
             remove: false,
@@ -90,11 +92,11 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "extras",
         |t| &mut t.extras,
-        Regex::case_insensitive(
+        Pattern::case_insensitive(
             r"(?:(?<=\b(?:19\d{2}|20\d{2})\b.*)\b(?:Trailers?)\b|\bTrailers?\b(?!.*\b(?:19\d{2}|20\d{2}|.(Park|And))\b))",
         )
         .unwrap(),
-        transforms::chain_transforms(transforms::replace_value("Trailer"), transforms::uniq_concat),
+        transforms::uniq_concat("Trailer"),
         RegexHandlerOptions {
             skip_from_title: true,
             remove: false,
@@ -106,8 +108,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "ppv",
         |t| &mut t.ppv,
-        Regex::case_insensitive(r"\bPPV\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\bPPV\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             skip_from_title: true,
             remove: true,
@@ -117,8 +119,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "ppv",
         |t| &mut t.ppv,
-        Regex::case_insensitive(r"\b\W?Fight.?Nights?\W?\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\b\W?Fight.?Nights?\W?\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             skip_from_title: true,
             remove: false,
@@ -130,8 +132,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "site",
         |t| &mut t.site,
-        Regex::case_insensitive(r"^(www?[\.,][\w-]+\.[\w-]+(?:\.[\w-]+)?)\s+-\s*").unwrap(),
-        transforms::identity,
+        Pattern::case_insensitive(r"^(www?[\.,][\w-]+\.[\w-]+(?:\.[\w-]+)?)\s+-\s*").unwrap(),
+        transforms::identity(),
         RegexHandlerOptions {
             skip_from_title: true,
             remove: true,
@@ -142,20 +144,33 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "site",
         |t| &mut t.site,
-        Regex::case_insensitive(r"^((?:www?[\.,])?[\w-]+\.[\w-]+(?:\.[\w-]+)*?)\s+-\s*").unwrap(),
-        transforms::identity,
+        Pattern::case_insensitive(r"^((?:www?[\.,])?[\w-]+\.[\w-]+(?:\.[\w-]+)*?)\s+-\s*").unwrap(),
+        transforms::identity(),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
         },
     ));
 
+    // Checksum (the episode code below removes it, so this has to come first). Only at the end of the title, or
+    // inside the last brackets (e.g. "(1080p AAC[9CC04E06]).mkv"), so a date like "[20190815]" earlier isn't one.
+    parser.add_handler(Handler::from_regex(
+        "checksum",
+        |t| &mut t.checksum,
+        Pattern::new(r"[[(]([\da-fA-F]{8})[\])](?=[\])]*(?:\.[a-zA-Z0-9]{1,5})?$)").unwrap(),
+        transforms::checksum(),
+        RegexHandlerOptions {
+            skip_from_title: true,
+            ..Default::default()
+        },
+    ));
+
     // Episode code
     parser.add_handler(Handler::from_regex(
         "episode_code",
         |t| &mut t.episode_code,
-        Regex::new(r"[[(]([a-zA-Z0-9]{8})[\])](?=\.[a-zA-Z0-9]{1,5}$|$)").unwrap(),
-        transforms::uppercase,
+        Pattern::new(r"[[(]([a-zA-Z0-9]{8})[\])](?=\.[a-zA-Z0-9]{1,5}$|$)").unwrap(),
+        transforms::uppercase(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -164,8 +179,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "episode_code",
         |t| &mut t.episode_code,
-        Regex::new(r"\[([A-Z0-9]{8})]").unwrap(),
-        transforms::uppercase,
+        Pattern::new(r"\[([A-Z0-9]{8})]").unwrap(),
+        transforms::uppercase(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -176,8 +191,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "resolution",
         |t| &mut t.resolution,
-        Regex::case_insensitive(r"\[?\]?3840x\d{4}[\])?]?").unwrap(),
-        transforms::value("2160p"),
+        Pattern::case_insensitive(r"\[?\]?7680x\d{4}[\])?]?").unwrap(),
+        transforms::const_value(Resolution::P4320),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -186,8 +201,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "resolution",
         |t| &mut t.resolution,
-        Regex::case_insensitive(r"\[?\]?1920x\d{3,4}[\])?]?").unwrap(),
-        transforms::value("1080p"),
+        Pattern::case_insensitive(r"\[?\]?3840x\d{4}[\])?]?").unwrap(),
+        transforms::const_value(Resolution::P2160),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -196,8 +211,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "resolution",
         |t| &mut t.resolution,
-        Regex::case_insensitive(r"\[?\]?1280x\d{3}[\])?]?").unwrap(),
-        transforms::value("720p"),
+        Pattern::case_insensitive(r"\[?\]?1920x\d{3,4}[\])?]?").unwrap(),
+        transforms::const_value(Resolution::P1080),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -206,8 +221,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "resolution",
         |t| &mut t.resolution,
-        Regex::case_insensitive(r"\[?\]?(\d{3,4}x\d{3,4})[\])?]?p?").unwrap(),
-        transforms::value("$1p"),
+        Pattern::case_insensitive(r"\[?\]?1280x\d{3}[\])?]?").unwrap(),
+        transforms::const_value(Resolution::P720),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -216,8 +231,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "resolution",
         |t| &mut t.resolution,
-        Regex::case_insensitive(r"(480|720|1080)0[pi]").unwrap(),
-        transforms::value("$1p"),
+        Pattern::case_insensitive(r"\[?\]?(\d{3,4}x\d{3,4})[\])?]?p?").unwrap(),
+        transforms::resolution_from_dimensions(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -226,8 +241,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "resolution",
         |t| &mut t.resolution,
-        Regex::case_insensitive(r"(?:QHD|QuadHD|WQHD|2560(\d+)?x(\d+)?1440p?)").unwrap(),
-        transforms::value("1440p"),
+        Pattern::case_insensitive(r"(480|720|1080)0[pi]").unwrap(),
+        transforms::resolution_from_height(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -236,8 +251,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "resolution",
         |t| &mut t.resolution,
-        Regex::case_insensitive(r"(?:Full HD|FHD|1920(\d+)?x(\d+)?1080p?)").unwrap(),
-        transforms::value("1080p"),
+        Pattern::case_insensitive(r"(?:QHD|QuadHD|WQHD|2560(\d+)?x(\d+)?1440p?)").unwrap(),
+        transforms::const_value(Resolution::P1440),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -246,8 +261,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "resolution",
         |t| &mut t.resolution,
-        Regex::case_insensitive(r"(?:BD|HD|M)(2160p?|4k)").unwrap(),
-        transforms::value("2160p"),
+        Pattern::case_insensitive(r"(?:Full HD|FHD|1920(\d+)?x(\d+)?1080p?)").unwrap(),
+        transforms::const_value(Resolution::P1080),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -256,8 +271,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "resolution",
         |t| &mut t.resolution,
-        Regex::case_insensitive(r"(?:BD|HD|M)1080p?").unwrap(),
-        transforms::value("1080p"),
+        Pattern::case_insensitive(r"(?:BD|HD|M)(2160p?|4k)").unwrap(),
+        transforms::const_value(Resolution::P2160),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -266,8 +281,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "resolution",
         |t| &mut t.resolution,
-        Regex::case_insensitive(r"(?:BD|HD|M)720p?").unwrap(),
-        transforms::value("720p"),
+        Pattern::case_insensitive(r"(?:BD|HD|M)1080p?").unwrap(),
+        transforms::const_value(Resolution::P1080),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -276,8 +291,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "resolution",
         |t| &mut t.resolution,
-        Regex::case_insensitive(r"(?:BD|HD|M)480p?").unwrap(),
-        transforms::value("480p"),
+        Pattern::case_insensitive(r"(?:BD|HD|M)720p?").unwrap(),
+        transforms::const_value(Resolution::P720),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -286,8 +301,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "resolution",
         |t| &mut t.resolution,
-        Regex::case_insensitive(r"\b(?:4k|2160p|1080p|720p|480p)(?!.*\b(?:4k|2160p|1080p|720p|480p)\b)").unwrap(),
-        transforms::resolution_transform,
+        Pattern::case_insensitive(r"(?:BD|HD|M)480p?").unwrap(),
+        transforms::const_value(Resolution::P480),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -296,8 +311,9 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "resolution",
         |t| &mut t.resolution,
-        Regex::case_insensitive(r"\b4k|21600?[pi]\b").unwrap(),
-        transforms::value("2160p"),
+        Pattern::case_insensitive(r"\b(?:8k|4k|4320p|2160p|1080p|720p|576p|480p)(?!.*\b(?:8k|4k|4320p|2160p|1080p|720p|576p|480p)\b)")
+            .unwrap(),
+        transforms::resolution_transform(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -306,8 +322,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "resolution",
         |t| &mut t.resolution,
-        Regex::case_insensitive(r"(\d{3,4})[pi]").unwrap(),
-        transforms::value("$1p"),
+        Pattern::case_insensitive(r"\b8k\b|43200?[pi]\b").unwrap(),
+        transforms::const_value(Resolution::P4320),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -316,8 +332,28 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "resolution",
         |t| &mut t.resolution,
-        Regex::case_insensitive(r"(240|360|480|576|720|1080|2160|3840)[pi]").unwrap(),
-        transforms::lowercase,
+        Pattern::case_insensitive(r"\b4k|21600?[pi]\b").unwrap(),
+        transforms::const_value(Resolution::P2160),
+        RegexHandlerOptions {
+            remove: true,
+            ..Default::default()
+        },
+    ));
+    parser.add_handler(Handler::from_regex(
+        "resolution",
+        |t| &mut t.resolution,
+        Pattern::case_insensitive(r"(\d{3,4})[pi]").unwrap(),
+        transforms::resolution_from_height(),
+        RegexHandlerOptions {
+            remove: true,
+            ..Default::default()
+        },
+    ));
+    parser.add_handler(Handler::from_regex(
+        "resolution",
+        |t| &mut t.resolution,
+        Pattern::case_insensitive(r"(240|360|480|576|720|1080|2160|3840)[pi]").unwrap(),
+        transforms::resolution_from_height(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -328,8 +364,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "trash",
         |t| &mut t.trash,
-        Regex::case_insensitive(r"\b(?:H[DQ][ .-]*)?CAM(?!.?(S|E|\()\d+)(?:H[DQ])?(?:[ .-]*Rip|Rp)?\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\b(?:H[DQ][ .-]*)?CAM(?!.?(S|E|\()\d+)(?:H[DQ])?(?:[ .-]*Rip|Rp)?\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: false,
             ..Default::default()
@@ -338,8 +374,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "trash",
         |t| &mut t.trash,
-        Regex::case_insensitive(r"\b(?:H[DQ][ .-]*)?S[ \.\-]print\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\b(?:H[DQ][ .-]*)?S[ \.\-]print\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: false,
             ..Default::default()
@@ -348,8 +384,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "trash",
         |t| &mut t.trash,
-        Regex::case_insensitive(r"\b(?:HD[ .-]*)?T(?:ELE)?(C|S)(?:INE|YNC)?(?:Rip)?\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\b(?:HD[ .-]*)?T(?:ELE)?(C|S)(?:INE|YNC)?(?:Rip)?\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: false,
             ..Default::default()
@@ -358,8 +394,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "trash",
         |t| &mut t.trash,
-        Regex::case_insensitive(r"\bPre.?DVD(?:Rip)?\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\bPre.?DVD(?:Rip)?\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: false,
             ..Default::default()
@@ -368,8 +404,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "trash",
         |t| &mut t.trash,
-        Regex::case_insensitive(r"\b(?:DVD?|BD|BR)?[ .-]*Scr(?:eener)?\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\b(?:DVD?|BD|BR)?[ .-]*Scr(?:eener)?\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: false,
             ..Default::default()
@@ -378,8 +414,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "trash",
         |t| &mut t.trash,
-        Regex::case_insensitive(r"\bDVB[ .-]*(?:Rip)?\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\bDVB[ .-]*(?:Rip)?\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: false,
             ..Default::default()
@@ -388,8 +424,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "trash",
         |t| &mut t.trash,
-        Regex::case_insensitive(r"\bSAT[ .-]*Rips?\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\bSAT[ .-]*Rips?\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: false,
             ..Default::default()
@@ -398,8 +434,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "trash",
         |t| &mut t.trash,
-        Regex::case_insensitive(r"\bLeaked\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\bLeaked\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -408,8 +444,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "trash",
         |t| &mut t.trash,
-        Regex::case_insensitive(r"threesixtyp").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"threesixtyp").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: false,
             ..Default::default()
@@ -418,8 +454,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "trash",
         |t| &mut t.trash,
-        Regex::case_insensitive(r"\bR5|R6\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\bR5|R6\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: false,
             ..Default::default()
@@ -428,8 +464,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "trash",
         |t| &mut t.trash,
-        Regex::case_insensitive(r"\b(?:Deleted[ .-]*)?Scene(?:s)?\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\b(?:Deleted[ .-]*)?Scene(?:s)?\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -438,8 +474,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "trash",
         |t| &mut t.trash,
-        Regex::case_insensitive(r"\bHQ.?(Clean)?.?(Aud(io)?)?\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\bHQ.?(Clean)?.?(Aud(io)?)?\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -450,7 +486,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "date",
         |t| &mut t.date,
-        Regex::new(r"(?:\W|^)([[(]?(?:19[6-9]|20[012])[0-9]([. \-/\\])(?:0[1-9]|1[012])\2(?:0[1-9]|[12][0-9]|3[01])[\]\)]?)(?:\W|$)")
+        Pattern::new(r"(?:\W|^)([[(]?(?:19[6-9]|20[012])[0-9]([. \-/\\])(?:0[1-9]|1[012])\2(?:0[1-9]|[12][0-9]|3[01])[\]\)]?)(?:\W|$)")
             .unwrap(),
         transforms::date_from_format("%Y %m %d"),
         RegexHandlerOptions {
@@ -461,7 +497,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "date",
         |t| &mut t.date,
-        Regex::new(r"(?:\W|^)(\[?\]?(?:0[1-9]|[12][0-9]|3[01])([. \-/\\])(?:0[1-9]|1[012])\2(?:19[6-9]|20[01])[0-9][\])]?)(?:\W|$)")
+        Pattern::new(r"(?:\W|^)(\[?\]?(?:0[1-9]|[12][0-9]|3[01])([. \-/\\])(?:0[1-9]|1[012])\2(?:19[6-9]|20[01])[0-9][\])]?)(?:\W|$)")
             .unwrap(),
         transforms::date_from_format("%d %m %Y"),
         RegexHandlerOptions {
@@ -472,7 +508,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "date",
         |t| &mut t.date,
-        Regex::new(r"(?:\W)(\[?\]?(?:0[1-9]|1[012])([. \-/\\])(?:0[1-9]|[12][0-9]|3[01])\2(?:[0][1-9]|[0126789][0-9])[\])]?)(?:\W|$)")
+        Pattern::new(r"(?:\W)(\[?\]?(?:0[1-9]|1[012])([. \-/\\])(?:0[1-9]|[12][0-9]|3[01])\2(?:[0][1-9]|[0126789][0-9])[\])]?)(?:\W|$)")
             .unwrap(),
         transforms::date_from_format("%m %d %y"),
         RegexHandlerOptions {
@@ -483,7 +519,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "date",
         |t| &mut t.date,
-        Regex::new(r"(?:\W)(\[?\]?(?:0[1-9]|[12][0-9]|3[01])([. \-/\\])(?:0[1-9]|1[012])\2(?:[0][1-9]|[0126789][0-9])[\])]?)(?:\W|$)")
+        Pattern::new(r"(?:\W)(\[?\]?(?:0[1-9]|[12][0-9]|3[01])([. \-/\\])(?:0[1-9]|1[012])\2(?:[0][1-9]|[0126789][0-9])[\])]?)(?:\W|$)")
             .unwrap(),
         transforms::date_from_format("%d %m %y"),
         RegexHandlerOptions {
@@ -494,7 +530,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "date",
         |t| &mut t.date,
-        Regex::case_insensitive(r"(?:\W|^)([([]?(?:0?[1-9]|[12][0-9]|3[01])[. ]?(?:st|nd|rd|th)?([. \-/\\])(?:feb(?:ruary)?|jan(?:uary)?|mar(?:ch)?|apr(?:il)?|may|june?|july?|aug(?:ust)?|sept?(?:ember)?|oct(?:ober)?|nov(?:ember)?|dec(?:ember)?)\2(?:19[7-9]|20[012])[0-9][)\]]?)(?=\W|$)").unwrap(),
+        Pattern::case_insensitive(r"(?:\W|^)([([]?(?:0?[1-9]|[12][0-9]|3[01])[. ]?(?:st|nd|rd|th)?([. \-/\\])(?:feb(?:ruary)?|jan(?:uary)?|mar(?:ch)?|apr(?:il)?|may|june?|july?|aug(?:ust)?|sept?(?:ember)?|oct(?:ober)?|nov(?:ember)?|dec(?:ember)?)\2(?:19[7-9]|20[012])[0-9][)\]]?)(?=\W|$)").unwrap(),
         transforms::date_from_formats(&[
             "%d %b %Y",      // regular format (9 Dec 2019)
             "%dst %b %Y",    // for 1st, 21st, 31st
@@ -515,7 +551,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "date",
         |t| &mut t.date,
-        Regex::case_insensitive(r"(?:\W|^)(\[?\]?(?:0?[1-9]|[12][0-9]|3[01])[. ]?(?:st|nd|rd|th)?([. \-\/\\])(?:feb(?:ruary)?|jan(?:uary)?|mar(?:ch)?|apr(?:il)?|may|june?|july?|aug(?:ust)?|sept?(?:ember)?|oct(?:ober)?|nov(?:ember)?|dec(?:ember)?)\2(?:0[1-9]|[0126789][0-9])[\])]?)(?:\W|$)").unwrap(),
+        Pattern::case_insensitive(r"(?:\W|^)(\[?\]?(?:0?[1-9]|[12][0-9]|3[01])[. ]?(?:st|nd|rd|th)?([. \-\/\\])(?:feb(?:ruary)?|jan(?:uary)?|mar(?:ch)?|apr(?:il)?|may|june?|july?|aug(?:ust)?|sept?(?:ember)?|oct(?:ober)?|nov(?:ember)?|dec(?:ember)?)\2(?:0[1-9]|[0126789][0-9])[\])]?)(?:\W|$)").unwrap(),
         transforms::date_from_format("%d %b %y"),
         RegexHandlerOptions {
             remove: true,
@@ -525,7 +561,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "date",
         |t| &mut t.date,
-        Regex::new(r"(?:\W|^)(\[?\]?20[012][0-9](?:0[1-9]|1[012])(?:0[1-9]|[12][0-9]|3[01])[\])]?)(?:\W|$)").unwrap(),
+        Pattern::new(r"(?:\W|^)(\[?\]?20[012][0-9](?:0[1-9]|1[012])(?:0[1-9]|[12][0-9]|3[01])[\])]?)(?:\W|$)").unwrap(),
         transforms::date_from_format("%Y%m%d"),
         RegexHandlerOptions {
             remove: true,
@@ -537,8 +573,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "complete",
         |t| &mut t.complete,
-        Regex::new(r"\b((?:19\d|20[012])\d[ .]?-[ .]?(?:19\d|20[012])\d)\b").unwrap(), // year range
-        transforms::true_if_found,
+        Pattern::new(r"\b((?:19\d|20[012])\d[ .]?-[ .]?(?:19\d|20[012])\d)\b").unwrap(), // year range
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -547,8 +583,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "complete",
         |t| &mut t.complete,
-        Regex::new(r"[([][ .]?((?:19\d|20[012])\d[ .]?-[ .]?\d{2})[ .]?[)\]]").unwrap(), // year range
-        transforms::true_if_found,
+        Pattern::new(r"[([][ .]?((?:19\d|20[012])\d[ .]?-[ .]?\d{2})[ .]?[)\]]").unwrap(), // year range
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -559,8 +595,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "bitrate",
         |t| &mut t.bitrate,
-        Regex::case_insensitive(r"\b\d+[kmg]bps\b").unwrap(),
-        transforms::lowercase,
+        Pattern::case_insensitive(r"\b\d+[kmg]bps\b").unwrap(),
+        transforms::lowercase(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -571,8 +607,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "year",
         |t| &mut t.year,
-        Regex::new(r"\b(20[0-9]{2}|2100)(?!\D*\d{4}\b)").unwrap(),
-        transforms::parse::<i32>,
+        Pattern::new(r"\b(20[0-9]{2}|2100)(?!\D*\d{4}\b)").unwrap(),
+        transforms::parse::<i32>(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -581,8 +617,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "year",
         |t| &mut t.year,
-        Regex::case_insensitive(r"[([]?(?!^)(?<!\d|Cap[. ]?)((?:19\d|20[012])\d)(?!\d|kbps)[)\]]?").unwrap(),
-        transforms::parse::<i32>,
+        Pattern::case_insensitive(r"[([]?(?!^)(?<!\d|Cap[. ]?)((?:19\d|20[012])\d)(?!\d|kbps)[)\]]?").unwrap(),
+        transforms::parse::<i32>(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -591,8 +627,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "year",
         |t| &mut t.year,
-        Regex::case_insensitive(r"^[([]?((?:19\d|20[012])\d)(?!\d|kbps)[)\]]?").unwrap(),
-        transforms::parse::<i32>,
+        Pattern::case_insensitive(r"^[([]?((?:19\d|20[012])\d)(?!\d|kbps)[)\]]?").unwrap(),
+        transforms::parse::<i32>(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -602,112 +638,140 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     // Edition
     parser.add_handler(Handler::from_regex(
         "edition",
-        |t| &mut t.edition,
-        Regex::case_insensitive(r"\b\d{2,3}(th)?[\.\s\-\+_\/(),]Anniversary[\.\s\-\+_\/(),](Edition|Ed)?\b").unwrap(),
-        transforms::value("Anniversary Edition"),
+        |t| &mut t.editions,
+        Pattern::case_insensitive(r"\b\d{2,3}(th)?[\.\s\-\+_\/(),]Anniversary[\.\s\-\+_\/(),](Edition|Ed)?\b").unwrap(),
+        transforms::uniq_concat(Edition::AnniversaryEdition),
         RegexHandlerOptions {
             remove: true,
+            skip_if_already_found: false,
             ..Default::default()
         },
     ));
     parser.add_handler(Handler::from_regex(
         "edition",
-        |t| &mut t.edition,
-        Regex::case_insensitive(r"\bUltimate[\.\s\-\+_\/(),]Edition\b").unwrap(),
-        transforms::value("Ultimate Edition"),
+        |t| &mut t.editions,
+        Pattern::case_insensitive(r"\bUltimate[\.\s\-\+_\/(),]Edition\b").unwrap(),
+        transforms::uniq_concat(Edition::UltimateEdition),
         RegexHandlerOptions {
             remove: true,
+            skip_if_already_found: false,
             ..Default::default()
         },
     ));
     parser.add_handler(Handler::from_regex(
         "edition",
-        |t| &mut t.edition,
-        Regex::case_insensitive(r#"\bExtended[\.\s\-\+_\/(),]Director\"?s\b"#).unwrap(),
-        transforms::value("Directors Cut"),
+        |t| &mut t.editions,
+        Pattern::case_insensitive(r#"\bExtended[\.\s\-\+_\/(),]Director['"’]?s(?:[\.\s\-\+_\/(),]Cut)?\b"#).unwrap(),
+        Transform::new(
+            "uniq_concat",
+            vec![Edition::ExtendedEdition.to_string(), Edition::DirectorsCut.to_string()],
+            |_, editions| {
+                let editions = transforms::push_unique(Edition::ExtendedEdition, editions)?;
+                transforms::push_unique(Edition::DirectorsCut, &editions)
+            },
+        ),
         RegexHandlerOptions {
             remove: true,
+            skip_if_already_found: false,
             ..Default::default()
         },
     ));
     parser.add_handler(Handler::from_regex(
         "edition",
-        |t| &mut t.edition,
-        Regex::case_insensitive(r"\b(custom.?)?Extended\b").unwrap(),
-        transforms::value("Extended Edition"),
+        |t| &mut t.editions,
+        Pattern::case_insensitive(r"\b(custom.?)?Extended\b").unwrap(),
+        transforms::uniq_concat(Edition::ExtendedEdition),
         RegexHandlerOptions {
             remove: true,
+            skip_if_already_found: false,
             ..Default::default()
         },
     ));
     parser.add_handler(Handler::from_regex(
         "edition",
-        |t| &mut t.edition,
-        Regex::case_insensitive(r#"\bDirector\"?s[\.\s\-\+_\/(),]Cut\b"#).unwrap(),
-        transforms::value("Directors Cut"),
+        |t| &mut t.editions,
+        Pattern::case_insensitive(r#"\bDirector['"’]?s[\.\s\-\+_\/(),]Cut\b"#).unwrap(),
+        transforms::uniq_concat(Edition::DirectorsCut),
         RegexHandlerOptions {
             remove: true,
+            skip_if_already_found: false,
             ..Default::default()
         },
     ));
     parser.add_handler(Handler::from_regex(
         "edition",
-        |t| &mut t.edition,
-        Regex::case_insensitive(r#"\bCollector\"?s\b"#).unwrap(),
-        transforms::value("Collectors Edition"),
+        |t| &mut t.editions,
+        Pattern::case_insensitive(r#"\bCollector['"’]?s\b"#).unwrap(),
+        transforms::uniq_concat(Edition::CollectorsEdition),
         RegexHandlerOptions {
             remove: true,
+            skip_if_already_found: false,
             ..Default::default()
         },
     ));
     parser.add_handler(Handler::from_regex(
         "edition",
-        |t| &mut t.edition,
-        Regex::case_insensitive(r"\bTheatrical\b").unwrap(),
-        transforms::value("Theatrical"),
+        |t| &mut t.editions,
+        Pattern::case_insensitive(r"\bTheatrical\b").unwrap(),
+        transforms::uniq_concat(Edition::Theatrical),
         RegexHandlerOptions {
             remove: true,
+            skip_if_already_found: false,
             ..Default::default()
         },
     ));
     parser.add_handler(Handler::from_regex(
         "edition",
-        |t| &mut t.edition,
-        Regex::case_insensitive(r"\bUncut\b").unwrap(),
-        transforms::value("Uncut"),
+        |t| &mut t.editions,
+        Pattern::case_insensitive(r"\bUncut\b").unwrap(),
+        transforms::uniq_concat(Edition::Uncut),
         RegexHandlerOptions {
             remove: true,
+            skip_if_already_found: false,
             ..Default::default()
         },
     ));
     parser.add_handler(Handler::from_regex(
         "edition",
-        |t| &mut t.edition,
-        Regex::case_insensitive(r"\bIMAX\b").unwrap(),
-        transforms::value("IMAX"),
+        |t| &mut t.editions,
+        Pattern::case_insensitive(r"\bIMAX\b").unwrap(),
+        transforms::uniq_concat(Edition::Imax),
         RegexHandlerOptions {
             remove: true,
+            skip_if_already_found: false,
             ..Default::default()
         },
     ));
     parser.add_handler(Handler::from_regex(
         "edition",
-        |t| &mut t.edition,
-        Regex::case_insensitive(r"\b\.Diamond\.\b").unwrap(),
-        transforms::value("Diamond Edition"),
+        |t| &mut t.editions,
+        Pattern::case_insensitive(r"\b\.Diamond\.\b").unwrap(),
+        transforms::uniq_concat(Edition::DiamondEdition),
         RegexHandlerOptions {
             remove: true,
+            skip_if_already_found: false,
             ..Default::default()
         },
     ));
     parser.add_handler(Handler::from_regex(
         "edition",
-        |t| &mut t.edition,
-        Regex::case_insensitive(r"\bRemaster(?:ed)?\b").unwrap(),
-        transforms::value("Remastered"),
+        |t| &mut t.editions,
+        // not part of a hyphenated name, and "Collection" is left for the complete handlers
+        Pattern::case_insensitive(r"(?<!-)\bCriterion\b(?!-)").unwrap(),
+        transforms::uniq_concat(Edition::Criterion),
+        RegexHandlerOptions {
+            skip_if_already_found: false,
+            ..Default::default()
+        },
+    ));
+    parser.add_handler(Handler::from_regex(
+        "edition",
+        |t| &mut t.editions,
+        Pattern::case_insensitive(r"\bRemaster(?:ed)?\b").unwrap(),
+        transforms::uniq_concat(Edition::Remastered),
         RegexHandlerOptions {
             remove: true,
-            skip_if_already_found: true,
+            skip_if_already_found: false,
             ..Default::default()
         },
     ));
@@ -716,22 +780,22 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "upscaled",
         |t| &mut t.upscaled,
-        Regex::case_insensitive(r"\b(?:AI.?)?(Upscal(ed?|ing)|Enhanced?)\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\b(?:AI.?)?(Upscal(ed?|ing)|Enhanced?)\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "upscaled",
         |t| &mut t.upscaled,
-        Regex::case_insensitive(r"\b(?:iris2|regrade|ups(uhd|fhd|hd|4k))\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\b(?:iris2|regrade|ups(uhd|fhd|hd|4k))\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "upscaled",
         |t| &mut t.upscaled,
-        Regex::case_insensitive(r"\b\.AI\.\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\b\.AI\.\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions::default(),
     ));
 
@@ -739,8 +803,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "convert",
         |t| &mut t.convert,
-        Regex::case_insensitive(r"\bCONVERT\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\bCONVERT\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions::default(),
     ));
 
@@ -748,8 +812,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "hardcoded",
         |t| &mut t.hardcoded,
-        Regex::case_insensitive(r"\bHC|HARDCODED\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\bHC|HARDCODED\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions::default(),
     ));
 
@@ -757,8 +821,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "proper",
         |t| &mut t.proper,
-        Regex::case_insensitive(r"\b(?:REAL.)?PROPER\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\b(?:REAL.)?PROPER\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions::default(),
     ));
 
@@ -766,8 +830,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "repack",
         |t| &mut t.repack,
-        Regex::case_insensitive(r"\bREPACK|RERIP\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\bREPACK|RERIP\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions::default(),
     ));
 
@@ -775,8 +839,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "retail",
         |t| &mut t.retail,
-        Regex::case_insensitive(r"\bRetail\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\bRetail\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions::default(),
     ));
 
@@ -784,8 +848,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "remastered",
         |t| &mut t.remastered,
-        Regex::case_insensitive(r"\bRemaster(?:ed)?\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\bRemaster(?:ed)?\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions::default(),
     ));
 
@@ -793,8 +857,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "documentary",
         |t| &mut t.documentary,
-        Regex::case_insensitive(r"\bDOCU(?:menta?ry)?\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\bDOCU(?:menta?ry)?\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             skip_from_title: true,
             ..Default::default()
@@ -805,28 +869,31 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "unrated",
         |t| &mut t.unrated,
-        Regex::case_insensitive(r"\bunrated|uncensored\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\bunrated|uncensored\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions::default(),
     ));
 
     // Region
-    parser.add_handler(Handler::from_regex(
-        "region",
-        |t| &mut t.region,
-        Regex::new(r"R\d\b").unwrap(),
-        transforms::identity,
-        RegexHandlerOptions {
-            skip_if_first: true,
-            ..Default::default()
-        },
-    ));
+    parser.add_handler(
+        Handler::from_regex(
+            "region",
+            |t| &mut t.region,
+            Pattern::new(r"R\d\b").unwrap(),
+            transforms::identity(),
+            RegexHandlerOptions {
+                skip_if_first: true,
+                ..Default::default()
+            },
+        )
+        .with_confidence(Confidence::Heuristic),
+    );
 
     // Quality
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\b(?:HD[ .-]*)?T(?:ELE)?S(?:YNC)?(?:Rip)?\b").unwrap(),
+        Pattern::case_insensitive(r"\b(?:HD[ .-]*)?T(?:ELE)?S(?:YNC)?(?:Rip)?\b").unwrap(),
         transforms::const_value(Quality::TeleSync),
         RegexHandlerOptions {
             remove: true,
@@ -836,7 +903,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::new(r"\b(?:HD[ .-]*)?T(?:ELE)?C(?:INE)?(?:Rip)?\b").unwrap(),
+        Pattern::new(r"\b(?:HD[ .-]*)?T(?:ELE)?C(?:INE)?(?:Rip)?\b").unwrap(),
         transforms::const_value(Quality::TeleCine),
         RegexHandlerOptions {
             remove: true,
@@ -846,7 +913,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\b(?:DVD?|BD|BR)?[ .-]*Scr(?:eener)?\b").unwrap(),
+        Pattern::case_insensitive(r"\b(?:DVD?|BD|BR)?[ .-]*Scr(?:eener)?\b").unwrap(),
         transforms::const_value(Quality::SCR),
         RegexHandlerOptions {
             remove: true,
@@ -856,7 +923,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\bP(?:RE)?-?(HD|DVD)(?:Rip)?\b").unwrap(),
+        Pattern::case_insensitive(r"\bP(?:RE)?-?(HD|DVD)(?:Rip)?\b").unwrap(),
         transforms::const_value(Quality::SCR),
         RegexHandlerOptions {
             remove: true,
@@ -866,7 +933,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\bBlu[ .-]*Ray\b(?=.*remux)").unwrap(),
+        Pattern::case_insensitive(r"\bBlu[ .-]*Ray\b(?=.*remux)").unwrap(),
         transforms::const_value(Quality::BluRayRemux),
         RegexHandlerOptions {
             remove: true,
@@ -876,7 +943,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"(?:BD|BR|UHD)[- ]?remux").unwrap(),
+        Pattern::case_insensitive(r"(?:BD|BR|UHD)[- ]?remux").unwrap(),
         transforms::const_value(Quality::BluRayRemux),
         RegexHandlerOptions {
             remove: true,
@@ -886,7 +953,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"(?<=remux.*)\bBlu[ .-]*Ray\b").unwrap(),
+        Pattern::case_insensitive(r"(?<=remux.*)\bBlu[ .-]*Ray\b").unwrap(),
         transforms::const_value(Quality::BluRayRemux),
         RegexHandlerOptions {
             remove: true,
@@ -896,7 +963,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\bremux\b").unwrap(),
+        Pattern::case_insensitive(r"\bremux\b").unwrap(),
         transforms::const_value(Quality::Remux),
         RegexHandlerOptions {
             remove: true,
@@ -906,7 +973,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\bBlu[ .-]*Ray\b(?![ .-]*Rip)").unwrap(),
+        Pattern::case_insensitive(r"\bBlu[ .-]*Ray\b(?![ .-]*Rip)").unwrap(),
         transforms::const_value(Quality::BluRay),
         RegexHandlerOptions {
             remove: true,
@@ -916,7 +983,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\bUHD[ .-]*Rip\b").unwrap(),
+        Pattern::case_insensitive(r"\bUHD[ .-]*Rip\b").unwrap(),
         transforms::const_value(Quality::UHDRip),
         RegexHandlerOptions {
             remove: true,
@@ -926,7 +993,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\bHD[ .-]*Rip\b").unwrap(),
+        Pattern::case_insensitive(r"\bHD[ .-]*Rip\b").unwrap(),
         transforms::const_value(Quality::HDRip),
         RegexHandlerOptions {
             remove: true,
@@ -936,7 +1003,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\bMicro[ .-]*HD\b").unwrap(),
+        Pattern::case_insensitive(r"\bMicro[ .-]*HD\b").unwrap(),
         transforms::const_value(Quality::HDRip),
         RegexHandlerOptions {
             remove: true,
@@ -946,7 +1013,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\b(?:BR|Blu[ .-]*Ray)[ .-]*Rip\b").unwrap(),
+        Pattern::case_insensitive(r"\b(?:BR|Blu[ .-]*Ray)[ .-]*Rip\b").unwrap(),
         transforms::const_value(Quality::BRRip),
         RegexHandlerOptions {
             remove: true,
@@ -956,7 +1023,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\bBD[ .-]*Rip\b|\bBDR\b|\bBD-RM\b|[[(]BD[\]) .,-]").unwrap(),
+        Pattern::case_insensitive(r"\bBD[ .-]*Rip\b|\bBDR\b|\bBD-RM\b|[[(]BD[\]) .,-]").unwrap(),
         transforms::const_value(Quality::BDRip),
         RegexHandlerOptions {
             remove: true,
@@ -966,7 +1033,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\b(?:HD[ .-]*)?DVD[ .-]*Rip\b").unwrap(),
+        Pattern::case_insensitive(r"\b(?:HD[ .-]*)?DVD[ .-]*Rip\b").unwrap(),
         transforms::const_value(Quality::DVDRip),
         RegexHandlerOptions {
             remove: true,
@@ -976,7 +1043,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\bVHS[ .-]*Rip?\b").unwrap(),
+        Pattern::case_insensitive(r"\bVHS[ .-]*Rip?\b").unwrap(),
         transforms::const_value(Quality::VHSRip),
         RegexHandlerOptions {
             remove: true,
@@ -986,7 +1053,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\bDVD(?:R\d?|.*Mux)?\b").unwrap(),
+        Pattern::case_insensitive(r"\bDVD(?:R\d?|.*Mux)?\b").unwrap(),
         transforms::const_value(Quality::DVD),
         RegexHandlerOptions {
             remove: true,
@@ -996,7 +1063,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\bVHS\b").unwrap(),
+        Pattern::case_insensitive(r"\bVHS\b").unwrap(),
         transforms::const_value(Quality::VHS),
         RegexHandlerOptions {
             remove: true,
@@ -1006,7 +1073,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\bPPVRip\b").unwrap(),
+        Pattern::case_insensitive(r"\bPPVRip\b").unwrap(),
         transforms::const_value(Quality::PPVRip),
         RegexHandlerOptions {
             remove: true,
@@ -1016,7 +1083,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\bHD.?TV.?Rip\b").unwrap(),
+        Pattern::case_insensitive(r"\bHD.?TV.?Rip\b").unwrap(),
         transforms::const_value(Quality::HDTVRip),
         RegexHandlerOptions {
             remove: true,
@@ -1026,7 +1093,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\bHD.?TV\b").unwrap(),
+        Pattern::case_insensitive(r"\bHD.?TV\b").unwrap(),
         transforms::const_value(Quality::HDTV),
         RegexHandlerOptions {
             remove: true,
@@ -1036,7 +1103,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\bDVB[ .-]*(?:Rip)?\b").unwrap(),
+        Pattern::case_insensitive(r"\bDVB[ .-]*(?:Rip)?\b").unwrap(),
         transforms::const_value(Quality::HDTV),
         RegexHandlerOptions {
             remove: true,
@@ -1046,7 +1113,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\bSAT[ .-]*Rips?\b").unwrap(),
+        Pattern::case_insensitive(r"\bSAT[ .-]*Rips?\b").unwrap(),
         transforms::const_value(Quality::SATRip),
         RegexHandlerOptions {
             remove: true,
@@ -1056,7 +1123,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\bTVRips?\b").unwrap(),
+        Pattern::case_insensitive(r"\bTVRips?\b").unwrap(),
         transforms::const_value(Quality::TVRip),
         RegexHandlerOptions {
             remove: true,
@@ -1066,7 +1133,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\bR5\b").unwrap(),
+        Pattern::case_insensitive(r"\bR5\b").unwrap(),
         transforms::const_value(Quality::R5),
         RegexHandlerOptions {
             remove: true,
@@ -1076,7 +1143,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\b(?:DL|WEB|BD|BR)MUX\b").unwrap(),
+        Pattern::case_insensitive(r"\b(?:DL|WEB|BD|BR)MUX\b").unwrap(),
         transforms::const_value(Quality::WebMux),
         RegexHandlerOptions {
             remove: true,
@@ -1086,7 +1153,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\bWEB[ .-]*Rip\b").unwrap(),
+        Pattern::case_insensitive(r"\bWEB[ .-]*Rip\b").unwrap(),
         transforms::const_value(Quality::WebRip),
         RegexHandlerOptions {
             remove: true,
@@ -1096,7 +1163,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\bWEB[ .-]?DL[ .-]?Rip\b").unwrap(),
+        Pattern::case_insensitive(r"\bWEB[ .-]?DL[ .-]?Rip\b").unwrap(),
         transforms::const_value(Quality::WebDLRip),
         RegexHandlerOptions {
             remove: true,
@@ -1106,7 +1173,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\bWEB[ .-]*(DL|.BDrip|.DLRIP)\b").unwrap(),
+        Pattern::case_insensitive(r"\bWEB[ .-]*(DL|.BDrip|.DLRIP)\b").unwrap(),
         transforms::const_value(Quality::WebDL),
         RegexHandlerOptions {
             remove: true,
@@ -1116,7 +1183,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\b(?<!\w.)WEB\b|\bWEB(?!([ \.\-\(\],]+\d))\b").unwrap(),
+        Pattern::case_insensitive(r"\b(?<!\w.)WEB\b|\bWEB(?!([ \.\-\(\],]+\d))\b").unwrap(),
         transforms::const_value(Quality::Web),
         RegexHandlerOptions {
             remove: true,
@@ -1127,7 +1194,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\b(?:H[DQ][ .-]*)?CAM(?!.?(S|E|\()\d+)(?:H[DQ])?(?:[ .-]*Rip|Rp)?\b").unwrap(),
+        Pattern::case_insensitive(r"\b(?:H[DQ][ .-]*)?CAM(?!.?(S|E|\()\d+)(?:H[DQ])?(?:[ .-]*Rip|Rp)?\b").unwrap(),
         transforms::const_value(Quality::Cam),
         RegexHandlerOptions {
             remove: true,
@@ -1138,7 +1205,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\b(?:H[DQ][ .-]*)?S[ \.\-]print").unwrap(),
+        Pattern::case_insensitive(r"\b(?:H[DQ][ .-]*)?S[ \.\-]print").unwrap(),
         transforms::const_value(Quality::Cam),
         RegexHandlerOptions {
             remove: true,
@@ -1149,7 +1216,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "quality",
         |t| &mut t.quality,
-        Regex::case_insensitive(r"\bPDTV\b").unwrap(),
+        Pattern::case_insensitive(r"\bPDTV\b").unwrap(),
         transforms::const_value(Quality::PDTV),
         RegexHandlerOptions {
             remove: true,
@@ -1180,14 +1247,14 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "bit_depth",
         |t| &mut t.bit_depth,
-        Regex::case_insensitive(r"\bhevc\s?10\b").unwrap(),
+        Pattern::case_insensitive(r"\bhevc\s?10\b").unwrap(),
         transforms::value("10bit"),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "bit_depth",
         |t| &mut t.bit_depth,
-        Regex::case_insensitive(r"(?:8|10|12)[-\.]?(?=bit)").unwrap(),
+        Pattern::case_insensitive(r"(?:8|10|12)[-\.]?(?=bit)").unwrap(),
         transforms::value("$1bit"),
         RegexHandlerOptions {
             remove: true,
@@ -1197,25 +1264,28 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "bit_depth",
         |t| &mut t.bit_depth,
-        Regex::case_insensitive(r"\bhdr10\b").unwrap(),
+        Pattern::case_insensitive(r"\bhdr10\b").unwrap(),
         transforms::value("10bit"),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "bit_depth",
         |t| &mut t.bit_depth,
-        Regex::case_insensitive(r"\bhi10\b").unwrap(),
+        Pattern::case_insensitive(r"\bhi10\b").unwrap(),
         transforms::value("10bit"),
         RegexHandlerOptions::default(),
     ));
 
-    parser.add_handler(Handler::new("bit_depth", |context| {
-        if let Some(bit_depth) = context.result.bit_depth.clone() {
-            // Remove hypens and spaces
-            context.result.bit_depth = Some(bit_depth.replace("-", "").replace(" ", ""));
-        }
-        None
-    }));
+    parser.add_handler(
+        Handler::new("bit_depth", |context| {
+            if let Some(bit_depth) = context.result.bit_depth.clone() {
+                // Remove hypens and spaces
+                context.result.bit_depth = Some(bit_depth.replace("-", "").replace(" ", ""));
+            }
+            None
+        })
+        .with_dependencies(&[]),
+    );
 
     /*
     # HDR
@@ -1229,8 +1299,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "hdr",
         |t| &mut t.hdr,
-        Regex::case_insensitive(r"\bDV\b|dolby.?vision|\bDoVi\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("DV"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"(?:\bDV|dolby.?vision|\bDoVi)[ .-]*P(?:rofile)?[ .-]*(\d(?:\.\d)?)\b").unwrap(),
+        transforms::dolby_vision_profile(),
         RegexHandlerOptions {
             remove: true,
             skip_if_already_found: false,
@@ -1240,8 +1310,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "hdr",
         |t| &mut t.hdr,
-        Regex::case_insensitive(r"HDR10(?:\+|[-\.\s]?plus)").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("HDR10+"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bDV\b|dolby.?vision|\bDoVi\b").unwrap(),
+        transforms::dolby_vision(),
         RegexHandlerOptions {
             remove: true,
             skip_if_already_found: false,
@@ -1251,8 +1321,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "hdr",
         |t| &mut t.hdr,
-        Regex::case_insensitive(r"\bHDR(?:10)?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("HDR"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"HDR10(?:\+|[-\.\s]?plus)").unwrap(),
+        transforms::uniq_concat(HdrFormat::Hdr10Plus),
         RegexHandlerOptions {
             remove: true,
             skip_if_already_found: false,
@@ -1262,8 +1332,41 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "hdr",
         |t| &mut t.hdr,
-        Regex::case_insensitive(r"\bSDR\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("SDR"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bHDR(?:10)?\b").unwrap(),
+        transforms::uniq_concat(HdrFormat::Hdr),
+        RegexHandlerOptions {
+            remove: true,
+            skip_if_already_found: false,
+            ..Default::default()
+        },
+    ));
+    parser.add_handler(Handler::from_regex(
+        "hdr",
+        |t| &mut t.hdr,
+        Pattern::case_insensitive(r"\bSDR\b").unwrap(),
+        transforms::uniq_concat(HdrFormat::Sdr),
+        RegexHandlerOptions {
+            remove: true,
+            skip_if_already_found: false,
+            ..Default::default()
+        },
+    ));
+    parser.add_handler(Handler::from_regex(
+        "hdr",
+        |t| &mut t.hdr,
+        Pattern::case_insensitive(r"\bHLG\b").unwrap(),
+        transforms::uniq_concat(HdrFormat::Hlg),
+        RegexHandlerOptions {
+            remove: true,
+            skip_if_already_found: false,
+            ..Default::default()
+        },
+    ));
+    parser.add_handler(Handler::from_regex(
+        "hdr",
+        |t| &mut t.hdr,
+        Pattern::case_insensitive(r"\bPQ10\b").unwrap(),
+        transforms::uniq_concat(HdrFormat::Pq10),
         RegexHandlerOptions {
             remove: true,
             skip_if_already_found: false,
@@ -1293,7 +1396,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "codec",
         |t| &mut t.codec,
-        Regex::case_insensitive(r"\b[hx][\. \-]?264\b").unwrap(),
+        Pattern::case_insensitive(r"\b[hx][\. \-]?264\b").unwrap(),
         transforms::const_value(Codec::Avc),
         RegexHandlerOptions {
             remove: true,
@@ -1303,7 +1406,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "codec",
         |t| &mut t.codec,
-        Regex::case_insensitive(r"\b[hx][\. \-]?265\b").unwrap(),
+        Pattern::case_insensitive(r"\b[hx][\. \-]?265\b").unwrap(),
         transforms::const_value(Codec::Hevc),
         RegexHandlerOptions {
             remove: true,
@@ -1313,7 +1416,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "codec",
         |t| &mut t.codec,
-        Regex::case_insensitive(r"HEVC10(bit)?\b|\b[xh][\. \-]?265\b").unwrap(),
+        Pattern::case_insensitive(r"HEVC10(bit)?\b|\b[xh][\. \-]?265\b").unwrap(),
         transforms::const_value(Codec::Hevc),
         RegexHandlerOptions {
             remove: true,
@@ -1323,7 +1426,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "codec",
         |t| &mut t.codec,
-        Regex::case_insensitive(r"\bhevc(?:\s?10)?\b").unwrap(),
+        Pattern::case_insensitive(r"\bhevc(?:\s?10)?\b").unwrap(),
         transforms::const_value(Codec::Hevc),
         RegexHandlerOptions {
             remove: true,
@@ -1334,7 +1437,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "codec",
         |t| &mut t.codec,
-        Regex::case_insensitive(r"\bdivx|xvid\b").unwrap(),
+        Pattern::case_insensitive(r"\bdivx|xvid\b").unwrap(),
         transforms::const_value(Codec::Xvid),
         RegexHandlerOptions {
             remove: true,
@@ -1345,7 +1448,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "codec",
         |t| &mut t.codec,
-        Regex::case_insensitive(r"\bavc\b").unwrap(),
+        Pattern::case_insensitive(r"\bavc\b").unwrap(),
         transforms::const_value(Codec::Avc),
         RegexHandlerOptions {
             remove: true,
@@ -1356,7 +1459,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "codec",
         |t| &mut t.codec,
-        Regex::case_insensitive(r"\bav1\b").unwrap(),
+        Pattern::case_insensitive(r"\bav1\b").unwrap(),
         transforms::const_value(Codec::Av1),
         RegexHandlerOptions {
             remove: true,
@@ -1367,7 +1470,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "codec",
         |t| &mut t.codec,
-        Regex::case_insensitive(r"\b(?:mpe?g\d*)\b").unwrap(),
+        Pattern::case_insensitive(r"\b(?:mpe?g\d*)\b").unwrap(),
         transforms::const_value(Codec::Mpeg),
         RegexHandlerOptions {
             remove: true,
@@ -1392,8 +1495,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "channels",
         |t| &mut t.channels,
-        Regex::case_insensitive(r"\bDDP?5[ \.\_]1\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("5.1"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bDDP?5[ \.\_]1\b").unwrap(),
+        transforms::uniq_concat(AudioChannels::FivePointOne),
         RegexHandlerOptions {
             remove: false,
             ..Default::default()
@@ -1402,8 +1505,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "channels",
         |t| &mut t.channels,
-        Regex::case_insensitive(r"\b5\.1(ch)?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("5.1"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b5\.1(ch)?\b").unwrap(),
+        transforms::uniq_concat(AudioChannels::FivePointOne),
         RegexHandlerOptions {
             remove: false,
             ..Default::default()
@@ -1412,8 +1515,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "channels",
         |t| &mut t.channels,
-        Regex::case_insensitive(r"\b7[\.\- ]1(.?ch(annel)?)?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("7.1"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b7[\.\- ]1(.?ch(annel)?)?\b").unwrap(),
+        transforms::uniq_concat(AudioChannels::SevenPointOne),
         RegexHandlerOptions {
             remove: false,
             ..Default::default()
@@ -1422,8 +1525,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "channels",
         |t| &mut t.channels,
-        Regex::case_insensitive(r"\b2\.0\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("2.0"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b2\.0\b").unwrap(),
+        transforms::uniq_concat(AudioChannels::TwoPointZero),
         RegexHandlerOptions {
             remove: false,
             ..Default::default()
@@ -1432,8 +1535,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "channels",
         |t| &mut t.channels,
-        Regex::case_insensitive(r"\bstereo\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("stereo"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bstereo\b").unwrap(),
+        transforms::uniq_concat(AudioChannels::Stereo),
         RegexHandlerOptions {
             remove: false,
             ..Default::default()
@@ -1442,8 +1545,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "channels",
         |t| &mut t.channels,
-        Regex::case_insensitive(r"\bmono\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("mono"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bmono\b").unwrap(),
+        transforms::uniq_concat(AudioChannels::Mono),
         RegexHandlerOptions {
             remove: false,
             ..Default::default()
@@ -1452,8 +1555,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "channels",
         |t| &mut t.channels,
-        Regex::case_insensitive(r"\b(?:x[2-4]|5[\W]1(?:x[2-4])?)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("5.1"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:x[2-4]|5[\W]1(?:x[2-4])?)\b").unwrap(),
+        transforms::uniq_concat(AudioChannels::FivePointOne),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -1462,8 +1565,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "channels",
         |t| &mut t.channels,
-        Regex::case_insensitive(r"\b2\.0(?:x[2-4])\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("2.0"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b2\.0(?:x[2-4])\b").unwrap(),
+        transforms::uniq_concat(AudioChannels::TwoPointZero),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -1493,8 +1596,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "audio",
         |t| &mut t.audio,
-        Regex::case_insensitive(r"\bDDP5[ \.\_]1\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("Dolby Digital Plus"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bDDP5[ \.\_]1\b").unwrap(),
+        transforms::uniq_concat(AudioCodec::DolbyDigitalPlus),
         RegexHandlerOptions {
             remove: true,
             skip_if_first: true,
@@ -1504,8 +1607,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "audio",
         |t| &mut t.audio,
-        Regex::case_insensitive(r"\b(?!.+HR)(DTS.?HD.?Ma(ster)?|DTS.?X)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("DTS Lossless"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?!.+HR)(DTS.?HD.?Ma(ster)?|DTS.?X)\b").unwrap(),
+        transforms::dts_lossless(),
         RegexHandlerOptions {
             remove: true,
             skip_if_already_found: false,
@@ -1515,8 +1618,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "audio",
         |t| &mut t.audio,
-        Regex::case_insensitive(r"\bDTS(?!(.?HD.?Ma(ster)?|.X)).?(HD.?HR|HD)?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("DTS Lossy"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bDTS(?!(.?HD.?Ma(ster)?|.X)).?(HD.?HR|HD)?\b").unwrap(),
+        transforms::uniq_concat(AudioCodec::DtsLossy),
         RegexHandlerOptions {
             remove: true,
             skip_if_already_found: false,
@@ -1526,8 +1629,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "audio",
         |t| &mut t.audio,
-        Regex::case_insensitive(r"\b(Dolby.?)?Atmos\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("Atmos"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(Dolby.?)?Atmos\b").unwrap(),
+        transforms::uniq_concat(AudioCodec::Atmos),
         RegexHandlerOptions {
             remove: true,
             skip_if_already_found: false,
@@ -1537,8 +1640,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "audio",
         |t| &mut t.audio,
-        Regex::case_insensitive(r"\b(TrueHD|\.True\.)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("TrueHD"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(TrueHD|\.True\.)\b").unwrap(),
+        transforms::uniq_concat(AudioCodec::TrueHD),
         RegexHandlerOptions {
             remove: true,
             skip_if_already_found: false,
@@ -1549,8 +1652,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "audio",
         |t| &mut t.audio,
-        Regex::new(r"\bTRUE\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("TrueHD"), transforms::uniq_concat),
+        Pattern::new(r"\bTRUE\b").unwrap(),
+        transforms::uniq_concat(AudioCodec::TrueHD),
         RegexHandlerOptions {
             remove: true,
             skip_if_already_found: false,
@@ -1561,8 +1664,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "audio",
         |t| &mut t.audio,
-        Regex::case_insensitive(r"\bFLAC(?:\+?2\.0)?(x[2-4])?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("FLAC"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bFLAC(?:\+?2\.0)?(x[2-4])?\b").unwrap(),
+        transforms::uniq_concat(AudioCodec::Flac),
         RegexHandlerOptions {
             remove: true,
             skip_if_already_found: false,
@@ -1572,8 +1675,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "audio",
         |t| &mut t.audio,
-        Regex::case_insensitive(r"\bEAC-?3(?:[. -]?[256]\.[01])?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("EAC3"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bEAC-?3(?:[. -]?[256]\.[01])?\b").unwrap(),
+        transforms::uniq_concat(AudioCodec::Eac3),
         RegexHandlerOptions {
             remove: true,
             skip_if_already_found: false,
@@ -1583,30 +1686,33 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "audio",
         |t| &mut t.audio,
-        Regex::case_insensitive(r"\bAC-?3(x2)?(?:[ .-](5\.1)?[x+]2\.?0?x?3?)?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("AC3"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bAC-?3(x2)?(?:[ .-](5\.1)?[x+]2\.?0?x?3?)?\b").unwrap(),
+        transforms::uniq_concat(AudioCodec::Ac3),
         RegexHandlerOptions {
             remove: true,
             skip_if_already_found: false,
             ..Default::default()
         },
     ));
+    parser.add_handler(
+        Handler::from_regex(
+            "audio",
+            |t| &mut t.audio,
+            Pattern::case_insensitive(r"\b5\.1(ch)?\b").unwrap(),
+            transforms::uniq_concat(AudioCodec::Ac3),
+            RegexHandlerOptions {
+                remove: true,
+                skip_if_already_found: true,
+                ..Default::default()
+            },
+        )
+        .with_confidence(Confidence::Inferred),
+    );
     parser.add_handler(Handler::from_regex(
         "audio",
         |t| &mut t.audio,
-        Regex::case_insensitive(r"\b5\.1(ch)?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("AC3"), transforms::uniq_concat),
-        RegexHandlerOptions {
-            remove: true,
-            skip_if_already_found: true,
-            ..Default::default()
-        },
-    ));
-    parser.add_handler(Handler::from_regex(
-        "audio",
-        |t| &mut t.audio,
-        Regex::case_insensitive(r"\b(DD2?[\+p]2?(.?5.1)?|DD Plus|Dolby Digital Plus)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("Dolby Digital Plus"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(DD2?[\+p]2?(.?5.1)?|DD Plus|Dolby Digital Plus)\b").unwrap(),
+        transforms::uniq_concat(AudioCodec::DolbyDigitalPlus),
         RegexHandlerOptions {
             remove: true,
             skip_if_already_found: false,
@@ -1616,8 +1722,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "audio",
         |t| &mut t.audio,
-        Regex::case_insensitive(r"\b(DD|Dolby.?Digital.?)2?(5.?1)?(?!.?(Plus|P|\+))\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("Dolby Digital"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(DD|Dolby.?Digital.?)2?(5.?1)?(?!.?(Plus|P|\+))\b").unwrap(),
+        transforms::uniq_concat(AudioCodec::DolbyDigital),
         RegexHandlerOptions {
             remove: true,
             skip_if_already_found: false,
@@ -1627,8 +1733,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "audio",
         |t| &mut t.audio,
-        Regex::case_insensitive(r"\bDolbyD\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("Dolby Digital"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bDolbyD\b").unwrap(),
+        transforms::uniq_concat(AudioCodec::DolbyDigital),
         RegexHandlerOptions {
             remove: true,
             skip_if_first: true,
@@ -1638,8 +1744,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "audio",
         |t| &mut t.audio,
-        Regex::case_insensitive(r"\bQ?Q?AAC(x?2)?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("AAC"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bQ?Q?AAC(x?2)?\b").unwrap(),
+        transforms::uniq_concat(AudioCodec::Aac),
         RegexHandlerOptions {
             remove: true,
             skip_if_already_found: false,
@@ -1649,8 +1755,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "audio",
         |t| &mut t.audio,
-        Regex::case_insensitive(r"\b(H[DQ])?.?(Clean.?Aud(io)?)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("HQ Clean Audio"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(H[DQ])?.?(Clean.?Aud(io)?)\b").unwrap(),
+        transforms::uniq_concat(AudioCodec::HqCleanAudio),
         RegexHandlerOptions {
             remove: true,
             skip_if_already_found: false,
@@ -1670,9 +1776,11 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "group",
         |t| &mut t.group,
-        Regex::case_insensitive(r"- ?(?!\d+$|S\d+|\d+x|ep?\d+|[^[]+]$)([^\-. []+[^\-. [)\]\d][^\-. [)\]]*)(?:\[[\w.-]+])?(?=\.\w{2,4}$|$)")
-            .unwrap(),
-        transforms::identity,
+        Pattern::case_insensitive(
+            r"- ?(?!\d+$|S\d+|\d+x|ep?\d+|[^[]+]$)([^\-. []+[^\-. [)\]\d][^\-. [)\]]*)(?:\[[\w.-]+])?(?=\.\w{2,4}$|$)",
+        )
+        .unwrap(),
+        transforms::identity(),
         RegexHandlerOptions {
             remove: false,
             ..Default::default()
@@ -1683,8 +1791,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "container",
         |t| &mut t.container,
-        Regex::case_insensitive(r"\.?[\[(]?\b(MKV|AVI|MP4|WMV|MPG|MPEG)\b[\])]?").unwrap(),
-        transforms::lowercase,
+        Pattern::case_insensitive(r"\.?[\[(]?\b(MKV|AVI|MP4|WMV|MPG|MPEG)\b[\])]?").unwrap(),
+        transforms::lowercase(),
         RegexHandlerOptions::default(),
     ));
 
@@ -1713,8 +1821,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "volumes",
         |t| &mut t.volumes,
-        Regex::case_insensitive(r"\bvol(?:s|umes?)?[. -]*(?:\d{1,2}[., +/\\&-]+)+\d{1,2}\b").unwrap(),
-        transforms::range_func,
+        Pattern::case_insensitive(r"\bvol(?:s|umes?)?[. -]*(?:\d{1,2}[., +/\\&-]+)+\d{1,2}\b").unwrap(),
+        transforms::range_func(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -1725,33 +1833,36 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
         static ref VOLUME_REGEX: Regex = Regex::case_insensitive(r"\bvol(?:ume)?[. -]*(\d{1,2})").unwrap();
     }
 
-    parser.add_handler(Handler::new("volumes", |context| {
-        let title = &context.title;
-        let start_index = context.matched.get("year").map(|y| y.match_index).unwrap_or(0);
-        let start_index = min(start_index, title.len() - 1); // make sure we don't go out of bounds
+    parser.add_handler(
+        Handler::new("volumes", |context| {
+            let title = &context.title;
+            let start_index = context.matched.get("year").map(|y| y.match_index).unwrap_or(0);
+            let start_index = floor_char_boundary(title, min(start_index, title.len().saturating_sub(1))); // make sure we don't go out of bounds
 
-        if let Some(m) = VOLUME_REGEX.find_str(&title[start_index..]) {
-            let vol = m.group(1).unwrap().as_str().parse::<i32>().unwrap();
+            if let Some(m) = VOLUME_REGEX.find_str(&title[start_index..]) {
+                let vol = m.group(1)?.as_str().parse::<i32>().ok()?;
 
-            context.matched.insert(
-                "volumes".to_string(),
-                Match {
-                    raw_match: m.as_str().to_string(),
-                    match_index: m.start(),
-                },
-            );
+                context.matched.insert(
+                    "volumes".to_string(),
+                    Match {
+                        raw_match: m.as_str().to_string(),
+                        match_index: m.start(),
+                    },
+                );
 
-            context.result.volumes = vec![vol];
+                context.result.volumes = vec![vol];
 
-            return Some(HandlerResult {
-                raw_match: m.as_str().to_string(),
-                match_index: m.start() + start_index,
-                remove: true,
-                skip_from_title: false,
-            });
-        }
-        None
-    }));
+                return Some(HandlerResult {
+                    raw_match: m.as_str().to_string(),
+                    match_index: m.start() + start_index,
+                    remove: true,
+                    skip_from_title: false,
+                });
+            }
+            None
+        })
+        .with_dependencies(&["year"]),
+    );
 
     /*
     # Pre-Language
@@ -1759,16 +1870,19 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     */
 
     // Pre-Language
-    parser.add_handler(Handler::from_regex(
-        "languages",
-        |t| &mut t.languages,
-        Regex::case_insensitive(r"\b(temporadas?|completa)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Spanish), transforms::uniq_concat),
-        RegexHandlerOptions {
-            skip_if_already_found: false,
-            ..Default::default()
-        },
-    ));
+    parser.add_handler(
+        Handler::from_regex(
+            "languages",
+            |t| &mut t.languages,
+            Pattern::case_insensitive(r"\b(temporadas?|completa)\b").unwrap(),
+            transforms::uniq_concat(Language::Spanish),
+            RegexHandlerOptions {
+                skip_if_already_found: false,
+                ..Default::default()
+            },
+        )
+        .with_confidence(Confidence::Inferred),
+    );
 
     /*
     # Complete
@@ -1788,41 +1902,41 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "complete",
         |t| &mut t.complete,
-        Regex::case_insensitive(r"(?:\bthe\W)?(?:\bcomplete|collection|dvd)?\b[ .]?\bbox[ .-]?set\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"(?:\bthe\W)?(?:\bcomplete|collection|dvd)?\b[ .]?\bbox[ .-]?set\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions::default(),
     ));
 
     parser.add_handler(Handler::from_regex(
         "complete",
         |t| &mut t.complete,
-        Regex::case_insensitive(r"(?:\bthe\W)?(?:\bcomplete|collection|dvd)?\b[ .]?\bmini[ .-]?series\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"(?:\bthe\W)?(?:\bcomplete|collection|dvd)?\b[ .]?\bmini[ .-]?series\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions::default(),
     ));
 
     parser.add_handler(Handler::from_regex(
         "complete",
         |t| &mut t.complete,
-        Regex::case_insensitive(r"(?:\bthe\W)?(?:\bcomplete|full|all)\b.*\b(?:series|seasons|collection|episodes|set|pack|movies)\b")
+        Pattern::case_insensitive(r"(?:\bthe\W)?(?:\bcomplete|full|all)\b.*\b(?:series|seasons|collection|episodes|set|pack|movies)\b")
             .unwrap(),
-        transforms::true_if_found,
+        transforms::true_if_found(),
         RegexHandlerOptions::default(),
     ));
 
     parser.add_handler(Handler::from_regex(
         "complete",
         |t| &mut t.complete,
-        Regex::case_insensitive(r"\b(?:series|seasons|movies?)\b.*\b(?:complete|collection)\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\b(?:series|seasons|movies?)\b.*\b(?:complete|collection)\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions::default(),
     ));
 
     parser.add_handler(Handler::from_regex(
         "complete",
         |t| &mut t.complete,
-        Regex::case_insensitive(r"(?:\bthe\W)?\bultimate\b[ .]\bcollection\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"(?:\bthe\W)?\bultimate\b[ .]\bcollection\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -1832,16 +1946,16 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "complete",
         |t| &mut t.complete,
-        Regex::case_insensitive(r"\bcollection\b.*\b(?:set|pack|movies)\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\bcollection\b.*\b(?:set|pack|movies)\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions::default(),
     ));
 
     parser.add_handler(Handler::from_regex(
         "complete",
         |t| &mut t.complete,
-        Regex::case_insensitive(r"\bcollection\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\bcollection\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             skip_from_title: true,
             ..Default::default()
@@ -1851,8 +1965,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "complete",
         |t| &mut t.complete,
-        Regex::case_insensitive(r"duology|trilogy|quadr[oi]logy|tetralogy|pentalogy|hexalogy|heptalogy|anthology").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"duology|trilogy|quadr[oi]logy|tetralogy|pentalogy|hexalogy|heptalogy|anthology").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -1862,8 +1976,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "complete",
         |t| &mut t.complete,
-        Regex::case_insensitive(r"\bcompleta\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\bcompleta\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -1873,8 +1987,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "complete",
         |t| &mut t.complete,
-        Regex::case_insensitive(r"\bsaga\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\bsaga\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: true,
@@ -1912,12 +2026,46 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler("seasons", regex.compile(r"(?:\W|^)(\d{1,2})(?:e|ep)\d{1,3}(?:\W|$)", regex.IGNORECASE), array(integer))
      */
 
+    // Absolute episode next to the seasonal one, e.g. "87 (S4-24)" or "1000 (S21E108)"
+    parser.add_handler(Handler::from_regex(
+        "absolute_episodes",
+        |t| &mut t.absolute_episodes,
+        Pattern::case_insensitive(r"[ .\-_](\d{1,4})(?=[ .]*[(\[]s\d{1,2}[e-]\d{1,4}[)\]])").unwrap(),
+        transforms::range_func(),
+        RegexHandlerOptions {
+            remove: true,
+            ..Default::default()
+        },
+    ));
+
+    // Version (before seasons and episodes, which take "S01E01v2" along)
+    parser.add_handler(Handler::from_regex(
+        "version",
+        |t| &mut t.version,
+        Pattern::case_insensitive(r"(?<=(?:^|[^a-z\d.]|(?<!\d)\.|e|ep)\d{1,4})v(\d{1,2})\b").unwrap(),
+        transforms::parse(),
+        RegexHandlerOptions {
+            skip_from_title: true,
+            ..Default::default()
+        },
+    ));
+    parser.add_handler(Handler::from_regex(
+        "version",
+        |t| &mut t.version,
+        Pattern::case_insensitive(r"[[(]v(\d{1,2})[\])]").unwrap(),
+        transforms::parse(),
+        RegexHandlerOptions {
+            skip_from_title: true,
+            ..Default::default()
+        },
+    ));
+
     // Seasons
     parser.add_handler(Handler::from_regex(
         "seasons",
         |t| &mut t.seasons,
-        Regex::case_insensitive(r"(?:complete\W|seasons?\W|\W|^)((?:s\d{1,2}[., +/\\&-]+)+s\d{1,2}\b)").unwrap(),
-        transforms::range_func,
+        Pattern::case_insensitive(r"(?:complete\W|seasons?\W|\W|^)((?:s\d{1,2}[., +/\\&-]+)+s\d{1,2}\b)").unwrap(),
+        transforms::range_func(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -1926,8 +2074,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "seasons",
         |t| &mut t.seasons,
-        Regex::case_insensitive(r"(?:complete\W|seasons?\W|\W|^)[([]?(s\d{2,}-\d{2,}\b)[)\]]?").unwrap(),
-        transforms::range_func,
+        Pattern::case_insensitive(r"(?:complete\W|seasons?\W|\W|^)[([]?(s\d{2,}-\d{2,}\b)[)\]]?").unwrap(),
+        transforms::range_func(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -1936,8 +2084,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "seasons",
         |t| &mut t.seasons,
-        Regex::case_insensitive(r"(?:complete\W|seasons?\W|\W|^)[([]?(s[1-9]-[2-9])[)\]]?").unwrap(),
-        transforms::range_func,
+        Pattern::case_insensitive(r"(?:complete\W|seasons?\W|\W|^)[([]?(s[1-9]-[2-9])[)\]]?").unwrap(),
+        transforms::range_func(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -1946,8 +2094,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "seasons",
         |t| &mut t.seasons,
-        Regex::case_insensitive(r"\d+ª(?:.+)?(?:a.?)?\d+ª(?:(?:.+)?(?:temporadas?))").unwrap(),
-        transforms::range_func,
+        Pattern::case_insensitive(r"\d+ª(?:.+)?(?:a.?)?\d+ª(?:(?:.+)?(?:temporadas?))").unwrap(),
+        transforms::range_func(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -1956,11 +2104,11 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "seasons",
         |t| &mut t.seasons,
-        Regex::case_insensitive(
+        Pattern::case_insensitive(
             r"(?:(?:\bthe\W)?\bcomplete\W)?(?:seasons?|[Сс]езони?|temporadas?)[. ]?[-:]?[. ]?[([]?((?:\d{1,2}[., /\\&]+)+\d{1,2}\b)[)\]]?",
         )
         .unwrap(),
-        transforms::range_func,
+        transforms::range_func(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -1969,11 +2117,11 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "seasons",
         |t| &mut t.seasons,
-        Regex::case_insensitive(
+        Pattern::case_insensitive(
             r"(?:(?:\bthe\W)?\bcomplete\W)?(?:seasons?|[Сс]езони?|temporadas?)[. ]?[-:]?[. ]?[([]?((?:\d{1,2}[.-]+)+[1-9]\d?\b)[)\]]?",
         )
         .unwrap(),
-        transforms::range_func,
+        transforms::range_func(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -1982,9 +2130,9 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "seasons",
         |t| &mut t.seasons,
-        Regex::case_insensitive(r"(?:(?:\bthe\W)?\bcomplete\W)?season[. ]?[([]?((?:\d{1,2}[. -]+)+[1-9]\d?\b)[)\]]?(?!.*\.\w{2,4}$)")
+        Pattern::case_insensitive(r"(?:(?:\bthe\W)?\bcomplete\W)?season[. ]?[([]?((?:\d{1,2}[. -]+)+[1-9]\d?\b)[)\]]?(?!.*\.\w{2,4}$)")
             .unwrap(),
-        transforms::range_func,
+        transforms::range_func(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -1993,9 +2141,9 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "seasons",
         |t| &mut t.seasons,
-        Regex::case_insensitive(r"(?:(?:\bthe\W)?\bcomplete\W)?\bseasons?\b[. -]?(\d{1,2}[. -]?(?:to|thru|and|\+|:)[. -]?\d{1,2})\b")
+        Pattern::case_insensitive(r"(?:(?:\bthe\W)?\bcomplete\W)?\bseasons?\b[. -]?(\d{1,2}[. -]?(?:to|thru|and|\+|:)[. -]?\d{1,2})\b")
             .unwrap(),
-        transforms::range_func,
+        transforms::range_func(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -2004,29 +2152,30 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "seasons",
         |t| &mut t.seasons,
-        Regex::case_insensitive(r"(?:(?:\bthe\W)?\bcomplete\W)?(?:saison|seizoen|season|series|temp(?:orada)?):?[. ]?(\d{1,2})\b").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"(?:(?:\bthe\W)?\bcomplete\W)?(?:saison|seizoen|season|series|temp(?:orada)?):?[. ]?(\d{1,2})\b")
+            .unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "seasons",
         |t| &mut t.seasons,
-        Regex::case_insensitive(r"(\d{1,2})(?:-?й)?[. _]?(?:[Сс]езон|sez(?:on)?)(?:\W?\D|$)").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"(\d{1,2})(?:-?й)?[. _]?(?:[Сс]езон|sez(?:on)?)(?:\W?\D|$)").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "seasons",
         |t| &mut t.seasons,
-        Regex::case_insensitive(r"[Сс]езон:?[. _]?№?(\d{1,2})(?!\d)").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"[Сс]езон:?[. _]?№?(\d{1,2})(?!\d)").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "seasons",
         |t| &mut t.seasons,
-        Regex::case_insensitive(r"(?:\D|^)(\d{1,2})Â?[°ºªa]?[. ]*temporada").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"(?:\D|^)(\d{1,2})Â?[°ºªa]?[. ]*temporada").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -2035,8 +2184,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "seasons",
         |t| &mut t.seasons,
-        Regex::case_insensitive(r"t(\d{1,3})(?:[ex]+|$)").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"t(\d{1,3})(?:[ex]+|$)").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -2045,8 +2194,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "seasons",
         |t| &mut t.seasons,
-        Regex::case_insensitive(r"(?:(?:\bthe\W)?\bcomplete)?s(\d{1,3})(?:[\Wex]|\d{2}\b|$)").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"(?:(?:\bthe\W)?\bcomplete)?s(\d{1,3})(?:[\Wex]|\d{2}\b|$)").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions {
             remove: false,
             skip_if_already_found: false,
@@ -2056,85 +2205,85 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "seasons",
         |t| &mut t.seasons,
-        Regex::case_insensitive(r"(?:(?:\bthe\W)?\bcomplete\W)?(?:\W|^)(\d{1,2})[. ]?(?:st|nd|rd|th)[. ]*season").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"(?:(?:\bthe\W)?\bcomplete\W)?(?:\W|^)(\d{1,2})[. ]?(?:st|nd|rd|th)[. ]*season").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "seasons",
         |t| &mut t.seasons,
-        Regex::case_insensitive(r"(?<=S)\d{2}(?=E\d+)").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"(?<=S)\d{2}(?=E\d+)").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "seasons",
         |t| &mut t.seasons,
-        Regex::case_insensitive(r"(?:\D|^)(\d{1,2})[xх]\d{1,3}(?:\D|$)").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"(?:\D|^)(\d{1,2})[xх]\d{1,3}(?:\D|$)").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "seasons",
         |t| &mut t.seasons,
-        Regex::case_insensitive(r"\bSn([1-9])(?:\D|$)").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"\bSn([1-9])(?:\D|$)").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "seasons",
         |t| &mut t.seasons,
-        Regex::case_insensitive(r"[[(](\d{1,2})\.\d{1,3}[)\]]").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"[[(](\d{1,2})\.\d{1,3}[)\]]").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "seasons",
         |t| &mut t.seasons,
-        Regex::case_insensitive(r"-\s?(\d{1,2})\.\d{2,3}\s?-").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"-\s?(\d{1,2})\.\d{2,3}\s?-").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "seasons",
         |t| &mut t.seasons,
-        Regex::case_insensitive(r"(?:^|\/)(\d{1,2})-\d{2}\b(?!-\d)").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"(?:^|\/)(\d{1,2})-\d{2}\b(?!-\d)").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "seasons",
         |t| &mut t.seasons,
-        Regex::case_insensitive(r"[^\w-](\d{1,2})-\d{2}(?=\.\w{2,4}$)").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"[^\w-](\d{1,2})-\d{2}(?=\.\w{2,4}$)").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "seasons",
         |t| &mut t.seasons,
-        Regex::case_insensitive(r"(?<!\bEp?(?:isode)? ?\d+\b.*)\b(\d{2})[ ._]\d{2}(?:.F)?\.\w{2,4}$").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"(?<!\bEp?(?:isode)? ?\d+\b.*)\b(\d{2})[ ._]\d{2}(?:.F)?\.\w{2,4}$").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "seasons",
         |t| &mut t.seasons,
-        Regex::case_insensitive(r"\bEp(?:isode)?\W+(\d{1,2})\.\d{1,3}\b").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"\bEp(?:isode)?\W+(\d{1,2})\.\d{1,3}\b").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "seasons",
         |t| &mut t.seasons,
-        Regex::case_insensitive(r"\bSeasons?\b.*\b(\d{1,2}-\d{1,2})\b").unwrap(),
-        transforms::range_func,
+        Pattern::case_insensitive(r"\bSeasons?\b.*\b(\d{1,2}-\d{1,2})\b").unwrap(),
+        transforms::range_func(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "seasons",
         |t| &mut t.seasons,
-        Regex::case_insensitive(r"(?:\W|^)(\d{1,2})(?:e|ep)\d{1,3}(?:\W|$)").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"(?:\W|^)(\d{1,2})(?:e|ep)\d{1,3}(?:\W|$)").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
 
@@ -2203,50 +2352,50 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "episodes",
         |t| &mut t.episodes,
-        Regex::case_insensitive(r"(?:[\W\d]|^)e[ .]?[([]?(\d{1,3}(?:[ .-]*(?:[&+]|e){1,2}[ .]?\d{1,3})+)(?:\W|$)").unwrap(),
-        transforms::range_func,
+        Pattern::case_insensitive(r"(?:[\W\d]|^)e[ .]?[([]?(\d{1,3}(?:[ .-]*(?:[&+]|e){1,2}[ .]?\d{1,3})+)(?:\W|$)").unwrap(),
+        transforms::range_func(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "episodes",
         |t| &mut t.episodes,
-        Regex::case_insensitive(r"(?:[\W\d]|^)ep[ .]?[([]?(\d{1,3}(?:[ .-]*(?:[&+]|ep){1,2}[ .]?\d{1,3})+)(?:\W|$)").unwrap(),
-        transforms::range_func,
+        Pattern::case_insensitive(r"(?:[\W\d]|^)ep[ .]?[([]?(\d{1,3}(?:[ .-]*(?:[&+]|ep){1,2}[ .]?\d{1,3})+)(?:\W|$)").unwrap(),
+        transforms::range_func(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "episodes",
         |t| &mut t.episodes,
-        Regex::case_insensitive(r"(?:[\W\d]|^)\d+[xх][ .]?[([]?(\d{1,3}(?:[ .]?[xх][ .]?\d{1,3})+)(?:\W|$)").unwrap(),
-        transforms::range_func,
+        Pattern::case_insensitive(r"(?:[\W\d]|^)\d+[xх][ .]?[([]?(\d{1,3}(?:[ .]?[xх][ .]?\d{1,3})+)(?:\W|$)").unwrap(),
+        transforms::range_func(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "episodes",
         |t| &mut t.episodes,
-        Regex::case_insensitive(r"(?:[\W\d]|^)(?:episodes?|[Сс]ерии:?)[ .]?[([]?(\d{1,3}(?:[ .+]*[&+][ .]?\d{1,3})+)(?:\W|$)").unwrap(),
-        transforms::range_func,
+        Pattern::case_insensitive(r"(?:[\W\d]|^)(?:episodes?|[Сс]ерии:?)[ .]?[([]?(\d{1,3}(?:[ .+]*[&+][ .]?\d{1,3})+)(?:\W|$)").unwrap(),
+        transforms::range_func(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "episodes",
         |t| &mut t.episodes,
-        Regex::case_insensitive(r"[([]?(?:\D|^)(\d{1,3}[ .]?ao[ .]?\d{1,3})[)\]]?(?:\W|$)").unwrap(),
-        transforms::range_func,
+        Pattern::case_insensitive(r"[([]?(?:\D|^)(\d{1,3}[ .]?ao[ .]?\d{1,3})[)\]]?(?:\W|$)").unwrap(),
+        transforms::range_func(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "episodes",
         |t| &mut t.episodes,
-        Regex::case_insensitive(r"(?:[\W\d]|^)(?:e|eps?|episodes?|[Сс]ерии:?|\d+[xх])[ .]*[([]?(\d{1,3}(?:-\d{1,3})+)(?:\W|$)").unwrap(),
-        transforms::range_func,
+        Pattern::case_insensitive(r"(?:[\W\d]|^)(?:e|eps?|episodes?|[Сс]ерии:?|\d+[xх])[ .]*[([]?(\d{1,3}(?:-\d{1,3})+)(?:\W|$)").unwrap(),
+        transforms::range_func(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "episodes",
         |t| &mut t.episodes,
-        Regex::case_insensitive(r"[st]\d{1,2}[. ]?[xх-]?[. ]?(?:e|x|х|ep|-|\.)[. ]?(\d{1,4})(?:[abc]|v0?[1-4]|\D|$)").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"[st]\d{1,2}[. ]?[xх-]?[. ]?(?:e|x|х|ep|-|\.)[. ]?(\d{1,4})(?:[abc]|v0?[1-4]|\D|$)").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -2255,156 +2404,156 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "episodes",
         |t| &mut t.episodes,
-        Regex::case_insensitive(r"\b[st]\d{2}(\d{2})\b").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"\b[st]\d{2}(\d{2})\b").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "episodes",
         |t| &mut t.episodes,
-        Regex::case_insensitive(r"(?:\W|^)(\d{1,3}(?:[ .]*~[ .]*\d{1,3})+)(?:\W|$)").unwrap(),
-        transforms::range_func,
+        Pattern::case_insensitive(r"(?:\W|^)(\d{1,3}(?:[ .]*~[ .]*\d{1,3})+)(?:\W|$)").unwrap(),
+        transforms::range_func(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "episodes",
         |r| &mut r.episodes,
-        Regex::case_insensitive(r"-\s(\d{1,3}[ .]*-[ .]*\d{1,3})(?!-\d)(?:\W|$)").unwrap(),
-        transforms::range_func,
+        Pattern::case_insensitive(r"-\s(\d{1,3}[ .]*-[ .]*\d{1,3})(?!-\d)(?:\W|$)").unwrap(),
+        transforms::range_func(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "episodes",
         |r| &mut r.episodes,
-        Regex::case_insensitive(r"s\d{1,2}\s?\((\d{1,3}[ .]*-[ .]*\d{1,3})\)").unwrap(),
-        transforms::range_func,
+        Pattern::case_insensitive(r"s\d{1,2}\s?\((\d{1,3}[ .]*-[ .]*\d{1,3})\)").unwrap(),
+        transforms::range_func(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "episodes",
         |r| &mut r.episodes,
-        Regex::new(r"(?:^|\/)\d{1,2}-(\d{2})\b(?!-\d)").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::new(r"(?:^|\/)\d{1,2}-(\d{2})\b(?!-\d)").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "episodes",
         |r| &mut r.episodes,
-        Regex::new(r"(?<!\d-)\b\d{1,2}-(\d{2})(?=\.\w{2,4}$)").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::new(r"(?<!\d-)\b\d{1,2}-(\d{2})(?=\.\w{2,4}$)").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "episodes",
         |t| &mut t.episodes,
-        Regex::case_insensitive(r"(?<=^\[.+].+)[. ]+-[. ]+(\d{1,4})[. ]+(?=\W)").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"(?<=^\[.+].+)[. ]+-[. ]+(\d{1,4})[. ]+(?=\W)").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "episodes",
         |t| &mut t.episodes,
-        Regex::case_insensitive(r"(?<!(?:seasons?|[Сс]езони?)\W*)(?:[ .([-]|^)(\d{1,3}(?:[ .]?[,&+~][ .]?\d{1,3})+)(?:[ .)\]-]|$)")
+        Pattern::case_insensitive(r"(?<!(?:seasons?|[Сс]езони?)\W*)(?:[ .([-]|^)(\d{1,3}(?:[ .]?[,&+~][ .]?\d{1,3})+)(?:[ .)\]-]|$)")
             .unwrap(),
-        transforms::range_func,
+        transforms::range_func(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "episodes",
         |t| &mut t.episodes,
-        Regex::case_insensitive(r"(?<!(?:seasons?|[Сс]езони?)\W*)(?:[ .([-]|^)(\d{1,3}(?:-\d{1,3})+)(?:[ .)(\]]|-\D|$)").unwrap(),
-        transforms::range_func,
+        Pattern::case_insensitive(r"(?<!(?:seasons?|[Сс]езони?)\W*)(?:[ .([-]|^)(\d{1,3}(?:-\d{1,3})+)(?:[ .)(\]]|-\D|$)").unwrap(),
+        transforms::range_func(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "episodes",
         |r| &mut r.episodes,
-        Regex::case_insensitive(r"\bEp(?:isode)?\W+\d{1,2}\.(\d{1,3})\b").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"\bEp(?:isode)?\W+\d{1,2}\.(\d{1,3})\b").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "episodes",
         |r| &mut r.episodes,
-        Regex::case_insensitive(r"(?:\b[ée]p?(?:isode)?|[Ээ]пизод|[Сс]ер(?:ии|ия|\.)?|cap(?:itulo)?|epis[oó]dio)[. ]?[-:#№]?[. ]?(\d{1,4})(?:[abc]|v0?[1-4]|\W|$)").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"(?:\b[ée]p?(?:isode)?|[Ээ]пизод|[Сс]ер(?:ии|ия|\.)?|cap(?:itulo)?|epis[oó]dio)[. ]?[-:#№]?[. ]?(\d{1,4})(?:[abc]|v0?[1-4]|\W|$)").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "episodes",
         |r| &mut r.episodes,
-        Regex::case_insensitive(r"\b(\d{1,3})(?:-?я)?[ ._-]*(?:ser(?:i?[iyj]a|\b)|[Сс]ер(?:ии|ия|\.)?)").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"\b(\d{1,3})(?:-?я)?[ ._-]*(?:ser(?:i?[iyj]a|\b)|[Сс]ер(?:ии|ия|\.)?)").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "episodes",
         |r| &mut r.episodes,
-        Regex::case_insensitive(r"(?:\D|^)\d{1,2}[. ]?[xх][. ]?(\d{1,3})(?:[abc]|v0?[1-4]|\D|$)").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"(?:\D|^)\d{1,2}[. ]?[xх][. ]?(\d{1,3})(?:[abc]|v0?[1-4]|\D|$)").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "episodes",
         |r| &mut r.episodes,
-        Regex::case_insensitive(r"(?<=S\d{2}E)\d+").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"(?<=S\d{2}E)\d+").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "episodes",
         |t| &mut t.episodes,
-        Regex::new(r"[[(]\d{1,2}\.(\d{1,3})[)\]]").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::new(r"[[(]\d{1,2}\.(\d{1,3})[)\]]").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "episodes",
         |r| &mut r.episodes,
-        Regex::new(r"\b[Ss]\d{1,2}[ .](\d{1,2})\b").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::new(r"\b[Ss]\d{1,2}[ .](\d{1,2})\b").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "episodes",
         |r| &mut r.episodes,
-        Regex::new(r"-\s?\d{1,2}\.(\d{2,3})\s?-").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::new(r"-\s?\d{1,2}\.(\d{2,3})\s?-").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "episodes",
         |r| &mut r.episodes,
-        Regex::case_insensitive(r"(?<=\D|^)(\d{1,3})[. ]?(?:of|из|iz)[. ]?\d{1,3}(?=\D|$)").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"(?<=\D|^)(\d{1,3})[. ]?(?:of|из|iz)[. ]?\d{1,3}(?=\D|$)").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "episodes",
         |r| &mut r.episodes,
-        Regex::new(r"\b\d{2}[ ._-](\d{2})(?:.F)?\.\w{2,4}$").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::new(r"\b\d{2}[ ._-](\d{2})(?:.F)?\.\w{2,4}$").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "episodes",
         |r| &mut r.episodes,
-        Regex::new(r"(?<!^)\[(\d{2,3})](?!(?:\.\w{2,4})?$)").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::new(r"(?<!^)\[(\d{2,3})](?!(?:\.\w{2,4})?$)").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "episodes",
         |r| &mut r.episodes,
-        Regex::case_insensitive(r"(\d+)(?=.?\[([A-Z0-9]{8})])").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"(\d+)(?=.?\[([A-Z0-9]{8})])").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "episodes",
         |r| &mut r.episodes,
-        Regex::case_insensitive(r"(?<![xh])\b264\b|\b265\b").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"(?<![xh])\b264\b|\b265\b").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -2413,8 +2562,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "episodes",
         |r| &mut r.episodes,
-        Regex::case_insensitive(r"(?<!\bMovie\s-\s)(?<=\s-\s)\d+(?=\s[-(\s])").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"(?<!\bMovie\s-\s)(?<=\s-\s)\d+(?=\s[-(\s])").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions {
             remove: true,
             skip_if_already_found: true,
@@ -2424,8 +2573,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "episodes",
         |r| &mut r.episodes,
-        Regex::case_insensitive(r"(?:\W|^)(?:\d+)?(?:e|ep)(\d{1,3})(?:\W|$)").unwrap(),
-        |v, _| Some(vec![v.parse().ok()?]),
+        Pattern::case_insensitive(r"(?:\W|^)(?:\d+)?(?:e|ep)(\d{1,3})(?:\W|$)").unwrap(),
+        transforms::array_integer(),
         RegexHandlerOptions::default(),
     ));
 
@@ -2437,71 +2586,68 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
         static ref EPISODE_RE2: Regex = Regex::case_insensitive(r"^(?:[\[\(-][ .]?)?(\d{1,4})(?:a|b|v\d)?(?:\W|$)(?!movie|film)").unwrap();
         static ref EPISODE_RE3: Regex = Regex::new(r"\d+").unwrap();
     }
-    parser.add_handler(Handler::new("episodes", |context| {
-        if context.result.episodes.is_empty() {
-            let start_indexes = [
-                context.matched.get("year").map(|m| m.match_index),
-                context.matched.get("seasons").map(|m| m.match_index),
-            ]
-            .into_iter()
-            .flatten()
-            .collect::<Vec<_>>();
-
-            let end_indexes = [
-                context.matched.get("resolution").map(|m| m.match_index),
-                context.matched.get("quality").map(|m| m.match_index),
-                context.matched.get("codec").map(|m| m.match_index),
-                context.matched.get("audio").map(|m| m.match_index),
-                Some(context.title.len()), // (custom addition to prevent out of bounds)
-            ]
-            .into_iter()
-            .flatten()
-            .collect::<Vec<_>>();
-
-            let start_index = start_indexes.iter().min().copied().unwrap_or(0);
-            let end_index = end_indexes.iter().min().copied().unwrap_or(context.title.len());
-
-            // If start_index > end_index, set start_index = end_index
-            let start_index = min(start_index, end_index);
-
-            let beginning_title = &context.title[..end_index];
-            let middle_title = &context.title[start_index..end_index];
-
-            if let Some(m) = EPISODE_RE1.find_str(beginning_title).or_else(|| EPISODE_RE2.find_str(middle_title)) {
-                let episode_str = m.group(1).unwrap().as_str();
-                let episode_numbers: Vec<i32> = EPISODE_RE3
-                    .find_iter_str(episode_str)
-                    .filter_map(|m| m.as_str().parse().ok())
-                    .collect();
-
-                if !episode_numbers.is_empty() {
-                    context.result.episodes = episode_numbers;
-                    return Some(HandlerResult {
-                        raw_match: m.as_str().to_string(),
-                        match_index: context.title.find(m.as_str()).unwrap(),
-                        remove: false,
-                        skip_from_title: false,
-                    });
+    parser.add_handler(
+        Handler::new("episodes", |context| {
+            if context.result.episodes.is_empty() {
+                let start_indexes = [
+                    context.matched.get("year").map(|m| m.match_index),
+                    context.matched.get("seasons").map(|m| m.match_index),
+                ]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>();
+
+                let end_indexes = [
+                    context.matched.get("resolution").map(|m| m.match_index),
+                    context.matched.get("quality").map(|m| m.match_index),
+                    context.matched.get("codec").map(|m| m.match_index),
+                    context.matched.get("audio").map(|m| m.match_index),
+                    Some(context.title.len()), // (custom addition to prevent out of bounds)
+                ]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>();
+
+                let start_index = start_indexes.iter().min().copied().unwrap_or(0);
+                let end_index = end_indexes.iter().min().copied().unwrap_or(context.title.len());
+
+                // If start_index > end_index, set start_index = end_index
+                let end_index = floor_char_boundary(context.title, end_index);
+                let start_index = floor_char_boundary(context.title, min(start_index, end_index));
+
+                let beginning_title = &context.title[..end_index];
+                let middle_title = &context.title[start_index..end_index];
+
+                if let Some(m) = EPISODE_RE1.find_str(beginning_title).or_else(|| EPISODE_RE2.find_str(middle_title)) {
+                    let episode_str = m.group(1)?.as_str();
+                    let episode_numbers: Vec<i32> = EPISODE_RE3
+                        .find_iter_str(episode_str)
+                        .filter_map(|m| m.as_str().parse().ok())
+                        .collect();
+
+                    if !episode_numbers.is_empty() {
+                        let match_index = context.title.find(m.as_str())?;
+                        context.result.episodes = episode_numbers;
+                        return Some(HandlerResult {
+                            raw_match: m.as_str().to_string(),
+                            match_index,
+                            remove: false,
+                            skip_from_title: false,
+                        });
+                    }
                 }
             }
-        }
-        None
-    }));
+            None
+        })
+        .with_dependencies(&["year", "seasons", "resolution", "quality", "codec", "audio"]),
+    );
 
     /*
     # Country Code
     parser.add_handler("country", regex.compile(r"\b(US|UK)\b"), value("$1"))
      */
 
-    // disabled for now (probably only for 'The Office')
-    // Country Code
-    /*parser.add_handler(Handler::from_regex(
-        "country",
-        |r| &mut r.country,
-        Regex::new(r"\b(US|UK)\b").unwrap(),
-        |v, _| Some(vec![v.to_string()]),
-        RegexHandlerOptions::default(),
-    ));*/
+    // Country Code: added last, it only counts at the end of the title
 
     /*
     # Languages (ISO 639-1 Standardized)
@@ -2520,8 +2666,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bengl?(?:sub[A-Z]*)?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::English), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bengl?(?:sub[A-Z]*)?\b").unwrap(),
+        transforms::uniq_concat(Language::English),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -2531,8 +2677,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\beng?sub[A-Z]*\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::English), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\beng?sub[A-Z]*\b").unwrap(),
+        transforms::uniq_concat(Language::English),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -2542,8 +2688,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bing(?:l[eéê]s)?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::English), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bing(?:l[eéê]s)?\b").unwrap(),
+        transforms::uniq_concat(Language::English),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -2553,8 +2699,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\besub\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::English), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\besub\b").unwrap(),
+        transforms::uniq_concat(Language::English),
         RegexHandlerOptions {
             remove: true,
             skip_if_already_found: false,
@@ -2565,31 +2711,34 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\benglish\W+(?:subs?|sdh|hi)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::English), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\benglish\W+(?:subs?|sdh|hi)\b").unwrap(),
+        transforms::uniq_concat(Language::English),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
         },
     ));
 
-    parser.add_handler(Handler::from_regex(
-        "languages",
-        |r| &mut r.languages,
-        Regex::case_insensitive(r"\beng?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::English), transforms::uniq_concat),
-        RegexHandlerOptions {
-            skip_from_title: true,
-            skip_if_already_found: false,
-            ..Default::default()
-        },
-    ));
+    parser.add_handler(
+        Handler::from_regex(
+            "languages",
+            |r| &mut r.languages,
+            Pattern::case_insensitive(r"\beng?\b").unwrap(),
+            transforms::uniq_concat(Language::English),
+            RegexHandlerOptions {
+                skip_from_title: true,
+                skip_if_already_found: false,
+                ..Default::default()
+            },
+        )
+        .with_confidence(Confidence::Heuristic),
+    );
 
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\benglish?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::English), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\benglish?\b").unwrap(),
+        transforms::uniq_concat(Language::English),
         RegexHandlerOptions {
             skip_if_first: true,
             skip_if_already_found: false,
@@ -2600,8 +2749,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:JP|JAP|JPN)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Japanese), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:JP|JAP|JPN)\b").unwrap(),
+        transforms::uniq_concat(Language::Japanese),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -2624,8 +2773,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(japanese|japon[eê]s)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Japanese), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(japanese|japon[eê]s)\b").unwrap(),
+        transforms::uniq_concat(Language::Japanese),
         RegexHandlerOptions {
             skip_if_first: true,
             skip_if_already_found: false,
@@ -2636,8 +2785,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:KOR|kor[ .-]?sub)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Korean), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:KOR|kor[ .-]?sub)\b").unwrap(),
+        transforms::uniq_concat(Language::Korean),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -2647,8 +2796,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(korean|coreano)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Korean), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(korean|coreano)\b").unwrap(),
+        transforms::uniq_concat(Language::Korean),
         RegexHandlerOptions {
             skip_if_first: true,
             skip_if_already_found: false,
@@ -2659,8 +2808,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:traditional\W*chinese|chinese\W*traditional)(?:\Wchi)?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Chinese), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:traditional\W*chinese|chinese\W*traditional)(?:\Wchi)?\b").unwrap(),
+        transforms::uniq_concat(Language::Chinese),
         RegexHandlerOptions {
             skip_if_already_found: false,
             remove: true,
@@ -2671,8 +2820,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bzh-hant\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Chinese), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bzh-hant\b").unwrap(),
+        transforms::uniq_concat(Language::Chinese),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -2682,8 +2831,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:mand[ae]rin|ch[sn])\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Chinese), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:mand[ae]rin|ch[sn])\b").unwrap(),
+        transforms::uniq_concat(Language::Chinese),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -2693,8 +2842,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"(?<!shang-?)\bCH(?:I|T)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Chinese), transforms::uniq_concat),
+        Pattern::case_insensitive(r"(?<!shang-?)\bCH(?:I|T)\b").unwrap(),
+        transforms::uniq_concat(Language::Chinese),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -2705,8 +2854,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(chinese|chin[eê]s)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Chinese), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(chinese|chin[eê]s)\b").unwrap(),
+        transforms::uniq_concat(Language::Chinese),
         RegexHandlerOptions {
             skip_if_first: true,
             skip_if_already_found: false,
@@ -2717,8 +2866,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bzh-hans\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Chinese), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bzh-hans\b").unwrap(),
+        transforms::uniq_concat(Language::Chinese),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -2728,8 +2877,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bFR(?:ench|a|e|anc[eê]s)?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::French), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bFR(?:ench|a|e|anc[eê]s)?\b").unwrap(),
+        transforms::uniq_concat(Language::French),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -2753,8 +2902,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(VOST(?:FR?|A)?)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::French), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(VOST(?:FR?|A)?)\b").unwrap(),
+        transforms::uniq_concat(Language::French),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -2764,8 +2913,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(VF[FQIB2]?|(TRUE|SUB)?.?FRENCH|(VOST)?FR2?)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::French), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(VF[FQIB2]?|(TRUE|SUB)?.?FRENCH|(VOST)?FR2?)\b").unwrap(),
+        transforms::uniq_concat(Language::French),
         RegexHandlerOptions {
             remove: true,
             skip_if_already_found: false,
@@ -2776,11 +2925,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bspanish\W?latin|american\W*(?:spa|esp?)").unwrap(),
-        transforms::chain_transforms(
-            transforms::replace_with_value(Language::LatinAmericanSpanish),
-            transforms::uniq_concat,
-        ),
+        Pattern::case_insensitive(r"\bspanish\W?latin|american\W*(?:spa|esp?)").unwrap(),
+        transforms::uniq_concat(Language::LatinAmericanSpanish),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -2792,8 +2938,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:\bla\b.+(?:cia\b))").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Spanish), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:\bla\b.+(?:cia\b))").unwrap(),
+        transforms::uniq_concat(Language::Spanish),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -2804,11 +2950,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:audio.)?lat(?:in?|ino)?\b").unwrap(),
-        transforms::chain_transforms(
-            transforms::replace_with_value(Language::LatinAmericanSpanish),
-            transforms::uniq_concat,
-        ),
+        Pattern::case_insensitive(r"\b(?:audio.)?lat(?:in?|ino)?\b").unwrap(),
+        transforms::uniq_concat(Language::LatinAmericanSpanish),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -2818,8 +2961,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:audio.)?(?:ESP?|spa|(en[ .]+)?espa[nñ]ola?|castellano)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Spanish), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:audio.)?(?:ESP?|spa|(en[ .]+)?espa[nñ]ola?|castellano)\b").unwrap(),
+        transforms::uniq_concat(Language::Spanish),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -2829,8 +2972,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bes(?=[ .,/-]+(?:[A-Z]{2}[ .,/-]+){2,})\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Spanish), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bes(?=[ .,/-]+(?:[A-Z]{2}[ .,/-]+){2,})\b").unwrap(),
+        transforms::uniq_concat(Language::Spanish),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -2841,8 +2984,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?<=[ .,/-]+(?:[A-Z]{2}[ .,/-]+){2,})es\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Spanish), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?<=[ .,/-]+(?:[A-Z]{2}[ .,/-]+){2,})es\b").unwrap(),
+        transforms::uniq_concat(Language::Spanish),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -2853,8 +2996,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?<=[ .,/-]+[A-Z]{2}[ .,/-]+)es(?=[ .,/-]+[A-Z]{2}[ .,/-]+)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Spanish), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?<=[ .,/-]+[A-Z]{2}[ .,/-]+)es(?=[ .,/-]+[A-Z]{2}[ .,/-]+)\b").unwrap(),
+        transforms::uniq_concat(Language::Spanish),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -2865,8 +3008,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bes(?=\.(?:ass|ssa|srt|sub|idx)$)").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Spanish), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bes(?=\.(?:ass|ssa|srt|sub|idx)$)").unwrap(),
+        transforms::uniq_concat(Language::Spanish),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -2877,8 +3020,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bspanish\W+subs?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Spanish), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bspanish\W+subs?\b").unwrap(),
+        transforms::uniq_concat(Language::Spanish),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -2901,8 +3044,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(spanish|espanhol)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Spanish), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(spanish|espanhol)\b").unwrap(),
+        transforms::uniq_concat(Language::Spanish),
         RegexHandlerOptions {
             skip_if_first: true,
             skip_if_already_found: false,
@@ -2913,8 +3056,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:p[rt]|en|port)[. (\\/-]*BR\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Portuguese), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:p[rt]|en|port)[. (\\/-]*BR\b").unwrap(),
+        transforms::uniq_concat(Language::Portuguese),
         RegexHandlerOptions {
             skip_if_already_found: false,
             remove: true,
@@ -2925,8 +3068,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bbr(?:a|azil|azilian)\W+(?:pt|por)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Portuguese), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bbr(?:a|azil|azilian)\W+(?:pt|por)\b").unwrap(),
+        transforms::uniq_concat(Language::Portuguese),
         RegexHandlerOptions {
             skip_if_already_found: false,
             remove: true,
@@ -2937,8 +3080,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:leg(?:endado|endas?)?|dub(?:lado)?|portugu[eèê]se?)[. -]*BR\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Portuguese), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:leg(?:endado|endas?)?|dub(?:lado)?|portugu[eèê]se?)[. -]*BR\b").unwrap(),
+        transforms::uniq_concat(Language::Portuguese),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -2948,8 +3091,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bleg(?:endado|endas?)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Portuguese), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bleg(?:endado|endas?)\b").unwrap(),
+        transforms::uniq_concat(Language::Portuguese),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -2959,8 +3102,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bportugu[eèê]s[ea]?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Portuguese), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bportugu[eèê]s[ea]?\b").unwrap(),
+        transforms::uniq_concat(Language::Portuguese),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -2970,8 +3113,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bPT[. -]*(?:PT|ENG?|sub(?:s|titles?))\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Portuguese), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bPT[. -]*(?:PT|ENG?|sub(?:s|titles?))\b").unwrap(),
+        transforms::uniq_concat(Language::Portuguese),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -2981,8 +3124,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bpt(?=\.(?:ass|ssa|srt|sub|idx)$)").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Portuguese), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bpt(?=\.(?:ass|ssa|srt|sub|idx)$)").unwrap(),
+        transforms::uniq_concat(Language::Portuguese),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -2993,8 +3136,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bpor\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Portuguese), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bpor\b").unwrap(),
+        transforms::uniq_concat(Language::Portuguese),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3005,8 +3148,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b-?ITA\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Italian), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b-?ITA\b").unwrap(),
+        transforms::uniq_concat(Language::Italian),
         RegexHandlerOptions {
             remove: true,
             skip_if_already_found: false,
@@ -3031,8 +3174,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::new(r"\b(?<!w{3}\.\w+\.)IT(?=[ .,/-]+(?:[a-zA-Z]{2}[ .,/-]+){2,})\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Italian), transforms::uniq_concat),
+        Pattern::new(r"\b(?<!w{3}\.\w+\.)IT(?=[ .,/-]+(?:[a-zA-Z]{2}[ .,/-]+){2,})\b").unwrap(),
+        transforms::uniq_concat(Language::Italian),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3043,8 +3186,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bit(?=\.(?:ass|ssa|srt|sub|idx)$)").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Italian), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bit(?=\.(?:ass|ssa|srt|sub|idx)$)").unwrap(),
+        transforms::uniq_concat(Language::Italian),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3055,8 +3198,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bitaliano?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Italian), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bitaliano?\b").unwrap(),
+        transforms::uniq_concat(Language::Italian),
         RegexHandlerOptions {
             skip_if_first: true,
             skip_if_already_found: false,
@@ -3067,8 +3210,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bgreek[ .-]*(?:audio|lang(?:uage)?|subs?(?:titles?)?)?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Greek), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bgreek[ .-]*(?:audio|lang(?:uage)?|subs?(?:titles?)?)?\b").unwrap(),
+        transforms::uniq_concat(Language::Greek),
         RegexHandlerOptions {
             skip_if_first: true,
             skip_if_already_found: false,
@@ -3079,8 +3222,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:GER|DEU)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::German), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:GER|DEU)\b").unwrap(),
+        transforms::uniq_concat(Language::German),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3091,8 +3234,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bde(?=[ .,/-]+(?:[A-Z]{2}[ .,/-]+){2,})\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::German), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bde(?=[ .,/-]+(?:[A-Z]{2}[ .,/-]+){2,})\b").unwrap(),
+        transforms::uniq_concat(Language::German),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3103,8 +3246,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?<=[ .,/-]+(?:[A-Z]{2}[ .,/-]+){2,})de\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::German), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?<=[ .,/-]+(?:[A-Z]{2}[ .,/-]+){2,})de\b").unwrap(),
+        transforms::uniq_concat(Language::German),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3115,8 +3258,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?<=[ .,/-]+[A-Z]{2}[ .,/-]+)de(?=[ .,/-]+[A-Z]{2}[ .,/-]+)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::German), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?<=[ .,/-]+[A-Z]{2}[ .,/-]+)de(?=[ .,/-]+[A-Z]{2}[ .,/-]+)\b").unwrap(),
+        transforms::uniq_concat(Language::German),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3127,8 +3270,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bde(?=\.(?:ass|ssa|srt|sub|idx)$)").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::German), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bde(?=\.(?:ass|ssa|srt|sub|idx)$)").unwrap(),
+        transforms::uniq_concat(Language::German),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3139,8 +3282,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(german|alem[aã]o)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::German), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(german|alem[aã]o)\b").unwrap(),
+        transforms::uniq_concat(Language::German),
         RegexHandlerOptions {
             skip_if_first: true,
             skip_if_already_found: false,
@@ -3151,8 +3294,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bRUS?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Russian), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bRUS?\b").unwrap(),
+        transforms::uniq_concat(Language::Russian),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -3178,8 +3321,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(russian|russo)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Russian), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(russian|russo)\b").unwrap(),
+        transforms::uniq_concat(Language::Russian),
         RegexHandlerOptions {
             skip_if_first: true,
             skip_if_already_found: false,
@@ -3190,8 +3333,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bUKR\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Ukrainian), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bUKR\b").unwrap(),
+        transforms::uniq_concat(Language::Ukrainian),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -3201,8 +3344,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bukrainian\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Ukrainian), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bukrainian\b").unwrap(),
+        transforms::uniq_concat(Language::Ukrainian),
         RegexHandlerOptions {
             skip_if_first: true,
             skip_if_already_found: false,
@@ -3213,8 +3356,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bhin(?:di)?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Hindi), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bhin(?:di)?\b").unwrap(),
+        transforms::uniq_concat(Language::Hindi),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -3224,8 +3367,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:(?<!w{3}\.\w+\.)tel(?!\W*aviv)|telugu)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Telugu), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:(?<!w{3}\.\w+\.)tel(?!\W*aviv)|telugu)\b").unwrap(),
+        transforms::uniq_concat(Language::Telugu),
         RegexHandlerOptions {
             remove: true,
             skip_if_already_found: false,
@@ -3236,8 +3379,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bt[aâ]m(?:il)?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Tamil), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bt[aâ]m(?:il)?\b").unwrap(),
+        transforms::uniq_concat(Language::Tamil),
         RegexHandlerOptions {
             remove: true,
             skip_if_already_found: false,
@@ -3248,8 +3391,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:(?<!w{3}\.\w+\.)MAL(?:ay)?|malayalam)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Malayalam), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:(?<!w{3}\.\w+\.)MAL(?:ay)?|malayalam)\b").unwrap(),
+        transforms::uniq_concat(Language::Malayalam),
         RegexHandlerOptions {
             remove: true,
             skip_if_first: true,
@@ -3261,8 +3404,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:(?<!w{3}\.\w+\.)KAN(?:nada)?|kannada)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Kannada), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:(?<!w{3}\.\w+\.)KAN(?:nada)?|kannada)\b").unwrap(),
+        transforms::uniq_concat(Language::Kannada),
         RegexHandlerOptions {
             remove: true,
             skip_if_already_found: false,
@@ -3273,8 +3416,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:(?<!w{3}\.\w+\.)MAR(?:a(?:thi)?)?|marathi)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Marathi), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:(?<!w{3}\.\w+\.)MAR(?:a(?:thi)?)?|marathi)\b").unwrap(),
+        transforms::uniq_concat(Language::Marathi),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -3284,8 +3427,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:(?<!w{3}\.\w+\.)GUJ(?:arati)?|gujarati)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Gujarati), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:(?<!w{3}\.\w+\.)GUJ(?:arati)?|gujarati)\b").unwrap(),
+        transforms::uniq_concat(Language::Gujarati),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -3295,8 +3438,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:(?<!w{3}\.\w+\.)PUN(?:jabi)?|punjabi)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Punjabi), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:(?<!w{3}\.\w+\.)PUN(?:jabi)?|punjabi)\b").unwrap(),
+        transforms::uniq_concat(Language::Punjabi),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -3306,8 +3449,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:(?<!w{3}\.\w+\.)BEN(?!.\bThe|and|of\b)(?:gali)?|bengali)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Bengali), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:(?<!w{3}\.\w+\.)BEN(?!.\bThe|and|of\b)(?:gali)?|bengali)\b").unwrap(),
+        transforms::uniq_concat(Language::Bengali),
         RegexHandlerOptions {
             skip_if_first: true,
             skip_if_already_found: false,
@@ -3318,8 +3461,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::new(r"\b(?<!YTS\.)LT\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Lithuanian), transforms::uniq_concat),
+        Pattern::new(r"\b(?<!YTS\.)LT\b").unwrap(),
+        transforms::uniq_concat(Language::Lithuanian),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3346,8 +3489,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\blithuanian\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Lithuanian), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\blithuanian\b").unwrap(),
+        transforms::uniq_concat(Language::Lithuanian),
         RegexHandlerOptions {
             skip_if_first: true,
             skip_if_already_found: false,
@@ -3358,8 +3501,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\blatvian\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Latvian), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\blatvian\b").unwrap(),
+        transforms::uniq_concat(Language::Latvian),
         RegexHandlerOptions {
             skip_if_first: true,
             skip_if_already_found: false,
@@ -3370,8 +3513,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bestonian\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Estonian), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bestonian\b").unwrap(),
+        transforms::uniq_concat(Language::Estonian),
         RegexHandlerOptions {
             skip_if_first: true,
             skip_if_already_found: false,
@@ -3382,8 +3525,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:(?<!w{3}\.\w+\.)PL|pol)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Polish), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:(?<!w{3}\.\w+\.)PL|pol)\b").unwrap(),
+        transforms::uniq_concat(Language::Polish),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -3393,8 +3536,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(polish|polon[eê]s|polaco)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Polish), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(polish|polon[eê]s|polaco)\b").unwrap(),
+        transforms::uniq_concat(Language::Polish),
         RegexHandlerOptions {
             skip_if_first: true,
             skip_if_already_found: false,
@@ -3405,8 +3548,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bCZ[EH]?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Czech), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bCZ[EH]?\b").unwrap(),
+        transforms::uniq_concat(Language::Czech),
         RegexHandlerOptions {
             skip_if_first: true,
             skip_if_already_found: false,
@@ -3417,8 +3560,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bczech\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Czech), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bczech\b").unwrap(),
+        transforms::uniq_concat(Language::Czech),
         RegexHandlerOptions {
             skip_if_first: true,
             skip_if_already_found: false,
@@ -3429,8 +3572,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bslo(?:vak|vakian|subs|[\]_)]?\.\w{2,4}$)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Slovak), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bslo(?:vak|vakian|subs|[\]_)]?\.\w{2,4}$)\b").unwrap(),
+        transforms::uniq_concat(Language::Slovak),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3441,8 +3584,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::new(r"\bHU\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Hungarian), transforms::uniq_concat),
+        Pattern::new(r"\bHU\b").unwrap(),
+        transforms::uniq_concat(Language::Hungarian),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3453,8 +3596,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bHUN(?:garian)?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Hungarian), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bHUN(?:garian)?\b").unwrap(),
+        transforms::uniq_concat(Language::Hungarian),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3465,8 +3608,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bROM(?:anian)?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Romanian), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bROM(?:anian)?\b").unwrap(),
+        transforms::uniq_concat(Language::Romanian),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3477,8 +3620,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bRO(?=[ .,/-]*(?:[A-Z]{2}[ .,/-]+)*sub)").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Romanian), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bRO(?=[ .,/-]*(?:[A-Z]{2}[ .,/-]+)*sub)").unwrap(),
+        transforms::uniq_concat(Language::Romanian),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -3488,8 +3631,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bbul(?:garian)?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Bulgarian), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bbul(?:garian)?\b").unwrap(),
+        transforms::uniq_concat(Language::Bulgarian),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3515,8 +3658,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:srp|serbian)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Serbian), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:srp|serbian)\b").unwrap(),
+        transforms::uniq_concat(Language::Serbian),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -3526,8 +3669,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:HRV|croatian)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Croatian), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:HRV|croatian)\b").unwrap(),
+        transforms::uniq_concat(Language::Croatian),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -3537,8 +3680,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bHR(?=[ .,/-]*(?:[A-Z]{2}[ .,/-]+)*sub)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Croatian), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bHR(?=[ .,/-]*(?:[A-Z]{2}[ .,/-]+)*sub)\b").unwrap(),
+        transforms::uniq_concat(Language::Croatian),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -3548,8 +3691,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bslovenian\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Slovenian), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bslovenian\b").unwrap(),
+        transforms::uniq_concat(Language::Slovenian),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3560,8 +3703,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:(?<!w{3}\.\w+\.)NL|dut|holand[eê]s)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Dutch), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:(?<!w{3}\.\w+\.)NL|dut|holand[eê]s)\b").unwrap(),
+        transforms::uniq_concat(Language::Dutch),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -3571,8 +3714,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bdutch\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Dutch), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bdutch\b").unwrap(),
+        transforms::uniq_concat(Language::Dutch),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3583,8 +3726,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bflemish\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Dutch), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bflemish\b").unwrap(),
+        transforms::uniq_concat(Language::Dutch),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -3594,8 +3737,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:DK|danska|dansub|nordic)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Danish), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:DK|danska|dansub|nordic)\b").unwrap(),
+        transforms::uniq_concat(Language::Danish),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -3605,8 +3748,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(danish|dinamarqu[eê]s)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Danish), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(danish|dinamarqu[eê]s)\b").unwrap(),
+        transforms::uniq_concat(Language::Danish),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3617,8 +3760,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bdan\b(?=.*\.(?:srt|vtt|ssa|ass|sub|idx)$)").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Danish), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bdan\b(?=.*\.(?:srt|vtt|ssa|ass|sub|idx)$)").unwrap(),
+        transforms::uniq_concat(Language::Danish),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3629,8 +3772,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:(?<!w{3}\.\w+\.)FI|finsk|finsub|nordic)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Finnish), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:(?<!w{3}\.\w+\.)FI|finsk|finsub|nordic)\b").unwrap(),
+        transforms::uniq_concat(Language::Finnish),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -3640,8 +3783,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bfinnish\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Finnish), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bfinnish\b").unwrap(),
+        transforms::uniq_concat(Language::Finnish),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3664,8 +3807,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:(?<!w{3}\.\w+\.)SE|swe|swesubs?|sv(?:ensk)?|nordic)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Swedish), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:(?<!w{3}\.\w+\.)SE|swe|swesubs?|sv(?:ensk)?|nordic)\b").unwrap(),
+        transforms::uniq_concat(Language::Swedish),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -3675,8 +3818,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(swedish|sueco)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Swedish), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(swedish|sueco)\b").unwrap(),
+        transforms::uniq_concat(Language::Swedish),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3687,8 +3830,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:NOR|norsk|norsub|nordic)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Norwegian), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:NOR|norsk|norsub|nordic)\b").unwrap(),
+        transforms::uniq_concat(Language::Norwegian),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -3698,8 +3841,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(norwegian|noruegu[eê]s|bokm[aå]l|nob|nor(?=[\]_)]?\.\w{2,4}$))\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Norwegian), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(norwegian|noruegu[eê]s|bokm[aå]l|nob|nor(?=[\]_)]?\.\w{2,4}$))\b").unwrap(),
+        transforms::uniq_concat(Language::Norwegian),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3710,8 +3853,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:arabic|[aá]rabe|ara)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Arabic), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:arabic|[aá]rabe|ara)\b").unwrap(),
+        transforms::uniq_concat(Language::Arabic),
         RegexHandlerOptions {
             skip_if_first: true,
             skip_if_already_found: false,
@@ -3722,8 +3865,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\barab.*(?:audio|lang(?:uage)?|sub(?:s|titles?)?)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Arabic), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\barab.*(?:audio|lang(?:uage)?|sub(?:s|titles?)?)\b").unwrap(),
+        transforms::uniq_concat(Language::Arabic),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3734,8 +3877,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bar(?=\.(?:ass|ssa|srt|sub|idx)$)").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Arabic), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bar(?=\.(?:ass|ssa|srt|sub|idx)$)").unwrap(),
+        transforms::uniq_concat(Language::Arabic),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3746,8 +3889,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:turkish|tur(?:co)?)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Turkish), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:turkish|tur(?:co)?)\b").unwrap(),
+        transforms::uniq_concat(Language::Turkish),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3758,8 +3901,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(TİVİBU|tivibu|bitturk(.net)?|turktorrent)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Turkish), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(TİVİBU|tivibu|bitturk(.net)?|turktorrent)\b").unwrap(),
+        transforms::uniq_concat(Language::Turkish),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3780,8 +3923,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bvietnamese\b|\bvie(?=[\]_)]?\.\w{2,4}$)").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Vietnamese), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bvietnamese\b|\bvie(?=[\]_)]?\.\w{2,4}$)").unwrap(),
+        transforms::uniq_concat(Language::Vietnamese),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3792,8 +3935,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bind(?:onesian)?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Indonesian), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bind(?:onesian)?\b").unwrap(),
+        transforms::uniq_concat(Language::Indonesian),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3804,8 +3947,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(thai|tailand[eê]s)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Thai), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(thai|tailand[eê]s)\b").unwrap(),
+        transforms::uniq_concat(Language::Thai),
         RegexHandlerOptions {
             skip_if_first: true,
             skip_if_already_found: false,
@@ -3816,8 +3959,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::new(r"\b(THA|tha)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Thai), transforms::uniq_concat),
+        Pattern::new(r"\b(THA|tha)\b").unwrap(),
+        transforms::uniq_concat(Language::Thai),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3828,8 +3971,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(?:malay|may(?=[\]_)]?\.\w{2,4}$)|(?<=subs?\([a-z,]+)may)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Malay), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(?:malay|may(?=[\]_)]?\.\w{2,4}$)|(?<=subs?\([a-z,]+)may)\b").unwrap(),
+        transforms::uniq_concat(Language::Malay),
         RegexHandlerOptions {
             skip_if_first: true,
             skip_if_already_found: false,
@@ -3840,8 +3983,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\bheb(?:rew|raico)?\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Hebrew), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bheb(?:rew|raico)?\b").unwrap(),
+        transforms::uniq_concat(Language::Hebrew),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3852,8 +3995,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"\b(persian|persa)\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_with_value(Language::Persian), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\b(persian|persa)\b").unwrap(),
+        transforms::uniq_concat(Language::Persian),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3881,8 +4024,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"[\u3040-\u30ff]+").unwrap(), // japanese
-        transforms::chain_transforms(transforms::replace_with_value(Language::Japanese), transforms::uniq_concat),
+        Pattern::case_insensitive(r"[\u3040-\u30ff]+").unwrap(), // japanese
+        transforms::uniq_concat(Language::Japanese),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3892,8 +4035,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"[\u3400-\u4dbf]+").unwrap(), // chinese
-        transforms::chain_transforms(transforms::replace_with_value(Language::Chinese), transforms::uniq_concat),
+        Pattern::case_insensitive(r"[\u3400-\u4dbf]+").unwrap(), // chinese
+        transforms::uniq_concat(Language::Chinese),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3903,8 +4046,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"[\u4e00-\u9fff]+").unwrap(), // chinese
-        transforms::chain_transforms(transforms::replace_with_value(Language::Chinese), transforms::uniq_concat),
+        Pattern::case_insensitive(r"[\u4e00-\u9fff]+").unwrap(), // chinese
+        transforms::uniq_concat(Language::Chinese),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3914,8 +4057,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"[\uf900-\ufaff]+").unwrap(), // chinese
-        transforms::chain_transforms(transforms::replace_with_value(Language::Chinese), transforms::uniq_concat),
+        Pattern::case_insensitive(r"[\uf900-\ufaff]+").unwrap(), // chinese
+        transforms::uniq_concat(Language::Chinese),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3925,8 +4068,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"[\uff66-\uff9f]+").unwrap(), // japanese
-        transforms::chain_transforms(transforms::replace_with_value(Language::Japanese), transforms::uniq_concat),
+        Pattern::case_insensitive(r"[\uff66-\uff9f]+").unwrap(), // japanese
+        transforms::uniq_concat(Language::Japanese),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3936,8 +4079,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"[\u0400-\u04ff]+").unwrap(), // russian
-        transforms::chain_transforms(transforms::replace_with_value(Language::Russian), transforms::uniq_concat),
+        Pattern::case_insensitive(r"[\u0400-\u04ff]+").unwrap(), // russian
+        transforms::uniq_concat(Language::Russian),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3947,8 +4090,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"[\u0600-\u06ff]+").unwrap(), // arabic
-        transforms::chain_transforms(transforms::replace_with_value(Language::Arabic), transforms::uniq_concat),
+        Pattern::case_insensitive(r"[\u0600-\u06ff]+").unwrap(), // arabic
+        transforms::uniq_concat(Language::Arabic),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3958,8 +4101,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"[\u0750-\u077f]+").unwrap(), // arabic
-        transforms::chain_transforms(transforms::replace_with_value(Language::Arabic), transforms::uniq_concat),
+        Pattern::case_insensitive(r"[\u0750-\u077f]+").unwrap(), // arabic
+        transforms::uniq_concat(Language::Arabic),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3969,8 +4112,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"[\u0c80-\u0cff]+").unwrap(), // kannada
-        transforms::chain_transforms(transforms::replace_with_value(Language::Kannada), transforms::uniq_concat),
+        Pattern::case_insensitive(r"[\u0c80-\u0cff]+").unwrap(), // kannada
+        transforms::uniq_concat(Language::Kannada),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3980,8 +4123,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"[\u0d00-\u0d7f]+").unwrap(), // malayalam
-        transforms::chain_transforms(transforms::replace_with_value(Language::Malayalam), transforms::uniq_concat),
+        Pattern::case_insensitive(r"[\u0d00-\u0d7f]+").unwrap(), // malayalam
+        transforms::uniq_concat(Language::Malayalam),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -3991,8 +4134,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"[\u0e00-\u0e7f]+").unwrap(), // thai
-        transforms::chain_transforms(transforms::replace_with_value(Language::Thai), transforms::uniq_concat),
+        Pattern::case_insensitive(r"[\u0e00-\u0e7f]+").unwrap(), // thai
+        transforms::uniq_concat(Language::Thai),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -4002,8 +4145,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"[\u0900-\u097f]+").unwrap(), // hindi
-        transforms::chain_transforms(transforms::replace_with_value(Language::Hindi), transforms::uniq_concat),
+        Pattern::case_insensitive(r"[\u0900-\u097f]+").unwrap(), // hindi
+        transforms::uniq_concat(Language::Hindi),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -4013,8 +4156,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"[\u0980-\u09ff]+").unwrap(), // bengali
-        transforms::chain_transforms(transforms::replace_with_value(Language::Bengali), transforms::uniq_concat),
+        Pattern::case_insensitive(r"[\u0980-\u09ff]+").unwrap(), // bengali
+        transforms::uniq_concat(Language::Bengali),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -4024,8 +4167,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "languages",
         |r| &mut r.languages,
-        Regex::case_insensitive(r"[\u0a00-\u0a7f]+").unwrap(), // gujarati
-        transforms::chain_transforms(transforms::replace_with_value(Language::Gujarati), transforms::uniq_concat),
+        Pattern::case_insensitive(r"[\u0a00-\u0a7f]+").unwrap(), // gujarati
+        transforms::uniq_concat(Language::Gujarati),
         RegexHandlerOptions {
             skip_from_title: true,
             skip_if_already_found: false,
@@ -4053,27 +4196,35 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
         static ref PT_LANG_RE2: Regex = Regex::case_insensitive(r"dublado").unwrap();
     }
 
-    parser.add_handler(Handler::new("languages", |context| {
-        if !context
-            .result
-            .languages
-            .iter()
-            .any(|lang| lang == &Language::Portuguese || lang == &Language::Spanish)
-        {
-            // Checking if episode naming convention suggests Portuguese language
-            if (context
-                .matched
-                .get("episodes")
-                .map(|e| &e.raw_match)
-                .map(|raw| PT_LANG_RE1.contains_match(raw))
-                .unwrap_or(false))
-                || PT_LANG_RE2.contains_match(&context.title)
+    parser.add_handler(
+        Handler::new("languages", |context| {
+            if context
+                .result
+                .languages
+                .iter()
+                .any(|lang| lang == &Language::Portuguese || lang == &Language::Spanish)
             {
-                context.result.languages.push(Language::Portuguese);
+                return None;
             }
-        }
-        None
-    }));
+            // Checking if episode naming convention suggests Portuguese language
+            let (raw_match, match_index) = match context.matched.get("episodes") {
+                Some(episodes) if PT_LANG_RE1.contains_match(&episodes.raw_match) => (episodes.raw_match.clone(), episodes.match_index),
+                _ => {
+                    let m = PT_LANG_RE2.find_str(context.title)?;
+                    (m.as_str().to_string(), m.start())
+                }
+            };
+            context.result.languages.push(Language::Portuguese);
+            Some(HandlerResult {
+                raw_match,
+                match_index,
+                remove: false,
+                skip_from_title: true,
+            })
+        })
+        .with_confidence(Confidence::Inferred)
+        .with_dependencies(&["episodes"]),
+    );
 
     /*
     # Subbed
@@ -4085,8 +4236,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "subbed",
         |r| &mut r.subbed,
-        Regex::case_insensitive(r"\b(?:Official.*?|Dual-?)?sub(s|bed)?\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\b(?:Official.*?|Dual-?)?sub(s|bed)?\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -4095,8 +4246,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "subbed",
         |r| &mut r.subbed,
-        Regex::case_insensitive(r"\bmulti(?:ple)?[ .-]*(?:su?$|sub\w*|dub\w*)\b|msub").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\bmulti(?:ple)?[ .-]*(?:su?$|sub\w*|dub\w*)\b|msub").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             skip_if_already_found: false,
             remove: true,
@@ -4120,8 +4271,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "dubbed",
         |r| &mut r.dubbed,
-        Regex::case_insensitive(r"\bmulti(?:ple)?[ .-]*(?:lang(?:uages?)?|audio|VF2)?\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\bmulti(?:ple)?[ .-]*(?:lang(?:uages?)?|audio|VF2)?\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: true,
             skip_if_already_found: false,
@@ -4131,8 +4282,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "dubbed",
         |r| &mut r.dubbed,
-        Regex::case_insensitive(r"\btri(?:ple)?[ .-]*(?:audio|dub\w*)\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\btri(?:ple)?[ .-]*(?:audio|dub\w*)\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -4141,8 +4292,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "dubbed",
         |r| &mut r.dubbed,
-        Regex::case_insensitive(r"\bdual[ .-]*(?:au?$|[aá]udio|line)\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\bdual[ .-]*(?:au?$|[aá]udio|line)\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -4151,8 +4302,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "dubbed",
         |r| &mut r.dubbed,
-        Regex::case_insensitive(r"\bdual\b(?![ .-]*sub)").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\bdual\b(?![ .-]*sub)").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             skip_if_already_found: false,
             ..Default::default()
@@ -4161,8 +4312,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "dubbed",
         |r| &mut r.dubbed,
-        Regex::case_insensitive(r"\b(fan\s?dub)\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\b(fan\s?dub)\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: true,
             skip_from_title: true,
@@ -4172,8 +4323,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "dubbed",
         |r| &mut r.dubbed,
-        Regex::case_insensitive(r"\b(Fan.*)?(?:DUBBED|dublado|dubbing|DUBS?)\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\b(Fan.*)?(?:DUBBED|dublado|dubbing|DUBS?)\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -4182,8 +4333,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "dubbed",
         |r| &mut r.dubbed,
-        Regex::case_insensitive(r"\b(?!.*\bsub(s|bed)?\b)([ _\-\[(\.])?(dual|multi)([ _\-\[(\.])?(audio)?\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\b(?!.*\bsub(s|bed)?\b)([ _\-\[(\.])?(dual|multi)([ _\-\[(\.])?(audio)?\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -4192,8 +4343,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "dubbed",
         |r| &mut r.dubbed,
-        Regex::case_insensitive(r"\b(JAP?(anese)?|ZH)\+ENG?(lish)?|ENG?(lish)?\+(JAP?(anese)?|ZH)\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\b(JAP?(anese)?|ZH)\+ENG?(lish)?|ENG?(lish)?\+(JAP?(anese)?|ZH)\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -4217,9 +4368,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
 
     // Group (again)
     parser.add_handler(Handler::new("group", |context| {
-        let Some(group_matched) = context.matched.get("group") else {
-            return None;
-        };
+        let group_matched = context.matched.get("group")?;
         if group_matched.raw_match.starts_with('[') && group_matched.raw_match.ends_with(']') {
             let end_index = group_matched.match_index + group_matched.raw_match.len();
 
@@ -4248,8 +4397,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "3d",
         |r| &mut r.is_3d,
-        Regex::case_insensitive(r"(?<=\b[12]\d{3}\b).*\b(3d|sbs|half[ .-]ou|half[ .-]sbs)\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"(?<=\b[12]\d{3}\b).*\b(3d|sbs|half[ .-]ou|half[ .-]sbs)\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: false,
             skip_if_first: true,
@@ -4259,8 +4408,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "3d",
         |r| &mut r.is_3d,
-        Regex::case_insensitive(r"\b((Half.)?SBS|HSBS)\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\b((Half.)?SBS|HSBS)\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: false,
             skip_if_first: true,
@@ -4270,8 +4419,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "3d",
         |r| &mut r.is_3d,
-        Regex::case_insensitive(r"\bBluRay3D\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\bBluRay3D\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: false,
             skip_if_first: true,
@@ -4281,8 +4430,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "3d",
         |r| &mut r.is_3d,
-        Regex::case_insensitive(r"\bBD3D\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\bBD3D\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: false,
             skip_if_first: true,
@@ -4292,8 +4441,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "3d",
         |r| &mut r.is_3d,
-        Regex::case_insensitive(r"\b3D\b").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"\b3D\b").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: false,
             skip_if_first: true,
@@ -4310,8 +4459,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "size",
         |r| &mut r.size,
-        Regex::case_insensitive(r"\b(\d+(\.\d+)?\s?(MB|GB|TB))\b").unwrap(),
-        transforms::identity,
+        Pattern::case_insensitive(r"\b(\d+(\.\d+)?\s?(MB|GB|TB))\b").unwrap(),
+        transforms::identity(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -4328,7 +4477,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "site",
         |r| &mut r.site,
-        Regex::case_insensitive(r"\[([^\]]+\.[^\]]+)\](?=\.\w{2,4}$|\s)").unwrap(),
+        Pattern::case_insensitive(r"\[([^\]]+\.[^\]]+)\](?=\.\w{2,4}$|\s)").unwrap(),
         transforms::value("$1"),
         RegexHandlerOptions {
             remove: true,
@@ -4338,7 +4487,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "site",
         |r| &mut r.site,
-        Regex::case_insensitive(r"\bwww\.\w*\.\w+\b").unwrap(),
+        Pattern::case_insensitive(r"\bwww\.\w*\.\w+\b").unwrap(),
         transforms::value("$1"),
         RegexHandlerOptions {
             remove: true,
@@ -4371,7 +4520,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "network",
         |r| &mut r.network,
-        Regex::case_insensitive(r"\bATVP?\b").unwrap(),
+        Pattern::case_insensitive(r"\bATVP?\b").unwrap(),
         transforms::const_value(Network::AppleTV),
         RegexHandlerOptions {
             remove: true,
@@ -4381,7 +4530,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "network",
         |r| &mut r.network,
-        Regex::case_insensitive(r"\bAMZN\b").unwrap(),
+        Pattern::case_insensitive(r"\bAMZN\b").unwrap(),
         transforms::const_value(Network::Amazon),
         RegexHandlerOptions {
             remove: true,
@@ -4391,7 +4540,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "network",
         |r| &mut r.network,
-        Regex::case_insensitive(r"\bNF|Netflix\b").unwrap(),
+        Pattern::case_insensitive(r"\bNF|Netflix\b").unwrap(),
         transforms::const_value(Network::Netflix),
         RegexHandlerOptions {
             remove: true,
@@ -4401,7 +4550,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "network",
         |r| &mut r.network,
-        Regex::case_insensitive(r"\bNICK(elodeon)?\b").unwrap(),
+        Pattern::case_insensitive(r"\bNICK(elodeon)?\b").unwrap(),
         transforms::const_value(Network::Nickelodeon),
         RegexHandlerOptions {
             remove: true,
@@ -4411,7 +4560,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "network",
         |r| &mut r.network,
-        Regex::case_insensitive(r"\bDSNY?P?\b").unwrap(),
+        Pattern::case_insensitive(r"\bDSNY?P?\b").unwrap(),
         transforms::const_value(Network::Disney),
         RegexHandlerOptions {
             remove: true,
@@ -4421,7 +4570,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "network",
         |r| &mut r.network,
-        Regex::case_insensitive(r"\bH(MAX|BO)\b").unwrap(),
+        Pattern::case_insensitive(r"\bH(MAX|BO)\b").unwrap(),
         transforms::const_value(Network::HBO),
         RegexHandlerOptions {
             remove: true,
@@ -4431,7 +4580,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "network",
         |r| &mut r.network,
-        Regex::case_insensitive(r"\bHULU\b").unwrap(),
+        Pattern::case_insensitive(r"\bHULU\b").unwrap(),
         transforms::const_value(Network::Hulu),
         RegexHandlerOptions {
             remove: true,
@@ -4441,7 +4590,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "network",
         |r| &mut r.network,
-        Regex::case_insensitive(r"\bCBS\b").unwrap(),
+        Pattern::case_insensitive(r"\bCBS\b").unwrap(),
         transforms::const_value(Network::CBS),
         RegexHandlerOptions {
             remove: true,
@@ -4451,7 +4600,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "network",
         |r| &mut r.network,
-        Regex::case_insensitive(r"\bNBC\b").unwrap(),
+        Pattern::case_insensitive(r"\bNBC\b").unwrap(),
         transforms::const_value(Network::NBC),
         RegexHandlerOptions {
             remove: true,
@@ -4461,7 +4610,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "network",
         |r| &mut r.network,
-        Regex::case_insensitive(r"\bAMC\b").unwrap(),
+        Pattern::case_insensitive(r"\bAMC\b").unwrap(),
         transforms::const_value(Network::AMC),
         RegexHandlerOptions {
             remove: true,
@@ -4471,7 +4620,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "network",
         |r| &mut r.network,
-        Regex::case_insensitive(r"\bPBS\b").unwrap(),
+        Pattern::case_insensitive(r"\bPBS\b").unwrap(),
         transforms::const_value(Network::PBS),
         RegexHandlerOptions {
             remove: true,
@@ -4481,7 +4630,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "network",
         |r| &mut r.network,
-        Regex::case_insensitive(r"\b(Crunchyroll|[. -]CR[. -])\b").unwrap(),
+        Pattern::case_insensitive(r"\b(Crunchyroll|[. -]CR[. -])\b").unwrap(),
         transforms::const_value(Network::Crunchyroll),
         RegexHandlerOptions {
             remove: true,
@@ -4491,7 +4640,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "network",
         |r| &mut r.network,
-        Regex::case_insensitive(r"\bVICE\b").unwrap(),
+        Pattern::case_insensitive(r"\bVICE\b").unwrap(),
         transforms::const_value(Network::VICE),
         RegexHandlerOptions {
             remove: true,
@@ -4501,7 +4650,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "network",
         |r| &mut r.network,
-        Regex::case_insensitive(r"\bSony\b").unwrap(),
+        Pattern::case_insensitive(r"\bSony\b").unwrap(),
         transforms::const_value(Network::Sony),
         RegexHandlerOptions {
             remove: true,
@@ -4511,7 +4660,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "network",
         |r| &mut r.network,
-        Regex::case_insensitive(r"\bHallmark\b").unwrap(),
+        Pattern::case_insensitive(r"\bHallmark\b").unwrap(),
         transforms::const_value(Network::Hallmark),
         RegexHandlerOptions {
             remove: true,
@@ -4521,7 +4670,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "network",
         |r| &mut r.network,
-        Regex::case_insensitive(r"\bAdult.?Swim\b").unwrap(),
+        Pattern::case_insensitive(r"\bAdult.?Swim\b").unwrap(),
         transforms::const_value(Network::AdultSwim),
         RegexHandlerOptions {
             remove: true,
@@ -4531,7 +4680,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "network",
         |r| &mut r.network,
-        Regex::case_insensitive(r"\bAnimal.?Planet|ANPL\b").unwrap(),
+        Pattern::case_insensitive(r"\bAnimal.?Planet|ANPL\b").unwrap(),
         transforms::const_value(Network::AnimalPlanet),
         RegexHandlerOptions {
             remove: true,
@@ -4549,15 +4698,15 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "extension",
         |r| &mut r.extension,
-        Regex::case_insensitive(r"\.(3g2|3gp|avi|flv|mkv|mk3d|mov|mp2|mp4|m4v|mpe|mpeg|mpg|mpv|webm|wmv|ogm|divx|ts|m2ts|iso|vob|sub|idx|ttxt|txt|smi|srt|ssa|ass|vtt|nfo|html)$").unwrap(),
-        transforms::lowercase,
+        Pattern::case_insensitive(r"\.(3g2|3gp|avi|flv|mkv|mk3d|mov|mp2|mp4|m4v|mpe|mpeg|mpg|mpv|webm|wmv|ogm|divx|ts|m2ts|iso|vob|sub|idx|ttxt|txt|smi|srt|ssa|ass|vtt|nfo|html)$").unwrap(),
+        transforms::lowercase(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "audio",
         |r| &mut r.audio,
-        Regex::case_insensitive(r"\bMP3\b").unwrap(),
-        transforms::chain_transforms(transforms::replace_value("MP3"), transforms::uniq_concat),
+        Pattern::case_insensitive(r"\bMP3\b").unwrap(),
+        transforms::uniq_concat(AudioCodec::Mp3),
         RegexHandlerOptions {
             remove: true,
             skip_if_already_found: false,
@@ -4585,14 +4734,14 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "group",
         |r| &mut r.group,
-        Regex::new(r"\(([\w-]+)\)(?:$|\.\w{2,4}$)").unwrap(),
-        transforms::identity,
+        Pattern::new(r"\(([\w-]+)\)(?:$|\.\w{2,4}$)").unwrap(),
+        transforms::identity(),
         RegexHandlerOptions::default(),
     ));
     parser.add_handler(Handler::from_regex(
         "group",
         |r| &mut r.group,
-        Regex::new(r"\b(INFLATE|DEFLATE)\b").unwrap(),
+        Pattern::new(r"\b(INFLATE|DEFLATE)\b").unwrap(),
         transforms::value("$1"),
         RegexHandlerOptions {
             remove: true,
@@ -4602,7 +4751,7 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "group",
         |r| &mut r.group,
-        Regex::case_insensitive(r"\b(?:Erai-raws|Erai-raws\.com)\b").unwrap(),
+        Pattern::case_insensitive(r"\b(?:Erai-raws|Erai-raws\.com)\b").unwrap(),
         transforms::value("Erai-raws"),
         RegexHandlerOptions {
             remove: true,
@@ -4612,19 +4761,22 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "group",
         |r| &mut r.group,
-        Regex::new(r"^\[([^[\]]+)]").unwrap(),
-        transforms::identity,
+        Pattern::new(r"^\[([^[\]]+)]").unwrap(),
+        transforms::identity(),
         RegexHandlerOptions::default(),
     ));
 
-    parser.add_handler(Handler::new("group", |context| {
-        if let Some(group) = &context.result.group {
-            if group == "-" || group == "" {
-                context.result.group = None; // remove this from groups
+    parser.add_handler(
+        Handler::new("group", |context| {
+            if let Some(group) = &context.result.group {
+                if group == "-" || group.is_empty() {
+                    context.result.group = None; // remove this from groups
+                }
             }
-        }
-        None
-    }));
+            None
+        })
+        .with_dependencies(&[]),
+    );
 
     /*
     parser.add_handler("trash", regex.compile(r"acesse o original", regex.IGNORECASE), boolean, {"remove": True})
@@ -4634,8 +4786,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "trash",
         |r| &mut r.trash,
-        Regex::case_insensitive(r"acesse o original").unwrap(),
-        transforms::true_if_found,
+        Pattern::case_insensitive(r"acesse o original").unwrap(),
+        transforms::true_if_found(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -4652,8 +4804,8 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "title",
         |r| &mut r.title,
-        Regex::case_insensitive(r"\b100[ .-]*years?[ .-]*quest\b").unwrap(),
-        transforms::identity_non_optional,
+        Pattern::case_insensitive(r"\b100[ .-]*years?[ .-]*quest\b").unwrap(),
+        transforms::identity_non_optional(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
@@ -4662,11 +4814,89 @@ pub fn add_default_handlers(parser: &mut super::Parser) {
     parser.add_handler(Handler::from_regex(
         "title",
         |r| &mut r.title,
-        Regex::case_insensitive(r"\b(?:INTEGRALE?|INTÉGRALE?|INTERNAL|HFR)\b").unwrap(),
-        transforms::identity_non_optional,
+        Pattern::case_insensitive(r"\b(?:INTEGRALE?|INTÉGRALE?|INTERNAL|HFR)\b").unwrap(),
+        transforms::identity_non_optional(),
         RegexHandlerOptions {
             remove: true,
             ..Default::default()
         },
     ));
+
+    // Absolute episodes: anime releases without any season are numbered from the first episode
+    lazy_static! {
+        static ref SEASON_WORD_REGEX: Regex = Regex::case_insensitive(r"\b(?:season|saison|part|cour)\b").unwrap();
+        static ref FANSUB_EPISODE_REGEX: Regex = Regex::new(r"[ _]-[ _]\d{1,4}(?:v\d{1,2})?(?:[ _.\[(]|$)").unwrap();
+        // "Chihayafuru 3 - 21" counts from the start of the third season
+        static ref SEQUEL_EPISODE_REGEX: Regex = Regex::new(r"[ _.](?:\d{1,2}|II|III|IV)[ _]-[ _]\d{1,4}\b").unwrap();
+    }
+
+    parser.add_handler(
+        Handler::new("absolute_episodes", |context| {
+            let result = context.result;
+            if !result.absolute_episodes.is_empty() || !result.seasons.is_empty() || result.episodes.is_empty() {
+                return None;
+            }
+            let fansub_episode = FANSUB_EPISODE_REGEX.find_str(context.title).filter(|_| {
+                context
+                    .matched
+                    .get("group")
+                    .is_some_and(|group| group.match_index == 0 && group.raw_match.starts_with('['))
+            });
+            if result.checksum.is_none() && fansub_episode.is_none()
+                || SEASON_WORD_REGEX.contains_match(context.title)
+                || SEQUEL_EPISODE_REGEX.contains_match(context.title)
+            {
+                return None;
+            }
+            // point at the episode number, or the checksum that marks the release as anime
+            let (raw_match, match_index) = match (context.matched.get("episodes"), fansub_episode) {
+                (Some(episodes), _) => (episodes.raw_match.clone(), episodes.match_index),
+                (None, Some(m)) => (m.as_str().to_string(), m.start()),
+                (None, None) => {
+                    let checksum = context.matched.get("checksum")?;
+                    (checksum.raw_match.clone(), checksum.match_index)
+                }
+            };
+            result.absolute_episodes = result.episodes.clone();
+            Some(HandlerResult {
+                raw_match,
+                match_index,
+                remove: false,
+                skip_from_title: true,
+            })
+        })
+        .with_confidence(Confidence::Heuristic)
+        .with_dependencies(&["group", "checksum", "seasons", "episodes"]),
+    );
+
+    // Country Code (only as the last word of the title followed by release metadata, e.g. "The Office US S01", and
+    // kept in the title)
+    lazy_static! {
+        static ref COUNTRY_SUFFIX_REGEX: Regex = Regex::new(r"\S[ .\-_]+[(\[]?(US|UK|AU|NZ|CA|IE)[)\]]?[ .\-_(\[]*$").unwrap();
+    }
+
+    parser.add_handler(
+        Handler::new("country", |context| {
+            let title = &context.title[..floor_char_boundary(context.title, context.end_of_title)];
+            let m = COUNTRY_SUFFIX_REGEX.find_str(title)?;
+            let code = m.group(1)?;
+            // a bare "Sherlock Holmes CA" could be part of the name, the release has to go on with a year, season or resolution
+            let metadata_follows = ["year", "seasons", "episodes", "resolution"]
+                .iter()
+                .filter_map(|field| context.matched.get(*field))
+                .any(|metadata| metadata.match_index >= code.range.end);
+            if !metadata_follows {
+                return None;
+            }
+            context.result.country = Some(code.as_str().parse().ok()?);
+            Some(HandlerResult {
+                raw_match: code.as_str().to_string(),
+                match_index: code.range.start,
+                remove: false,
+                skip_from_title: true,
+            })
+        })
+        .with_dependencies(&["year", "seasons", "episodes", "resolution"])
+        .with_end_of_title_dependency(),
+    );
 }
@@ -1,69 +1,269 @@
+use std::collections::BTreeMap;
+use std::ops::Range;
+
 use thiserror::Error;
 
+#[cfg(feature = "crc")]
+mod crc;
 mod extensions;
 mod handler_wrapper;
 mod handlers;
 mod parser;
-mod transforms;
+mod prefilter;
+#[cfg(feature = "rules")]
+mod rules;
+mod span;
+mod trace;
+pub mod transforms;
 mod types;
 
-pub use parser::Parser;
-pub use types::{Codec, Language, Network, Quality};
+pub use extensions::regex::RegexStringExt;
+pub use handler_wrapper::{
+    Handler, HandlerContext, HandlerInfo, HandlerResult, Match, Pattern, PropertyIsSet, RegexHandlerOptions, TrimIfString,
+};
+pub use parser::{ParseOptions, Parser, DEFAULT_MAX_LENGTH};
+pub use regress;
+#[cfg(feature = "rules")]
+pub use rules::{Rule, RuleError, RulePack, RuleTransform};
+pub use span::{FieldSpan, LeftoverToken};
+pub use trace::{HandlerTrace, SkipReason};
+pub use transforms::{Transform, TransformInfo};
+pub use types::{
+    AlternativeTitle, AudioChannels, AudioCodec, Codec, Confidence, Crc32, Credit, CreditRole, Edition, FieldValue, HdrFormat, Language,
+    Network, Quality, RemakeCountry, Resolution, Script, UnknownVariantError,
+};
 
 #[derive(Debug, Error)]
 pub enum ParserError {
-    #[error("Failed to parse title")]
-    ParseError(String),
+    #[error("Title is empty")]
+    Empty,
+    #[error("Title is {length} bytes long, the limit is {limit}")]
+    TooLong { length: usize, limit: usize },
+    /// Only with [`ParseOptions::require_title`]
+    #[error("No title could be derived")]
+    NoTitle,
+    #[error("Not a release name but a {0}")]
+    NotAReleaseName(RejectedInput),
+}
+
+/// What an input rejected with [`ParserError::NotAReleaseName`] looks like instead
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RejectedInput {
+    /// A link (e.g. "https://..." or a magnet link without a `dn` name) without anything else
+    Url,
+    /// Only a hex hash like a torrent info hash
+    Hash,
+}
+
+impl std::fmt::Display for RejectedInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RejectedInput::Url => write!(f, "URL"),
+            RejectedInput::Hash => write!(f, "hash"),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
 pub struct ParsedTitle {
     pub title: String,
-    pub resolution: Option<String>,
+    /// Titles in other scripts that were cut from `title`, e.g. the Russian name before a `/`
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    pub alternative_titles: Vec<AlternativeTitle>,
+    /// The part of the title in a non-Latin script (e.g. "進撃の巨人" next to "Shingeki no Kyojin"), only set when
+    /// `title` is the Latin one
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub native_title: Option<String>,
+    /// People named in the title: cast or director in parentheses, audiobook authors and narrators
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    pub credits: Vec<Credit>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub resolution: Option<Resolution>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub date: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub year: Option<i32>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
     pub ppv: bool,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
     pub trash: bool,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
     pub adult: bool,
-    pub edition: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    pub editions: Vec<Edition>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
     pub extended: bool,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
     pub convert: bool,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
     pub hardcoded: bool,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
     pub proper: bool,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
     pub repack: bool,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
     pub retail: bool,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
     pub remastered: bool,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
     pub unrated: bool,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub region: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub quality: Option<Quality>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub bitrate: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub bit_depth: Option<String>,
-    pub hdr: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    pub hdr: Vec<HdrFormat>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub codec: Option<Codec>,
-    pub audio: Vec<String>,
-    pub channels: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    pub audio: Vec<AudioCodec>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    pub channels: Vec<AudioChannels>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub group: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub container: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
     pub volumes: Vec<i32>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
     pub seasons: Vec<i32>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
     pub episodes: Vec<i32>,
+    /// Absolute episode numbers of anime releases, e.g. 87 in "Boku no Hero Academia 87 (S4-24)" or 12 in
+    /// "[SubsPlease] Frieren - 12" (anime releases without a season are numbered from the first episode)
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    pub absolute_episodes: Vec<i32>,
+    /// Any bracketed 8 character code at the end, like PTT's `episode_code`; see `checksum` for a typed CRC32
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub episode_code: Option<String>,
+    /// The bracketed CRC32 of anime releases, only set if the code is hexadecimal
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub checksum: Option<Crc32>,
+    /// Release revision, 2 for "12v2"
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub version: Option<u8>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
     pub complete: bool,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
     pub languages: Vec<Language>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
     pub dubbed: bool,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub site: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub extension: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
     pub subbed: bool,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
     pub documentary: bool,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
     pub upscaled: bool,
+    #[cfg_attr(feature = "serde", serde(rename = "3d", skip_serializing_if = "is_false"))]
     pub is_3d: bool,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
     pub extras: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub size: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub network: Option<Network>,
+    /// Only recognized as the last word of the title ("The Office US"), which stays part of `title`
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub country: Option<RemakeCountry>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
     pub scene: bool,
+    /// Fields set by custom handlers that have no dedicated member, see [`HandlerContext::set_extra`]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "BTreeMap::is_empty"))]
+    pub extra: BTreeMap<String, FieldValue>,
+    /// Fields whose value is a guess, with the confidence of the last handler that matched them; all other fields
+    /// were matched exactly (see [`ParsedTitle::field_confidence`])
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "BTreeMap::is_empty"))]
+    pub low_confidence: BTreeMap<String, Confidence>,
+}
+
+impl ParsedTitle {
+    /// Confidence of a field, named like its handlers (e.g. "languages", "3d"), `None` if the field isn't set.
+    ///
+    /// This is the confidence of the last handler that matched the field, which set its final value. A list like
+    /// `audio` gets it from the handler that added the last entry: "AAC 5.1" is exact although "5.1" alone only
+    /// infers AC3.
+    pub fn field_confidence(&self, field: &str) -> Option<Confidence> {
+        let is_set = if parser::FIELDS.contains(&field) {
+            parser::is_field_set(self, field)
+        } else {
+            self.extra.contains_key(field)
+        };
+        if !is_set {
+            return None;
+        }
+        Some(self.low_confidence.get(field).copied().unwrap_or(Confidence::Exact))
+    }
+
+    /// Aggregate confidence between 0 and 1, the mean score of all extracted fields (1 if nothing was extracted)
+    pub fn confidence_score(&self) -> f32 {
+        let fields: Vec<&str> = parser::FIELDS
+            .iter()
+            .copied()
+            .filter(|field| parser::is_field_set(self, field))
+            .chain(self.extra.keys().map(String::as_str))
+            .collect();
+        if fields.is_empty() {
+            return 1.0;
+        }
+        let total: f32 = fields
+            .iter()
+            .filter_map(|field| self.field_confidence(field))
+            .map(|c| c.score())
+            .sum();
+        total / fields.len() as f32
+    }
+}
+
+#[cfg(feature = "serde")]
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+/// A [`ParsedTitle`] together with the spans of the original input each field was extracted from
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DetailedParsedTitle {
+    pub parsed: ParsedTitle,
+    /// One entry per handler match, in the order the handlers ran
+    pub spans: Vec<FieldSpan>,
+    /// Part of the original input the title was taken from (before cleaning)
+    pub title_span: Range<usize>,
+    /// Tokens after the title that no handler recognized
+    pub leftovers: Vec<LeftoverToken>,
+}
+
+impl DetailedParsedTitle {
+    /// All spans that were extracted for the given field
+    pub fn spans_for<'a>(&'a self, field: &'a str) -> impl Iterator<Item = &'a FieldSpan> + 'a {
+        self.spans.iter().filter(move |span| span.field == field)
+    }
+
+    /// Confidence of a field, same as [`ParsedTitle::field_confidence`]
+    pub fn field_confidence(&self, field: &str) -> Option<Confidence> {
+        self.parsed.field_confidence(field)
+    }
+
+    /// Aggregate confidence between 0 and 1, same as [`ParsedTitle::confidence_score`]
+    pub fn confidence_score(&self) -> f32 {
+        self.parsed.confidence_score()
+    }
 }
 
 pub fn parse_title(raw_title: &str) -> Result<ParsedTitle, ParserError> {
     let parser = Parser::default();
     parser.parse(raw_title)
 }
+
+pub fn parse_title_detailed(raw_title: &str) -> Result<DetailedParsedTitle, ParserError> {
+    let parser = Parser::default();
+    parser.parse_detailed(raw_title)
+}
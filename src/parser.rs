@@ -1,10 +1,23 @@
 use crate::extensions::regex::RegexStringExt as _;
+use crate::extensions::text::{ceil_char_boundary, floor_char_boundary, percent_decode};
 use crate::handler_wrapper::Handler;
 use crate::handler_wrapper::HandlerContext;
+use crate::handler_wrapper::HandlerInfo;
 use crate::handler_wrapper::Match;
+use crate::handler_wrapper::PropertyIsSet;
 use crate::handlers;
+use crate::prefilter::Prefilter;
+use crate::span::{leftover_tokens, FieldSpan, WorkingTitle};
+use crate::trace::HandlerTrace;
+use crate::AlternativeTitle;
+use crate::Confidence;
+use crate::Credit;
+use crate::CreditRole;
+use crate::DetailedParsedTitle;
 use crate::ParsedTitle;
 use crate::ParserError;
+use crate::RejectedInput;
+use crate::Script;
 use lazy_static::lazy_static;
 
 use regress::Regex;
@@ -28,13 +41,28 @@ const NON_ENGLISH_CHARS: &str = concat!(
     "\u{0750}-\u{077f}", // Arabic characters
     "\u{0c80}-\u{0cff}", // Kannada characters
     "\u{0d00}-\u{0d7f}", // Malayalam characters
-    "\u{0e00}-\u{0e7f}"  // Thai characters
+    "\u{0e00}-\u{0e7f}", // Thai characters
+    "\u{1100}-\u{11ff}", // Hangul Jamo (Korean)
+    "\u{3130}-\u{318f}", // Hangul Compatibility Jamo (Korean)
+    "\u{ac00}-\u{d7af}"  // Hangul Syllables (Korean)
 );
 
 lazy_static! {
     static ref CLEAN_TITLE_REGEX: Regex = Regex::new(r"_+").unwrap();
     static ref MOVIE_REGEX: Regex = Regex::case_insensitive(r"[[(]movie[)\]]").unwrap();
-    static ref RUSSIAN_CAST_REGEX: Regex = Regex::new(&format!(r"\([^)]*[\u0400-\u04ff][^)]*\)$|(?<=\/.*)\(.*\)$")).unwrap();
+    static ref NARRATOR_REGEX: Regex =
+        Regex::case_insensitive(r"\s*(?:[(\[]\s*(?:read|narrated) by\s+([^)\]]+?)\s*[)\]]|\b(?:read|narrated) by\s+([^()[\]{}]+?)(?=\s*(?:[([{]| - |$|\b(?:audio ?books?|(?:un)?abridged)\b)))").unwrap();
+    // Case sensitive, so lowercase words after "by" aren't taken for names
+    static ref AUTHOR_REGEX: Regex =
+        Regex::new(r"\s*(?:[(\[]\s*[Bb]y\s+([A-Z][^)\]]*?)\s*[)\]]|\b[Bb]y\s+([A-Z][^()[\]{}]*?)(?=\s*(?:[([{]| - |$|\b(?:[Aa]udio ?[Bb]ooks?|(?:[Uu]n)?[Aa]bridged)\b)))").unwrap();
+    static ref AUDIOBOOK_REGEX: Regex = Regex::case_insensitive(r"\b(?:audio ?books?|(?:un)?abridged|m4b)\b").unwrap();
+    // "/" is left to `split_credits`, it may separate two people or one name in two scripts
+    static ref NAME_SEPARATORS_REGEX: Regex =
+        Regex::case_insensitive(r"\s*(?:\((?!\s*as\b)|(?<!\(\s*as\b[^()]*)\)|[,;&]|\band\b|\sи\s)\s*").unwrap();
+    static ref RUSSIAN_CAST_REGEX: Regex = Regex::new(r"\([^)]*[\u0400-\u04ff][^)]*\)$|(?<=\/.*)\(.*\)$").unwrap();
+    static ref DIRECTOR_PREFIX_REGEX: Regex = Regex::case_insensitive(r"^\s*(?:directed by|director|dir\.|режисс[её]р)\s*:?\s*").unwrap();
+    // What's left of "A - by Author - B" once the credit is removed
+    static ref DANGLING_SEPARATORS_REGEX: Regex = Regex::new(r"\s+-(?:\s+-)+(?=\s|$)").unwrap();
     static ref ALT_TITLES_REGEX: Regex = Regex::new(&format!(
         r"[^/|(]*[{}][^/|]*[/|]|[/|][^/|(]*[{}][^/|]*",
         NON_ENGLISH_CHARS, NON_ENGLISH_CHARS
@@ -58,39 +86,297 @@ lazy_static! {
     static ref DOT_REGEX: Regex = Regex::new(r"\.").unwrap();
 }
 
+lazy_static! {
+    // a magnet link carries the release name in its `dn` parameter
+    static ref MAGNET_NAME_REGEX: Regex = Regex::case_insensitive(r"^magnet:\?(?:\S*&)?dn=([^&\s]*)").unwrap();
+    // the whole input is a link or a hash, there's no release name to parse
+    static ref URL_REGEX: Regex = Regex::case_insensitive(r"^(?:[a-z][a-z\d+.-]*://|magnet:\?)\S*$").unwrap();
+    static ref HASH_REGEX: Regex = Regex::case_insensitive(r"^(?:[\da-f]{32}|[\da-f]{40}|[\da-f]{64})$").unwrap();
+}
+
 static DEFAULT_PARSER: OnceLock<Parser> = OnceLock::new();
 
+/// Inputs longer than this (in bytes) are rejected unless [`ParseOptions::max_length`] says otherwise
+pub const DEFAULT_MAX_LENGTH: usize = 1024;
+
+/// Options for [`Parser::parse_with`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOptions {
+    fields: Option<Vec<String>>,
+    lenient: bool,
+    max_length: usize,
+    require_title: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            fields: None,
+            lenient: false,
+            max_length: DEFAULT_MAX_LENGTH,
+            require_title: false,
+        }
+    }
+}
+
+impl ParseOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse whatever is passed in instead of rejecting empty, overlong or non-release input
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// Reject inputs longer than `max_length` bytes with [`ParserError::TooLong`]
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
+    /// Return [`ParserError::NoTitle`] if everything in the input was recognized as something else.
+    ///
+    /// Off by default, as e.g. subtitle file names like "Subs/Brazilian.por.srt" parse fine without a title. Only
+    /// checked when the title is computed (see [`ParseOptions::fields`]).
+    pub fn require_title(mut self, require_title: bool) -> Self {
+        self.require_title = require_title;
+        self
+    }
+
+    /// Only extract these fields, named like the handlers that fill them (e.g. "resolution", "episodes", "3d").
+    ///
+    /// Handlers for other fields only run if a requested one depends on them, so other fields of the result may be
+    /// partially filled. The title is only computed if "title" (or one of "alternative_titles", "native_title" and
+    /// "credits") is requested, which needs every handler.
+    pub fn fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.fields = Some(fields.into_iter().map(Into::into).collect());
+        self
+    }
+
+    fn wants(&self, field: &str) -> bool {
+        self.fields.as_ref().is_none_or(|fields| fields.iter().any(|f| f == field))
+    }
+
+    /// Whether the title or one of the fields taken from it during cleanup is requested
+    fn wants_title(&self) -> bool {
+        TITLE_FIELDS.iter().any(|field| self.wants(field))
+    }
+}
+
+/// Fields that are filled while cleaning the title rather than by a handler
+const TITLE_FIELDS: &[&str] = &["title", "alternative_titles", "native_title", "credits"];
+
+/// The cleaned up title and what was cut out of it on the way
+struct CleanedTitle {
+    title: String,
+    alternative_titles: Vec<AlternativeTitle>,
+    native_title: Option<String>,
+    credits: Vec<Credit>,
+}
+
+/// Split a list like "A, B and C" into credits.
+///
+/// "Зиги Ротемунд / Sigi Rothemund (as Siggi Götz)" is one person: the two sides of a "/" are written in different
+/// scripts, so the second one is the transliteration, and "(as ...)" stays with the name it belongs to.
+fn split_credits(text: &str, role: Option<CreditRole>) -> Vec<Credit> {
+    let is_name = |name: &&str| name.chars().any(char::is_alphabetic);
+    let mut credits = Vec::new();
+    for names in NAME_SEPARATORS_REGEX.replace_all(text, "\n").split('\n') {
+        let names: Vec<&str> = names.split('/').map(str::trim).filter(is_name).collect();
+        match names[..] {
+            [name, transliteration] if Script::detect(name) != Script::detect(transliteration) => {
+                credits.push(Credit::new(name, role).with_transliteration(transliteration));
+            }
+            _ => credits.extend(names.into_iter().map(|name| Credit::new(name, role))),
+        }
+    }
+    credits
+}
+
+/// Credits in a parenthetical removed by [`RUSSIAN_CAST_REGEX`].
+///
+/// Only Cyrillic names, a role like "(Director: John Stockwell)" or a list of names like "(Tom Hardy, Emily Blunt)"
+/// count, so "Blade Runner (Final Cut)" isn't taken for a person.
+fn cast_credits(text: &str) -> Vec<Credit> {
+    let (names, role) = match DIRECTOR_PREFIX_REGEX.find_str(text) {
+        Some(m) => (&text[m.m.end()..], Some(CreditRole::Director)),
+        None => (text, None),
+    };
+    let cyrillic = names.chars().any(|c| ('\u{0400}'..='\u{04ff}').contains(&c));
+    if role.is_none() && !cyrillic && !is_name_list(names) {
+        return Vec::new();
+    }
+    split_credits(names, role)
+}
+
+/// "A, B" where every item is a few capitalized words
+fn is_name_list(text: &str) -> bool {
+    let is_word = |word: &str| {
+        word.chars().next().is_some_and(char::is_uppercase) && word.chars().all(|c| c.is_alphabetic() || matches!(c, '.' | '\'' | '-'))
+    };
+    let items: Vec<&str> = text.split(',').map(str::trim).collect();
+    items.len() > 1
+        && items.iter().all(|item| {
+            let words: Vec<&str> = item.split_whitespace().collect();
+            (1..=4).contains(&words.len()) && words.into_iter().all(is_word)
+        })
+}
+
+/// Trim the separators around a title removed by [`ALT_TITLES_REGEX`] or [`NOT_ONLY_NON_ENGLISH_REGEX`]
+fn clean_alternative_title(text: &str) -> Option<AlternativeTitle> {
+    let mut cleaned = text.to_string();
+    for (open_bracket, close_bracket) in BRACKETS {
+        if cleaned.matches(open_bracket).count() != cleaned.matches(close_bracket).count() {
+            cleaned = cleaned.replace(open_bracket, "").replace(close_bracket, "");
+        }
+    }
+    let trimmed = cleaned.trim_matches(|c: char| c.is_whitespace() || matches!(c, '/' | '|' | '-' | ':' | ',' | '.'));
+    AlternativeTitle::new(SPACING_REGEX.replace_all(trimmed, " "))
+}
+
 pub struct Parser {
     handlers: Vec<Handler>,
+    /// Built on first use and dropped whenever the handlers change
+    prefilter: OnceLock<Prefilter>,
 }
 
 impl Parser {
+    #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        Parser { handlers: Vec::new() }
+        Parser {
+            handlers: Vec::new(),
+            prefilter: OnceLock::new(),
+        }
     }
 
+    /// Shared parser with the built-in handlers, used by [`crate::parse_title`]
+    #[allow(clippy::should_implement_trait)]
     pub fn default() -> &'static Parser {
-        DEFAULT_PARSER.get_or_init(|| {
-            let mut parser = Parser::new();
-            handlers::add_default_handlers(&mut parser);
-            parser
-        })
+        DEFAULT_PARSER.get_or_init(Parser::with_default_handlers)
+    }
+
+    /// Owned parser with the built-in handlers that can be extended or trimmed down
+    pub fn with_default_handlers() -> Self {
+        let mut parser = Parser::new();
+        handlers::add_default_handlers(&mut parser);
+        parser
     }
 
     pub fn add_handler(&mut self, handler: Handler) {
         self.handlers.push(handler);
+        self.prefilter.take();
+    }
+
+    /// Names of all handlers in the order they run (names repeat, most fields have several handlers)
+    pub fn handler_names(&self) -> impl Iterator<Item = &str> {
+        self.handlers.iter().map(|handler| handler.get_name())
+    }
+
+    /// All handlers described as data, in the order they run
+    pub fn handler_infos(&self) -> Vec<HandlerInfo> {
+        self.handlers
+            .iter()
+            .enumerate()
+            .map(|(order, handler)| handler.info(order))
+            .collect()
+    }
+
+    /// Remove all handlers with the given name, returns how many were removed
+    pub fn remove_handlers(&mut self, name: &str) -> usize {
+        let before = self.handlers.len();
+        self.handlers.retain(|handler| handler.get_name() != name);
+        self.prefilter.take();
+        before - self.handlers.len()
+    }
+
+    /// Replace all handlers with the given name by `handler`, which takes the position of the first one.
+    /// Returns false (and drops `handler`) if there is no handler with that name.
+    pub fn replace_handlers(&mut self, name: &str, handler: Handler) -> bool {
+        let Some(index) = self.position(name) else {
+            return false;
+        };
+        // all removed handlers are at or after `index`, so it still points at the same spot
+        self.remove_handlers(name);
+        self.handlers.insert(index, handler);
+        self.prefilter.take();
+        true
+    }
+
+    /// Insert `handler` so it runs before the first handler with the given name.
+    /// Returns false (and drops `handler`) if there is no handler with that name.
+    pub fn insert_handler_before(&mut self, name: &str, handler: Handler) -> bool {
+        let Some(index) = self.position(name) else {
+            return false;
+        };
+        self.handlers.insert(index, handler);
+        self.prefilter.take();
+        true
+    }
+
+    /// Insert `handler` so it runs after the last handler with the given name.
+    /// Returns false (and drops `handler`) if there is no handler with that name.
+    pub fn insert_handler_after(&mut self, name: &str, handler: Handler) -> bool {
+        let Some(index) = self.handlers.iter().rposition(|handler| handler.get_name() == name) else {
+            return false;
+        };
+        self.handlers.insert(index + 1, handler);
+        self.prefilter.take();
+        true
+    }
+
+    /// Insert `handler` at `index` in the run order, used by rule packs that place several handlers at one spot
+    #[cfg(feature = "rules")]
+    pub(crate) fn insert_handler_at(&mut self, index: usize, handler: Handler) {
+        self.handlers.insert(index.min(self.handlers.len()), handler);
+        self.prefilter.take();
     }
 
-    fn clean_title(&self, title: &str) -> String {
+    fn position(&self, name: &str) -> Option<usize> {
+        self.handlers.iter().position(|handler| handler.get_name() == name)
+    }
+
+    /// `audiobook` enables "read by Narrator" and "by Author", which are too ambiguous for other titles ("Stand by Me")
+    fn clean_title(&self, title: &str, audiobook: bool) -> CleanedTitle {
         let mut cleaned = title.to_string();
         cleaned = cleaned.replace("_", " ");
         cleaned = MOVIE_REGEX.replace_all(&cleaned, "").to_string();
         cleaned = NOT_ALLOWED_SYMBOLS_AT_START_AND_END.replace_all(&cleaned, "").to_string();
+        let mut credits = Vec::new();
+        let credit_regexes = if audiobook {
+            vec![(&*NARRATOR_REGEX, CreditRole::Narrator), (&*AUTHOR_REGEX, CreditRole::Author)]
+        } else {
+            Vec::new()
+        };
+        for (regex, role) in credit_regexes {
+            for m in regex.find_iter_str(&cleaned) {
+                let names = m.group(1).or_else(|| m.group(2)).map(|group| group.as_str()).unwrap_or_default();
+                credits.extend(split_credits(names, Some(role)));
+            }
+            cleaned = regex.replace_all(&cleaned, "").to_string();
+            cleaned = DANGLING_SEPARATORS_REGEX.replace_all(&cleaned, " -").to_string();
+        }
+        for m in RUSSIAN_CAST_REGEX.find_iter_str(&cleaned) {
+            let names = m
+                .as_str()
+                .strip_prefix('(')
+                .and_then(|names| names.strip_suffix(')'))
+                .unwrap_or_default();
+            credits.extend(cast_credits(names));
+        }
         cleaned = RUSSIAN_CAST_REGEX.replace_all(&cleaned, "").to_string();
         cleaned = STAR_REGEX_1.replace_all_with_captures(&cleaned, r"\1", true);
         cleaned = STAR_REGEX_2.replace_all_with_captures(&cleaned, r"\1", true);
-        cleaned = ALT_TITLES_REGEX.replace_all(&cleaned, "").to_string();
-        cleaned = NOT_ONLY_NON_ENGLISH_REGEX.replace_all(&cleaned, "").to_string();
+        let mut alternative_titles = Vec::new();
+        for regex in [&*ALT_TITLES_REGEX, &*NOT_ONLY_NON_ENGLISH_REGEX] {
+            alternative_titles.extend(regex.find_iter_str(&cleaned).filter_map(|m| clean_alternative_title(m.as_str())));
+            cleaned = regex.replace_all(&cleaned, "").to_string();
+        }
         cleaned = REMAINING_NOT_ALLOWED_SYMBOLS_AT_START_AND_END.replace_all(&cleaned, "").to_string();
         cleaned = EMPTY_BRACKETS_REGEX.replace_all(&cleaned, "").to_string();
         cleaned = MP3_REGEX.replace_all(&cleaned, "").to_string();
@@ -109,57 +395,402 @@ impl Parser {
 
         cleaned = REDUNDANT_SYMBOLS_AT_END.replace_all(&cleaned, "").to_string();
         cleaned = SPACING_REGEX.replace_all(&cleaned, " ").to_string();
-        cleaned = cleaned.trim().to_string();
-        cleaned
+        let title = cleaned.trim().to_string();
+        // a title that is entirely in another script stays the title, there's no Latin one to set it apart from.
+        // Kana or hangul only appear in the original Japanese or Korean title, Han characters alone are often a
+        // Chinese translation of it (e.g. "別對映像研出手！" next to "映像研には手を出すな！").
+        let native_title = match Script::detect(&title) {
+            None if !title.is_empty() => alternative_titles
+                .iter()
+                .find(|alternative| matches!(alternative.script, Script::Japanese | Script::Korean))
+                .or(alternative_titles.first())
+                .map(|alternative| alternative.title.clone()),
+            _ => None,
+        };
+        CleanedTitle {
+            title,
+            alternative_titles,
+            native_title,
+            credits,
+        }
     }
 
+    /// Parse the title with all handlers. Doesn't panic on any input as long as the custom handlers don't.
+    ///
+    /// Empty input, input over [`DEFAULT_MAX_LENGTH`] bytes and input that is only a URL or a hash are rejected, use
+    /// [`Parser::parse_with`] and [`ParseOptions::lenient`] to parse them anyway. A magnet link with a `dn` parameter
+    /// is parsed by that (decoded) name, spans then point into the name instead of the link.
     pub fn parse(&self, raw_title: &str) -> Result<ParsedTitle, ParserError> {
+        self.parse_detailed(raw_title).map(|detailed| detailed.parsed)
+    }
+
+    /// Parse the title, running only the handlers needed for the requested fields
+    pub fn parse_with(&self, raw_title: &str, options: &ParseOptions) -> Result<ParsedTitle, ParserError> {
+        let selected = self.select_handlers(options);
+        self.run(raw_title, options, &selected, None).map(|detailed| detailed.parsed)
+    }
+
+    /// Which handlers have to run so the requested fields come out the same as in a full parse.
+    ///
+    /// Walking backwards, a handler is needed if it fills a requested field, if it may remove text from the title
+    /// that a later needed handler sees, if a later needed handler reads its entry in `matched`, or if it fills the
+    /// same field as a later needed handler (which may skip or build on the value).
+    fn select_handlers(&self, options: &ParseOptions) -> Vec<bool> {
+        if options.fields.is_none() || options.wants_title() {
+            return vec![true; self.handlers.len()];
+        }
+
+        let mut selected = vec![false; self.handlers.len()];
+        let mut any_later = false;
+        let mut reads_all = false;
+        let mut reads_end_of_title = false;
+        let mut reads: Vec<&str> = Vec::new();
+        let mut filled: Vec<&str> = Vec::new();
+        for (index, handler) in self.handlers.iter().enumerate().rev() {
+            let name = handler.get_name();
+            let needed = options.wants(name)
+                || (any_later && handler.may_remove_text())
+                || reads_all
+                || (reads_end_of_title && handler.may_cut_title())
+                || reads.contains(&name)
+                || filled.contains(&name);
+            if !needed {
+                continue;
+            }
+
+            selected[index] = true;
+            any_later = true;
+            reads_end_of_title |= handler.reads_end_of_title();
+            if !filled.contains(&name) {
+                filled.push(name);
+            }
+            match handler.reads_matches() {
+                Some(names) => reads.extend(names.iter().map(String::as_str)),
+                None => reads_all = true,
+            }
+        }
+        selected
+    }
+
+    /// Parse the title and also return the spans in `raw_title` that produced each extracted field
+    pub fn parse_detailed(&self, raw_title: &str) -> Result<DetailedParsedTitle, ParserError> {
+        self.parse_detailed_with(raw_title, &ParseOptions::default())
+    }
+
+    /// [`Parser::parse_detailed`] with options, spans are only recorded for the handlers that ran
+    pub fn parse_detailed_with(&self, raw_title: &str, options: &ParseOptions) -> Result<DetailedParsedTitle, ParserError> {
+        let selected = self.select_handlers(options);
+        self.run(raw_title, options, &selected, None)
+    }
+
+    /// Parse the title and record what every handler did, in the order they ran
+    pub fn parse_traced(&self, raw_title: &str) -> Result<(ParsedTitle, Vec<HandlerTrace>), ParserError> {
+        self.parse_traced_with(raw_title, &ParseOptions::default())
+    }
+
+    /// [`Parser::parse_traced`] with options, handlers that [`ParseOptions::fields`] leaves out have no trace
+    pub fn parse_traced_with(&self, raw_title: &str, options: &ParseOptions) -> Result<(ParsedTitle, Vec<HandlerTrace>), ParserError> {
+        let selected = self.select_handlers(options);
+        let mut trace = Vec::with_capacity(self.handlers.len());
+        let detailed = self.run(raw_title, options, &selected, Some(&mut trace))?;
+        Ok((detailed.parsed, trace))
+    }
+
+    /// Decoded `dn` (display name) of a magnet link, which is what the link would be saved as
+    fn magnet_display_name(input: &str) -> Option<String> {
+        let name = MAGNET_NAME_REGEX.find_str(input.trim())?.group(1)?.as_str().replace('+', " ");
+        Some(percent_decode(&name))
+    }
+
+    /// Reject input that isn't worth parsing, unless the options are lenient
+    fn validate(raw_title: &str, options: &ParseOptions) -> Result<(), ParserError> {
+        if options.lenient {
+            return Ok(());
+        }
+        let trimmed = raw_title.trim();
+        if trimmed.is_empty() {
+            return Err(ParserError::Empty);
+        }
+        if raw_title.len() > options.max_length {
+            return Err(ParserError::TooLong {
+                length: raw_title.len(),
+                limit: options.max_length,
+            });
+        }
+        if URL_REGEX.contains_match(trimmed) {
+            return Err(ParserError::NotAReleaseName(RejectedInput::Url));
+        }
+        if HASH_REGEX.contains_match(trimmed) {
+            return Err(ParserError::NotAReleaseName(RejectedInput::Hash));
+        }
+        Ok(())
+    }
+
+    /// `selected` marks the handlers to run, all of them if it's empty
+    fn run(
+        &self,
+        raw_title: &str,
+        options: &ParseOptions,
+        selected: &[bool],
+        mut trace: Option<&mut Vec<HandlerTrace>>,
+    ) -> Result<DetailedParsedTitle, ParserError> {
+        let display_name = Self::magnet_display_name(raw_title);
+        let raw_title = display_name.as_deref().unwrap_or(raw_title);
+        Self::validate(raw_title, options)?;
+
         let mut result = ParsedTitle::default();
-        let mut title = raw_title.to_string();
         let mut matched: HashMap<String, Match> = HashMap::new();
-        let mut end_of_title = title.len();
+        let mut spans: Vec<FieldSpan> = Vec::new();
+        // End of the title as an offset into the original input, so removals don't shift it
+        let mut end_of_title = raw_title.len();
 
         // Basic title cleaning
-        title = CLEAN_TITLE_REGEX.replace_all(&title, " ").to_string();
+        let mut title = WorkingTitle::new(raw_title, CLEAN_TITLE_REGEX.find_iter(raw_title).map(|m| m.range()));
+
+        // Literals present in the title, rescanned after every removal as it can join text into new literals
+        let prefilter = self.prefilter.get_or_init(|| Prefilter::new(&self.handlers));
+        let mut literals = prefilter.scan(title.as_str());
 
         // Apply handlers and track matches
-        for handler in &self.handlers {
+        for (index, handler) in self.handlers.iter().enumerate() {
+            if !selected.is_empty() && !selected[index] {
+                continue;
+            }
+            let mut skipped = None;
+            let mut extra_spans = Vec::new();
             let match_result = handler.call(HandlerContext {
-                title: &title,
+                title: title.as_str(),
                 result: &mut result,
                 matched: &mut matched,
-                // end_of_title: &mut end_of_title,
+                skipped: &mut skipped,
+                can_match: prefilter.may_match(index, &literals),
+                end_of_title: title.working_offset(end_of_title),
+                extra_spans: &mut extra_spans,
             });
-
-            #[cfg(feature = "debug")]
-            println!("match result for {}: {:?}", handler.get_name(), match_result);
-            #[cfg(feature = "debug")]
-            println!("title: {}", title);
+            // before the match is removed from the title, the ranges point into the title the handler saw
+            let set_own_extra = extra_spans.iter().any(|(key, _)| key == handler.get_name());
+            for (key, range) in extra_spans {
+                let start = floor_char_boundary(title.as_str(), range.start);
+                let end = ceil_char_boundary(title.as_str(), range.end.max(start));
+                let span = title.original_span(start..end);
+                spans.retain(|existing| existing.field != key);
+                spans.push(FieldSpan {
+                    field: key,
+                    raw_match: title.as_str()[start..end].to_string(),
+                    span,
+                    confidence: handler.get_confidence(),
+                });
+            }
 
             let Some(match_result) = match_result else {
+                if let Some(trace) = trace.as_deref_mut() {
+                    trace.push(HandlerTrace {
+                        index,
+                        name: handler.get_name().to_string(),
+                        matched: false,
+                        raw_match: None,
+                        match_index: None,
+                        span: None,
+                        skipped,
+                        removed: false,
+                        title_after: None,
+                        end_of_title_before: end_of_title,
+                        end_of_title_after: end_of_title,
+                    });
+                }
                 continue;
             };
+            let end_of_title_before = end_of_title;
 
-            if match_result.remove {
-                title = format!(
-                    "{}{}",
-                    &title[..match_result.match_index],
-                    &title[match_result.match_index + match_result.raw_match.len()..]
-                );
+            // custom handlers may report a position past the end or inside a character, keep it on the title
+            let match_start = floor_char_boundary(title.as_str(), match_result.match_index);
+            let match_end = ceil_char_boundary(title.as_str(), match_start + match_result.raw_match.len());
+            let match_range = match_start..match_end;
+            let span = title.original_span(match_range.clone());
+            // a lookahead-only match (e.g. the scene handler) doesn't point at any text
+            if !set_own_extra && !match_range.is_empty() {
+                spans.push(FieldSpan {
+                    field: handler.get_name().to_string(),
+                    raw_match: match_result.raw_match.clone(),
+                    span: span.clone(),
+                    confidence: handler.get_confidence(),
+                });
             }
-            if !match_result.skip_from_title && 1 < match_result.match_index && match_result.match_index < end_of_title {
-                end_of_title = match_result.match_index;
+
+            if !match_result.skip_from_title
+                && 1 < match_result.match_index
+                && match_result.match_index < title.working_offset(end_of_title)
+            {
+                end_of_title = span.start;
+            }
+            if match_result.remove {
+                title.remove(match_range.clone());
+                literals = prefilter.scan(title.as_str());
+                // keep the stored matches pointing into the shortened title
+                for m in matched.values_mut() {
+                    if m.match_index >= match_range.end {
+                        m.match_index -= match_range.len();
+                    } else if m.match_index > match_range.start {
+                        m.match_index = match_range.start;
+                    }
+                }
             }
-            if match_result.remove && match_result.skip_from_title && match_result.match_index < end_of_title {
-                end_of_title -= match_result.raw_match.len();
+
+            if let Some(trace) = trace.as_deref_mut() {
+                trace.push(HandlerTrace {
+                    index,
+                    name: handler.get_name().to_string(),
+                    matched: true,
+                    raw_match: Some(match_result.raw_match),
+                    match_index: Some(match_result.match_index),
+                    span: Some(span),
+                    skipped: None,
+                    removed: match_result.remove,
+                    title_after: match_result.remove.then(|| title.as_str().to_string()),
+                    end_of_title_before,
+                    end_of_title_after: end_of_title,
+                });
             }
         }
 
         // Clean the title by taking only the part before the first match
-        end_of_title = end_of_title.min(title.len()); // TODO: maybe fix that this can't be possible (e.g. for "2008 The Incredible Hulk Feature Film.mp4")
-        let title = title[..end_of_title].to_string();
-        result.title = self.clean_title(&title);
+        let title_end = title.working_offset(end_of_title);
+        let title_span = title.original_span(0..title_end);
+        if options.wants_title() {
+            let audiobook = AUDIOBOOK_REGEX.contains_match(raw_title);
+            let cleaned = self.clean_title(&title.as_str()[..floor_char_boundary(title.as_str(), title_end)], audiobook);
+            result.title = cleaned.title;
+            result.alternative_titles = cleaned.alternative_titles;
+            result.native_title = cleaned.native_title;
+            result.credits = cleaned.credits;
+            if result.title.is_empty() && options.require_title && !options.lenient {
+                return Err(ParserError::NoTitle);
+            }
+        }
+
+        let claimed: Vec<_> = spans.iter().map(|span| span.span.clone()).collect();
+        let leftovers = leftover_tokens(raw_title, title_span.end, &claimed);
+
+        // Drop spans of fields that were unset again by later handlers
+        spans.retain(|span| is_field_set(&result, &span.field));
+        // spans are in the order the handlers ran, so the last one is from the handler that set the final value
+        for span in &spans {
+            if span.confidence < Confidence::Exact {
+                result.low_confidence.insert(span.field.clone(), span.confidence);
+            } else {
+                result.low_confidence.remove(&span.field);
+            }
+        }
+
+        Ok(DetailedParsedTitle {
+            parsed: result,
+            spans,
+            title_span,
+            leftovers,
+        })
+    }
+}
+
+/// Names of the fields filled by the built-in handlers, as used by [`is_field_set`]
+pub(crate) const FIELDS: &[&str] = &[
+    "resolution",
+    "date",
+    "year",
+    "ppv",
+    "trash",
+    "adult",
+    "edition",
+    "extended",
+    "convert",
+    "hardcoded",
+    "proper",
+    "repack",
+    "retail",
+    "remastered",
+    "unrated",
+    "region",
+    "quality",
+    "bitrate",
+    "bit_depth",
+    "hdr",
+    "codec",
+    "audio",
+    "channels",
+    "group",
+    "container",
+    "volumes",
+    "seasons",
+    "episodes",
+    "absolute_episodes",
+    "episode_code",
+    "checksum",
+    "version",
+    "complete",
+    "languages",
+    "dubbed",
+    "site",
+    "extension",
+    "subbed",
+    "documentary",
+    "upscaled",
+    "3d",
+    "extras",
+    "size",
+    "network",
+    "country",
+    "scene",
+];
 
-        Ok(result)
+/// Whether the field filled by the handler with the given name still has a value (unknown names count as set)
+pub(crate) fn is_field_set(result: &ParsedTitle, handler_name: &str) -> bool {
+    match handler_name {
+        "title" => false, // only used to cut tokens out of the title
+        "resolution" => result.resolution.is_set(),
+        "date" => result.date.is_set(),
+        "year" => result.year.is_set(),
+        "ppv" => result.ppv,
+        "trash" => result.trash,
+        "adult" => result.adult,
+        "edition" => result.editions.is_set(),
+        "extended" => result.extended,
+        "convert" => result.convert,
+        "hardcoded" => result.hardcoded,
+        "proper" => result.proper,
+        "repack" => result.repack,
+        "retail" => result.retail,
+        "remastered" => result.remastered,
+        "unrated" => result.unrated,
+        "region" => result.region.is_set(),
+        "quality" => result.quality.is_set(),
+        "bitrate" => result.bitrate.is_set(),
+        "bit_depth" => result.bit_depth.is_set(),
+        "hdr" => result.hdr.is_set(),
+        "codec" => result.codec.is_set(),
+        "audio" => result.audio.is_set(),
+        "channels" => result.channels.is_set(),
+        "group" => result.group.is_set(),
+        "container" => result.container.is_set(),
+        "volumes" => result.volumes.is_set(),
+        "seasons" => result.seasons.is_set(),
+        "episodes" => result.episodes.is_set(),
+        "absolute_episodes" => result.absolute_episodes.is_set(),
+        "episode_code" => result.episode_code.is_set(),
+        "checksum" => result.checksum.is_set(),
+        "version" => result.version.is_set(),
+        "complete" => result.complete,
+        "languages" => result.languages.is_set(),
+        "dubbed" => result.dubbed,
+        "site" => result.site.is_set(),
+        "extension" => result.extension.is_set(),
+        "subbed" => result.subbed,
+        "documentary" => result.documentary,
+        "upscaled" => result.upscaled,
+        "3d" => result.is_3d,
+        "extras" => result.extras.is_set(),
+        "size" => result.size.is_set(),
+        "network" => result.network.is_set(),
+        "country" => result.country.is_set(),
+        "scene" => result.scene,
+        _ => true,
     }
 }
@@ -0,0 +1,363 @@
+//! Skips regex handlers whose pattern can't match because a literal it requires is missing from the title.
+//!
+//! For every pattern we derive a set of lowercase ASCII strings of which at least one has to occur in any match.
+//! All of them are searched at once with Aho-Corasick over the lowercased title, so a handler only runs its regex
+//! if one of its literals was found.
+
+use aho_corasick::AhoCorasick;
+
+use crate::handler_wrapper::Handler;
+
+/// Literals shorter than this filter out too little to be worth it
+const MIN_LITERAL_LEN: usize = 2;
+/// Upper bound for the alternatives a pattern like `(?:a|b)(?:c|d)` expands to
+const MAX_ALTERNATIVES: usize = 16;
+
+pub(crate) struct Prefilter {
+    automaton: AhoCorasick,
+    literal_count: usize,
+    /// Indexes into the automaton's patterns per handler, `None` if the handler always has to run
+    handler_literals: Vec<Option<Vec<usize>>>,
+}
+
+impl Prefilter {
+    pub(crate) fn new(handlers: &[Handler]) -> Self {
+        let mut literals: Vec<String> = Vec::new();
+        let handler_literals = handlers
+            .iter()
+            .map(|handler| {
+                let required = required_literals(handler.pattern_source()?)?;
+                let ids = required
+                    .into_iter()
+                    .map(|literal| match literals.iter().position(|known| *known == literal) {
+                        Some(id) => id,
+                        None => {
+                            literals.push(literal);
+                            literals.len() - 1
+                        }
+                    })
+                    .collect();
+                Some(ids)
+            })
+            .collect();
+
+        Prefilter {
+            automaton: AhoCorasick::new(&literals).expect("literals are plain strings"),
+            literal_count: literals.len(),
+            handler_literals,
+        }
+    }
+
+    /// Which literals occur in the title
+    pub(crate) fn scan(&self, title: &str) -> Vec<bool> {
+        let mut present = vec![false; self.literal_count];
+        let lowercase: String = title.chars().map(fold_case).collect();
+        for m in self.automaton.find_overlapping_iter(&lowercase) {
+            present[m.pattern().as_usize()] = true;
+        }
+        present
+    }
+
+    /// Whether the handler may match given the literals found by [`Prefilter::scan`]
+    pub(crate) fn may_match(&self, handler_index: usize, present: &[bool]) -> bool {
+        match &self.handler_literals[handler_index] {
+            Some(ids) => ids.iter().any(|&id| present[id]),
+            None => true,
+        }
+    }
+}
+
+/// Lowercase like regress does for case insensitive patterns, which also folds two non-ASCII letters into ASCII
+fn fold_case(c: char) -> char {
+    match c {
+        'ſ' => 's',
+        '\u{212A}' => 'k', // Kelvin sign
+        c => c.to_ascii_lowercase(),
+    }
+}
+
+/// One of these strings occurs (lowercased) in every match of the pattern, `None` if nothing useful is required
+pub(crate) fn required_literals(pattern: &str) -> Option<Vec<String>> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut analyzer = Analyzer { chars: &chars, pos: 0 };
+    let required = analyzer.alternation()?;
+    if analyzer.pos != chars.len() {
+        return None; // unbalanced parenthesis, don't guess
+    }
+    let required = required.literals?;
+    if required.iter().any(|literal| literal.len() < MIN_LITERAL_LEN) {
+        return None;
+    }
+    Some(required)
+}
+
+/// What a part of the pattern contributes to a sequence
+enum Item {
+    /// Matches exactly one of these strings
+    Literal(Vec<String>),
+    /// Matches something else, but one of these strings (if any) is always part of it
+    Opaque(Option<Vec<String>>),
+}
+
+/// What an alternation or sequence requires
+struct Requirement {
+    /// One of these strings occurs in every match
+    literals: Option<Vec<String>>,
+    /// The strings are exactly what is matched, nothing else
+    exact: bool,
+}
+
+struct Analyzer<'a> {
+    chars: &'a [char],
+    pos: usize,
+}
+
+impl Analyzer<'_> {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        Some(c)
+    }
+
+    fn eat(&mut self, s: &str) -> bool {
+        let expected: Vec<char> = s.chars().collect();
+        if self.chars[self.pos..].starts_with(&expected) {
+            self.pos += expected.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// `a|b|c`, `None` for syntax we don't understand
+    fn alternation(&mut self) -> Option<Requirement> {
+        let mut branches = vec![self.sequence()?];
+        while self.peek() == Some('|') {
+            self.pos += 1;
+            branches.push(self.sequence()?);
+        }
+
+        let exact = branches.iter().all(|branch| branch.exact);
+        let mut union: Vec<String> = Vec::new();
+        for branch in branches {
+            let Some(literals) = branch.literals else {
+                // a branch without requirement makes the whole alternation optional
+                return Some(Requirement {
+                    literals: None,
+                    exact: false,
+                });
+            };
+            for literal in literals {
+                if !union.contains(&literal) {
+                    union.push(literal);
+                }
+            }
+        }
+        Some(Requirement {
+            literals: Some(union),
+            exact,
+        })
+    }
+
+    fn sequence(&mut self) -> Option<Requirement> {
+        let mut candidates: Vec<Vec<String>> = Vec::new();
+        let mut run: Option<Vec<String>> = None;
+        let mut exact = true;
+
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            match self.quantified()? {
+                Item::Literal(literals) => {
+                    run = Some(match run.take() {
+                        None => literals,
+                        Some(prefixes) if prefixes.len() * literals.len() <= MAX_ALTERNATIVES => prefixes
+                            .iter()
+                            .flat_map(|prefix| literals.iter().map(move |literal| format!("{}{}", prefix, literal)))
+                            .collect(),
+                        Some(prefixes) => {
+                            exact = false;
+                            candidates.push(prefixes);
+                            literals
+                        }
+                    });
+                }
+                Item::Opaque(required) => {
+                    exact = false;
+                    candidates.extend(run.take());
+                    candidates.extend(required);
+                }
+            }
+        }
+
+        if exact {
+            // nothing but literals, so these are exactly the strings the sequence matches
+            return Some(Requirement {
+                literals: Some(run.unwrap_or_else(|| vec![String::new()])),
+                exact,
+            });
+        }
+        candidates.extend(run);
+
+        // the most selective candidate: longest shortest literal, then fewest alternatives
+        let literals = candidates.into_iter().max_by_key(|literals| {
+            let shortest = literals.iter().map(String::len).min().unwrap_or(0);
+            (shortest, usize::MAX - literals.len())
+        });
+        Some(Requirement { literals, exact })
+    }
+
+    fn quantified(&mut self) -> Option<Item> {
+        let item = self.atom()?;
+        let min = match self.peek() {
+            Some('?') | Some('*') => {
+                self.pos += 1;
+                0
+            }
+            Some('+') => {
+                self.pos += 1;
+                1
+            }
+            Some('{') => match self.repetition() {
+                Some(min) => min,
+                None => return Some(item), // a literal `{`, handled by the next atom
+            },
+            _ => return Some(item),
+        };
+        self.eat("?"); // lazy quantifier
+
+        if min == 0 {
+            return Some(Item::Opaque(None));
+        }
+        // repeated, so it can't be glued to its neighbours anymore
+        Some(match item {
+            Item::Literal(literals) => Item::Opaque(Some(literals)),
+            opaque => opaque,
+        })
+    }
+
+    /// `{n}`, `{n,}` or `{n,m}`, returns the minimum
+    fn repetition(&mut self) -> Option<usize> {
+        let start = self.pos;
+        let end = self.chars[start..].iter().position(|&c| c == '}')? + start;
+        let body: String = self.chars[start + 1..end].iter().collect();
+        let min = body.split(',').next()?.parse().ok()?;
+        self.pos = end + 1;
+        Some(min)
+    }
+
+    fn atom(&mut self) -> Option<Item> {
+        let c = self.next()?;
+        Some(match c {
+            '(' => self.group()?,
+            '[' => self.class()?,
+            '\\' => self.escape()?,
+            '.' | '^' | '$' => Item::Opaque(None),
+            c => literal(c),
+        })
+    }
+
+    fn group(&mut self) -> Option<Item> {
+        let lookaround = if self.eat("?=") || self.eat("?<=") {
+            true
+        } else if self.eat("?!") || self.eat("?<!") {
+            self.alternation()?;
+            return self.close_group(Item::Opaque(None));
+        } else if self.eat("?<") {
+            while self.next()? != '>' {} // named group
+            false
+        } else if self.eat("?:") || self.peek() != Some('?') {
+            false
+        } else {
+            return None; // a group modifier we don't know
+        };
+
+        let required = self.alternation()?;
+        let item = match required.literals {
+            // an alternation of literals like `(?:hevc|x265)` still glues to its neighbours
+            Some(literals) if required.exact && !lookaround => Item::Literal(literals),
+            literals => Item::Opaque(literals),
+        };
+        self.close_group(item)
+    }
+
+    fn close_group(&mut self, item: Item) -> Option<Item> {
+        (self.next()? == ')').then_some(item)
+    }
+
+    fn class(&mut self) -> Option<Item> {
+        let mut members: Vec<char> = Vec::new();
+        let mut simple = self.peek() != Some('^');
+        loop {
+            let c = self.next()?;
+            match c {
+                ']' => break,
+                '\\' => {
+                    let escaped = self.next()?;
+                    if escaped.is_ascii_alphanumeric() {
+                        simple = false; // \d, \w, Ѐ and friends
+                    } else {
+                        members.push(escaped);
+                    }
+                }
+                '-' if !members.is_empty() && self.peek() != Some(']') => {
+                    simple = false; // a range
+                    self.next()?;
+                }
+                c => members.push(c),
+            }
+        }
+
+        let mut literals: Vec<String> = Vec::new();
+        for member in members {
+            if !member.is_ascii() {
+                simple = false;
+            }
+            let member = member.to_ascii_lowercase().to_string();
+            if !literals.contains(&member) {
+                literals.push(member);
+            }
+        }
+        if simple && !literals.is_empty() && literals.len() <= 4 {
+            Some(Item::Literal(literals))
+        } else {
+            Some(Item::Opaque(None))
+        }
+    }
+
+    fn escape(&mut self) -> Option<Item> {
+        let c = self.next()?;
+        Some(match c {
+            'u' | 'p' | 'P' | 'k' if matches!(self.peek(), Some('{') | Some('<')) => {
+                // code points, properties and named references: skip their argument
+                let close = if self.next()? == '{' { '}' } else { '>' };
+                while self.next()? != close {}
+                Item::Opaque(None)
+            }
+            'x' | 'u' | 'c' => {
+                let digits = match c {
+                    'x' => 2,
+                    'u' => 4,
+                    _ => 1,
+                };
+                self.pos = (self.pos + digits).min(self.chars.len());
+                Item::Opaque(None)
+            }
+            c if c.is_ascii_alphanumeric() => Item::Opaque(None), // \d, \b, \s, back references...
+            c => literal(c),
+        })
+    }
+}
+
+fn literal(c: char) -> Item {
+    if c.is_ascii() {
+        Item::Literal(vec![c.to_ascii_lowercase().to_string()])
+    } else {
+        Item::Opaque(None)
+    }
+}
@@ -0,0 +1,361 @@
+//! Rules defined as data (TOML or JSON) that are turned into regex handlers at runtime
+
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::handler_wrapper::TrimIfString;
+use crate::transforms::{self, Transform};
+use crate::{Handler, ParsedTitle, Parser, Pattern, RegexHandlerOptions};
+
+/// A set of rules, e.g. loaded from a file
+///
+/// ```toml
+/// [[rules]]
+/// id = "trackerx-group"
+/// field = "group"
+/// pattern = '\bTrkX\b'
+/// transform = { value = "TrkX" }
+/// remove = true
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RulePack {
+    #[serde(default)]
+    pub rules: Vec<Rule>,
+}
+
+/// A single regex rule filling one field of [`ParsedTitle`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Rule {
+    /// Identifier used in error messages
+    pub id: String,
+    /// Name of the field to fill, same as the handler names of the built-in rules (e.g. "group", "languages", "3d")
+    pub field: String,
+    /// ECMAScript regex, the first capture group (or the whole match) is passed to the transform
+    pub pattern: String,
+    #[serde(default)]
+    pub case_insensitive: bool,
+    #[serde(default)]
+    pub transform: RuleTransform,
+    #[serde(default = "default_true")]
+    pub skip_if_already_found: bool,
+    #[serde(default)]
+    pub skip_from_title: bool,
+    #[serde(default)]
+    pub skip_if_first: bool,
+    #[serde(default)]
+    pub remove: bool,
+    /// Run before the first handler with this name (default: before the first handler of `field`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+    /// Run after the last handler with this name, can't be combined with `before`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// How the matched text is turned into the field value, `$1` in values is replaced by the match
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleTransform {
+    /// Use the matched text as is
+    #[default]
+    Identity,
+    /// Set a single value field
+    Value(String),
+    /// Add a value to a list field, skipping duplicates
+    UniqConcat(String),
+    /// Set a flag to true
+    Boolean,
+    /// Parse a date with the first matching chrono format and store it as YYYY-MM-DD
+    Date(Vec<String>),
+    /// Parse a number range like "1-3" into a list of numbers
+    Range,
+}
+
+impl RuleTransform {
+    fn name(&self) -> &'static str {
+        match self {
+            RuleTransform::Identity => "identity",
+            RuleTransform::Value(_) => "value",
+            RuleTransform::UniqConcat(_) => "uniq_concat",
+            RuleTransform::Boolean => "boolean",
+            RuleTransform::Date(_) => "date",
+            RuleTransform::Range => "range",
+        }
+    }
+}
+
+/// Problem with a rule pack, rule specific errors carry the rule id
+#[derive(Debug, Error)]
+pub enum RuleError {
+    #[error("failed to read rule pack: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("invalid rule pack: {0}")]
+    Format(String),
+    #[error("rule {id}: invalid pattern: {message}")]
+    InvalidPattern { id: String, message: String },
+    #[error("rule {id}: unknown field {field}")]
+    UnknownField { id: String, field: String },
+    #[error("rule {id}: transform {transform} can't be used for field {field}")]
+    UnsupportedTransform {
+        id: String,
+        field: String,
+        transform: &'static str,
+    },
+    #[error("rule {id}: invalid value {value} for field {field}")]
+    InvalidValue { id: String, field: String, value: String },
+    #[error("rule {id}: no handler named {name}")]
+    UnknownHandler { id: String, name: String },
+    #[error("rule {id}: only one of before and after can be set")]
+    ConflictingPlacement { id: String },
+}
+
+impl RulePack {
+    pub fn from_toml(source: &str) -> Result<Self, RuleError> {
+        toml::from_str(source).map_err(|e| RuleError::Format(e.to_string()))
+    }
+
+    pub fn from_json(source: &str) -> Result<Self, RuleError> {
+        serde_json::from_str(source).map_err(|e| RuleError::Format(e.to_string()))
+    }
+
+    /// Load a `.json` file, any other extension is read as TOML
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, RuleError> {
+        let path = path.as_ref();
+        let source = fs::read_to_string(path)?;
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => Self::from_json(&source),
+            _ => Self::from_toml(&source),
+        }
+    }
+}
+
+impl Rule {
+    /// Compile the rule into a handler
+    pub fn to_handler(&self) -> Result<Handler, RuleError> {
+        let regex = Pattern::with_case_insensitive(&self.pattern, self.case_insensitive).map_err(|e| RuleError::InvalidPattern {
+            id: self.id.clone(),
+            message: e.to_string(),
+        })?;
+
+        match self.field.as_str() {
+            "resolution" => self.single(regex, |t| &mut t.resolution),
+            "date" => self.date(regex),
+            "year" => self.single(regex, |t| &mut t.year),
+            "ppv" => self.flag(regex, |t| &mut t.ppv),
+            "trash" => self.flag(regex, |t| &mut t.trash),
+            "adult" => self.flag(regex, |t| &mut t.adult),
+            "edition" => self.list(regex, |t| &mut t.editions),
+            "extended" => self.flag(regex, |t| &mut t.extended),
+            "convert" => self.flag(regex, |t| &mut t.convert),
+            "hardcoded" => self.flag(regex, |t| &mut t.hardcoded),
+            "proper" => self.flag(regex, |t| &mut t.proper),
+            "repack" => self.flag(regex, |t| &mut t.repack),
+            "retail" => self.flag(regex, |t| &mut t.retail),
+            "remastered" => self.flag(regex, |t| &mut t.remastered),
+            "unrated" => self.flag(regex, |t| &mut t.unrated),
+            "region" => self.single(regex, |t| &mut t.region),
+            "quality" => self.single(regex, |t| &mut t.quality),
+            "bitrate" => self.single(regex, |t| &mut t.bitrate),
+            "bit_depth" => self.single(regex, |t| &mut t.bit_depth),
+            "hdr" => self.list(regex, |t| &mut t.hdr),
+            "codec" => self.single(regex, |t| &mut t.codec),
+            "audio" => self.list(regex, |t| &mut t.audio),
+            "channels" => self.list(regex, |t| &mut t.channels),
+            "group" => self.single(regex, |t| &mut t.group),
+            "container" => self.single(regex, |t| &mut t.container),
+            "volumes" => self.numbers(regex, |t| &mut t.volumes),
+            "seasons" => self.numbers(regex, |t| &mut t.seasons),
+            "episodes" => self.numbers(regex, |t| &mut t.episodes),
+            "absolute_episodes" => self.numbers(regex, |t| &mut t.absolute_episodes),
+            "episode_code" => self.single(regex, |t| &mut t.episode_code),
+            "checksum" => self.single(regex, |t| &mut t.checksum),
+            "version" => self.single(regex, |t| &mut t.version),
+            "complete" => self.flag(regex, |t| &mut t.complete),
+            "languages" => self.list(regex, |t| &mut t.languages),
+            "dubbed" => self.flag(regex, |t| &mut t.dubbed),
+            "site" => self.single(regex, |t| &mut t.site),
+            "extension" => self.single(regex, |t| &mut t.extension),
+            "subbed" => self.flag(regex, |t| &mut t.subbed),
+            "documentary" => self.flag(regex, |t| &mut t.documentary),
+            "upscaled" => self.flag(regex, |t| &mut t.upscaled),
+            "3d" => self.flag(regex, |t| &mut t.is_3d),
+            "extras" => self.list(regex, |t| &mut t.extras),
+            "size" => self.single(regex, |t| &mut t.size),
+            "network" => self.single(regex, |t| &mut t.network),
+            "country" => self.single(regex, |t| &mut t.country),
+            "scene" => self.flag(regex, |t| &mut t.scene),
+            _ => Err(RuleError::UnknownField {
+                id: self.id.clone(),
+                field: self.field.clone(),
+            }),
+        }
+    }
+
+    fn options(&self) -> RegexHandlerOptions {
+        RegexHandlerOptions {
+            skip_if_already_found: self.skip_if_already_found,
+            skip_from_title: self.skip_from_title,
+            skip_if_first: self.skip_if_first,
+            remove: self.remove,
+        }
+    }
+
+    fn unsupported(&self) -> RuleError {
+        RuleError::UnsupportedTransform {
+            id: self.id.clone(),
+            field: self.field.clone(),
+            transform: self.transform.name(),
+        }
+    }
+
+    /// Check a value template that doesn't depend on the match up front, so typos fail when loading
+    fn checked_template<T: FromStr>(&self, template: &str) -> Result<String, RuleError> {
+        if !template.contains("$1") && template.parse::<T>().is_err() {
+            return Err(RuleError::InvalidValue {
+                id: self.id.clone(),
+                field: self.field.clone(),
+                value: template.to_string(),
+            });
+        }
+        Ok(template.to_string())
+    }
+
+    fn single<T>(&self, regex: Pattern, accessor: fn(&mut ParsedTitle) -> &mut Option<T>) -> Result<Handler, RuleError>
+    where
+        T: FromStr + TrimIfString + 'static,
+    {
+        let template = match &self.transform {
+            RuleTransform::Identity => "$1".to_string(),
+            RuleTransform::Value(value) => self.checked_template::<T>(value)?,
+            _ => return Err(self.unsupported()),
+        };
+        let transform = Transform::new(self.transform.name(), vec![template.clone()], move |value, _: &Option<T>| {
+            template.replace("$1", value).parse().ok().map(Some)
+        });
+        Ok(Handler::from_regex(&self.field, accessor, regex, transform, self.options()))
+    }
+
+    fn list<T>(&self, regex: Pattern, accessor: fn(&mut ParsedTitle) -> &mut Vec<T>) -> Result<Handler, RuleError>
+    where
+        T: FromStr + Clone + PartialEq + 'static,
+    {
+        let template = match &self.transform {
+            RuleTransform::Identity => "$1".to_string(),
+            RuleTransform::UniqConcat(value) => self.checked_template::<T>(value)?,
+            _ => return Err(self.unsupported()),
+        };
+        let transform = Transform::new(self.transform.name(), vec![template.clone()], move |value, result: &Vec<T>| {
+            let value: T = template.replace("$1", value).parse().ok()?;
+            transforms::push_unique(value, result)
+        });
+        Ok(Handler::from_regex(&self.field, accessor, regex, transform, self.options()))
+    }
+
+    fn numbers(&self, regex: Pattern, accessor: fn(&mut ParsedTitle) -> &mut Vec<i32>) -> Result<Handler, RuleError> {
+        match self.transform {
+            RuleTransform::Range => Ok(Handler::from_regex(
+                &self.field,
+                accessor,
+                regex,
+                transforms::range_func(),
+                self.options(),
+            )),
+            _ => self.list(regex, accessor),
+        }
+    }
+
+    fn flag(&self, regex: Pattern, accessor: fn(&mut ParsedTitle) -> &mut bool) -> Result<Handler, RuleError> {
+        match self.transform {
+            RuleTransform::Boolean => Ok(Handler::from_regex(
+                &self.field,
+                accessor,
+                regex,
+                transforms::true_if_found(),
+                self.options(),
+            )),
+            _ => Err(self.unsupported()),
+        }
+    }
+
+    fn date(&self, regex: Pattern) -> Result<Handler, RuleError> {
+        let RuleTransform::Date(formats) = &self.transform else {
+            return self.single(regex, |t| &mut t.date);
+        };
+        let formats: Vec<&str> = formats.iter().map(String::as_str).collect();
+        let transform = transforms::date_from_formats(&formats);
+        Ok(Handler::from_regex(&self.field, |t| &mut t.date, regex, transform, self.options()))
+    }
+}
+
+impl Parser {
+    /// Add all rules of the pack, or none of them if any rule is invalid
+    ///
+    /// Rules run before the first handler of their field unless `before` or `after` says otherwise. Rules placed at
+    /// the same spot run in the order they are declared.
+    pub fn add_rule_pack(&mut self, pack: &RulePack) -> Result<(), Vec<RuleError>> {
+        let mut errors = Vec::new();
+        let mut handlers = Vec::new();
+        // `before` and `after` may refer to built-in handlers and to fields of earlier rules in the pack
+        let mut known: Vec<&str> = self.handler_names().collect();
+        for rule in &pack.rules {
+            match rule.to_handler() {
+                Ok(handler) => handlers.push((rule, handler)),
+                Err(e) => errors.push(e),
+            }
+            if rule.before.is_some() && rule.after.is_some() {
+                errors.push(RuleError::ConflictingPlacement { id: rule.id.clone() });
+            }
+            for name in rule.before.iter().chain(&rule.after) {
+                if !known.contains(&name.as_str()) {
+                    errors.push(RuleError::UnknownHandler {
+                        id: rule.id.clone(),
+                        name: name.clone(),
+                    });
+                }
+            }
+            known.push(&rule.field);
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        // rules placed at the same spot go after the pack's previous one there, so they run in the order they're declared
+        let mut last_inserted: Vec<((bool, &str), usize)> = Vec::new();
+        for (rule, handler) in handlers {
+            let spot = match &rule.after {
+                Some(name) => (true, name.as_str()),
+                None => (false, rule.before.as_ref().unwrap_or(&rule.field).as_str()),
+            };
+            let index = match last_inserted.iter().find(|(placed, _)| *placed == spot) {
+                Some((_, last)) => last + 1,
+                None => {
+                    let names: Vec<&str> = self.handler_names().collect();
+                    match spot {
+                        (true, name) => names
+                            .iter()
+                            .rposition(|handler| *handler == name)
+                            .map_or(names.len(), |last| last + 1),
+                        (false, name) => names.iter().position(|handler| *handler == name).unwrap_or(names.len()),
+                    }
+                }
+            };
+            self.insert_handler_at(index, handler);
+            for (_, last) in last_inserted.iter_mut().filter(|(_, last)| *last >= index) {
+                *last += 1;
+            }
+            last_inserted.retain(|(placed, _)| *placed != spot);
+            last_inserted.push((spot, index));
+        }
+        Ok(())
+    }
+}
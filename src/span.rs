@@ -0,0 +1,201 @@
+use std::ops::Range;
+
+use crate::Confidence;
+
+/// Byte span in the original input that produced a value of a field
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FieldSpan {
+    /// Name of the handler that matched (e.g. "resolution", "languages")
+    pub field: String,
+    /// The text the handler matched on
+    pub raw_match: String,
+    /// Byte range into the original input
+    pub span: Range<usize>,
+    pub confidence: Confidence,
+}
+
+/// Token after the end of the title that no handler matched
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LeftoverToken {
+    pub text: String,
+    /// Byte range into the original input
+    pub span: Range<usize>,
+}
+
+const TOKEN_SEPARATORS: &[char] = &[
+    '.', '_', '-', '+', ',', ':', ';', '|', '/', '\\', '[', ']', '(', ')', '{', '}', '<', '>', '【', '】', '~', '*',
+];
+
+/// Split the part of `raw_title` starting at `from` into tokens, skipping everything covered by `claimed`
+pub(crate) fn leftover_tokens(raw_title: &str, from: usize, claimed: &[Range<usize>]) -> Vec<LeftoverToken> {
+    let is_claimed = |index: usize| claimed.iter().any(|range| range.contains(&index));
+
+    let mut tokens = Vec::new();
+    let mut token_start: Option<usize> = None;
+    for (index, c) in raw_title.char_indices().chain(std::iter::once((raw_title.len(), ' '))) {
+        let is_token_char = index >= from && !c.is_whitespace() && !TOKEN_SEPARATORS.contains(&c) && !is_claimed(index);
+        match (token_start, is_token_char) {
+            (None, true) => token_start = Some(index),
+            (Some(start), false) => {
+                tokens.push(LeftoverToken {
+                    text: raw_title[start..index].to_string(),
+                    span: start..index,
+                });
+                token_start = None;
+            }
+            _ => {}
+        }
+    }
+    tokens
+}
+
+/// Part of the working title and where it came from in the original input
+struct Segment {
+    /// Start in the working title
+    start: usize,
+    len: usize,
+    /// Byte range in the original input, byte-by-byte if the lengths match (otherwise it was replaced)
+    original: Range<usize>,
+}
+
+impl Segment {
+    fn is_copy(&self) -> bool {
+        self.len == self.original.len()
+    }
+
+    fn end(&self) -> usize {
+        self.start + self.len
+    }
+
+    /// Original byte range of the working title byte at `index`
+    fn original_byte(&self, index: usize) -> Range<usize> {
+        if self.is_copy() {
+            let start = self.original.start + index - self.start;
+            start..start + 1
+        } else {
+            self.original.clone()
+        }
+    }
+}
+
+/// The title the handlers work on: the original input with separators replaced and matches cut out.
+///
+/// Instead of rebuilding the string on every removal, the text is shortened in place and a list of the parts of the
+/// original input that are still visible is kept, so every position can be mapped back to the original input.
+pub(crate) struct WorkingTitle {
+    text: String,
+    segments: Vec<Segment>,
+    original_len: usize,
+}
+
+impl WorkingTitle {
+    /// Copy of `original` where every range in `replaced` is replaced by a single space
+    pub fn new(original: &str, replaced: impl Iterator<Item = Range<usize>>) -> Self {
+        let mut title = WorkingTitle {
+            text: String::with_capacity(original.len()),
+            segments: Vec::new(),
+            original_len: original.len(),
+        };
+        let mut copied_until = 0;
+        for range in replaced {
+            title.push(&original[copied_until..range.start], copied_until..range.start);
+            title.push(" ", range.clone());
+            copied_until = range.end;
+        }
+        title.push(&original[copied_until..], copied_until..original.len());
+        title
+    }
+
+    fn push(&mut self, text: &str, original: Range<usize>) {
+        if text.is_empty() {
+            return;
+        }
+        self.segments.push(Segment {
+            start: self.text.len(),
+            len: text.len(),
+            original,
+        });
+        self.text.push_str(text);
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Cut `range` out of the working title, it has to lie on char boundaries
+    pub fn remove(&mut self, range: Range<usize>) {
+        let end = range.end.min(self.text.len());
+        let start = range.start.min(end);
+        if start == end {
+            return;
+        }
+        self.text.replace_range(start..end, "");
+
+        let removed = end - start;
+        let mut segments = Vec::with_capacity(self.segments.len() + 1);
+        for segment in self.segments.drain(..) {
+            if segment.end() <= start {
+                segments.push(segment);
+            } else if segment.start >= end {
+                segments.push(Segment {
+                    start: segment.start - removed,
+                    ..segment
+                });
+            } else if segment.is_copy() {
+                // keep what is left on either side of the removed part
+                if segment.start < start {
+                    segments.push(Segment {
+                        start: segment.start,
+                        len: start - segment.start,
+                        original: segment.original.start..segment.original.start + start - segment.start,
+                    });
+                }
+                if segment.end() > end {
+                    segments.push(Segment {
+                        start,
+                        len: segment.end() - end,
+                        original: segment.original.end - (segment.end() - end)..segment.original.end,
+                    });
+                }
+            }
+        }
+        self.segments = segments;
+    }
+
+    fn segment_at(&self, index: usize) -> Option<&Segment> {
+        let position = self.segments.partition_point(|segment| segment.end() <= index);
+        self.segments.get(position)
+    }
+
+    /// Original byte range covered by `range` of the working title
+    pub fn original_span(&self, range: Range<usize>) -> Range<usize> {
+        let start = self.original_offset(range.start);
+        let end = if range.end > range.start {
+            self.segment_at(range.end - 1)
+                .map(|segment| segment.original_byte(range.end - 1).end)
+                .unwrap_or(self.original_len)
+        } else {
+            start
+        };
+        start..end.max(start)
+    }
+
+    /// Position in the original input of the working title byte at `index` (the input length past the end)
+    pub fn original_offset(&self, index: usize) -> usize {
+        self.segment_at(index)
+            .map(|segment| segment.original_byte(index).start)
+            .unwrap_or(self.original_len)
+    }
+
+    /// Number of working title bytes that come from before `original_offset` in the original input
+    pub fn working_offset(&self, original_offset: usize) -> usize {
+        let position = self.segments.partition_point(|segment| segment.original.start < original_offset);
+        match position.checked_sub(1).map(|last| &self.segments[last]) {
+            Some(segment) if segment.is_copy() => segment.start + segment.len.min(original_offset - segment.original.start),
+            Some(segment) => segment.end(),
+            None => 0,
+        }
+    }
+}
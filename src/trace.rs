@@ -0,0 +1,48 @@
+use std::ops::Range;
+
+/// Why a handler didn't run or discarded its match
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "snake_case"))]
+pub enum SkipReason {
+    /// The field already had a value and the handler has `skip_if_already_found`
+    AlreadyFound,
+    /// The match came before all other matches and the handler has `skip_if_first`
+    SkipIfFirst,
+    /// The transform returned `None` for the matched text
+    TransformRejected,
+}
+
+/// What a single handler did during [`crate::Parser::parse_traced`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct HandlerTrace {
+    /// Position of the handler in the parser
+    pub index: usize,
+    pub name: String,
+    pub matched: bool,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw_match: Option<String>,
+    /// Byte index of the match in the working title at the time the handler ran
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub match_index: Option<usize>,
+    /// Byte range of the match in the original input
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub span: Option<Range<usize>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub skipped: Option<SkipReason>,
+    /// Whether the match was cut out of the working title
+    pub removed: bool,
+    /// Working title after the removal
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub title_after: Option<String>,
+    /// End of the title as an offset into the original input before the handler ran
+    pub end_of_title_before: usize,
+    /// End of the title as an offset into the original input after the handler ran
+    pub end_of_title_after: usize,
+}
+
+impl HandlerTrace {
+    pub fn end_of_title_moved(&self) -> bool {
+        self.end_of_title_before != self.end_of_title_after
+    }
+}
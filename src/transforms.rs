@@ -1,73 +1,127 @@
+//! Helpers that turn the text matched by a [`crate::Handler::from_regex`] pattern into a field value
+
+use std::fmt::{self, Display};
 use std::str::FromStr;
 
-use chrono::NaiveDate;
+use chrono::{Datelike, NaiveDate};
 use lazy_static::lazy_static;
 use regex::Regex;
 
+use crate::{AudioCodec, Crc32, HdrFormat, Resolution};
+
 lazy_static! {
     static ref SANITIZER_REGEX: Regex = Regex::new(r"\W+").unwrap();
 }
 
-pub fn identity<'a>(value: &'a str, _: &Option<String>) -> Option<Option<String>> {
-    Some(Some(value.to_string()))
+/// What a [`Transform`] does, as exported by [`crate::Parser::handler_infos`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TransformInfo {
+    /// Name of the helper in this module that built the transform, "custom" for closures
+    pub kind: &'static str,
+    /// Arguments the helper was built with, e.g. the value `uniq_concat` adds
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    pub params: Vec<String>,
 }
 
-pub fn identity_non_optional(value: &str, _: &String) -> Option<String> {
-    Some(value.to_string())
+impl fmt::Display for TransformInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.params.is_empty() {
+            f.write_str(self.kind)
+        } else {
+            write!(f, "{}({})", self.kind, self.params.join(", "))
+        }
+    }
 }
 
-pub fn uppercase(value: &str, _: &Option<String>) -> Option<Option<String>> {
-    Some(Some(value.to_uppercase()))
-}
+type TransformFn<T> = dyn Fn(&str, &T) -> Option<T> + Send + Sync;
 
-pub fn lowercase(value: &str, _: &Option<String>) -> Option<Option<String>> {
-    Some(Some(value.to_lowercase()))
+/// Turns the matched text and the current field value into the new field value, `None` rejects the match
+///
+/// Closures convert into a transform of kind "custom", use [`Transform::new`] to describe them.
+pub struct Transform<T> {
+    func: Box<TransformFn<T>>,
+    info: TransformInfo,
 }
 
-pub fn true_if_found(value: &str, _: &bool) -> Option<bool> {
-    if value.is_empty() {
-        None
-    } else {
-        Some(true)
+impl<T> Transform<T> {
+    pub fn new<F>(kind: &'static str, params: Vec<String>, func: F) -> Self
+    where
+        F: Fn(&str, &T) -> Option<T> + Send + Sync + 'static,
+    {
+        Transform {
+            func: Box::new(func),
+            info: TransformInfo { kind, params },
+        }
     }
-}
 
-pub fn parse<T: FromStr>(value: &str, _: &Option<T>) -> Option<Option<T>> {
-    let value = value.trim();
-    if value.is_empty() {
-        None
-    } else {
-        match value.parse::<T>() {
-            Ok(result) => Some(Some(result)),
-            Err(_) => None,
-        }
+    pub fn info(&self) -> &TransformInfo {
+        &self.info
+    }
+
+    pub(crate) fn apply(&self, value: &str, current: &T) -> Option<T> {
+        (self.func)(value, current)
     }
 }
 
-/// note: `$1` is replaced with the input value
-pub fn value(value: &'static str) -> impl Fn(&str, &Option<String>) -> Option<Option<String>> {
-    move |input_value: &str, _| {
-        let mut result = value.to_string();
-        result = result.replace("$1", input_value);
-        Some(Some(result))
+impl<T, F> From<F> for Transform<T>
+where
+    F: Fn(&str, &T) -> Option<T> + Send + Sync + 'static,
+{
+    fn from(func: F) -> Self {
+        Transform::new("custom", Vec::new(), func)
     }
 }
 
-pub fn const_value<T: Clone>(value: T) -> impl Fn(&str, &Option<T>) -> Option<Option<T>> {
-    move |_, _| -> Option<Option<T>> { Some(Some(value.clone())) }
+pub(crate) fn identity() -> Transform<Option<String>> {
+    Transform::new("identity", Vec::new(), |value, _| Some(Some(value.to_string())))
+}
+
+pub(crate) fn identity_non_optional() -> Transform<String> {
+    Transform::new("identity", Vec::new(), |value, _| Some(value.to_string()))
+}
+
+pub(crate) fn uppercase() -> Transform<Option<String>> {
+    Transform::new("uppercase", Vec::new(), |value, _| Some(Some(value.to_uppercase())))
+}
+
+pub(crate) fn lowercase() -> Transform<Option<String>> {
+    Transform::new("lowercase", Vec::new(), |value, _| Some(Some(value.to_lowercase())))
+}
+
+pub(crate) fn true_if_found() -> Transform<bool> {
+    Transform::new(
+        "true_if_found",
+        Vec::new(),
+        |value, _| if value.is_empty() { None } else { Some(true) },
+    )
+}
+
+pub(crate) fn parse<T: FromStr + 'static>() -> Transform<Option<T>> {
+    Transform::new("parse", Vec::new(), |value, _| {
+        let value = value.trim();
+        if value.is_empty() {
+            None
+        } else {
+            match value.parse::<T>() {
+                Ok(result) => Some(Some(result)),
+                Err(_) => None,
+            }
+        }
+    })
 }
 
 /// note: `$1` is replaced with the input value
-pub fn replace_value(value: &'static str) -> impl Fn(&str) -> String {
-    move |input_value: &str| -> String {
+pub fn value(value: &'static str) -> Transform<Option<String>> {
+    Transform::new("value", vec![value.to_string()], move |input_value, _| {
         let mut result = value.to_string();
         result = result.replace("$1", input_value);
-        result
-    }
+        Some(Some(result))
+    })
 }
 
-pub fn replace_with_value<T: Clone>(value: T) -> impl Fn(&str) -> T {
-    move |_| -> T { value.clone() }
+pub(crate) fn const_value<T: Clone + Display + Send + Sync + 'static>(value: T) -> Transform<Option<T>> {
+    Transform::new("const_value", vec![value.to_string()], move |_, _| Some(Some(value.clone())))
 }
 
 fn convert_months(date_str: &str) -> String {
@@ -99,42 +153,45 @@ fn convert_months(date_str: &str) -> String {
     result
 }
 
-/// Return a transformer that parses dates using the specified format
-///
-/// :param date_format: The date format to use for parsing (e.g. "YYYY MM DD")
-/// :return: The transformer function.
-pub fn date_from_format(format: &'static str) -> impl Fn(&str, &Option<String>) -> Option<Option<String>> {
-    move |input_value: &str, _| {
-        let sanitized = SANITIZER_REGEX.replace_all(input_value, " ").trim().to_string();
-        let sanitized = convert_months(&sanitized);
+/// Parse a date using the specified format (e.g. "%Y %m %d") and normalize it to YYYY-MM-DD
+fn parse_date(input_value: &str, format: &str) -> Option<String> {
+    let sanitized = SANITIZER_REGEX.replace_all(input_value, " ").trim().to_string();
+    let sanitized = convert_months(&sanitized);
 
-        #[cfg(feature = "debug")]
-        println!("input_value: '{}' sanitized: '{}' for format: '{}'", input_value, sanitized, format);
+    let date = NaiveDate::parse_from_str(&sanitized, format).ok()?;
 
-        let date = NaiveDate::parse_from_str(&sanitized, format).ok()?;
+    Some(date.format("%Y-%m-%d").to_string())
+}
 
-        Some(Some(date.format("%Y-%m-%d").to_string())) // normalize to YYYY-MM-DD
-    }
+/// Return a transformer that parses dates using the specified format
+pub(crate) fn date_from_format(format: &'static str) -> Transform<Option<String>> {
+    Transform::new("date_from_format", vec![format.to_string()], move |input_value, _| {
+        parse_date(input_value, format).map(Some)
+    })
 }
 
-/// Return a transformer that parses dates using the specified formats
-pub fn date_from_formats(formats: &'static [&'static str]) -> impl Fn(&str, &Option<String>) -> Option<Option<String>> {
-    let format_functions: Vec<_> = formats.iter().map(|format| date_from_format(format)).collect();
+/// Return a transformer that parses dates using the first of the specified formats that fits
+pub fn date_from_formats(formats: &[&str]) -> Transform<Option<String>> {
+    let formats: Vec<String> = formats.iter().map(|format| format.to_string()).collect();
+    Transform::new("date_from_formats", formats.clone(), move |input_value, _| {
+        formats.iter().find_map(|format| parse_date(input_value, format)).map(Some)
+    })
+}
 
-    move |input_value: &str, existing| {
-        for format_function in &format_functions {
-            let result = format_function(input_value, existing);
-            if result.is_some() {
-                return result;
-            }
-        }
-        None
-    }
+/// Return a transformer that adds `value` to the list, unless it is already in it
+pub fn uniq_concat<T>(value: impl Into<T>) -> Transform<Vec<T>>
+where
+    T: Clone + PartialEq + Display + Send + Sync + 'static,
+{
+    let value: T = value.into();
+    Transform::new("uniq_concat", vec![value.to_string()], move |_, result| {
+        push_unique(value.clone(), result)
+    })
 }
 
-pub fn uniq_concat<T: Clone + PartialEq>(value: impl Into<T>, result: &Vec<T>) -> Option<Vec<T>> {
+#[allow(clippy::ptr_arg)] // signature has to match the `&mut Vec<T>` field accessor
+pub(crate) fn push_unique<T: Clone + PartialEq>(value: T, result: &Vec<T>) -> Option<Vec<T>> {
     let mut result = result.clone();
-    let value: T = value.into();
     if result.contains(&value) {
         return Some(result);
     }
@@ -142,34 +199,112 @@ pub fn uniq_concat<T: Clone + PartialEq>(value: impl Into<T>, result: &Vec<T>) -
     Some(result)
 }
 
-/// Transform the resolution string to a standardized format (e.g. 1080p)
-pub fn resolution_transform(value: &str, _: &Option<String>) -> Option<Option<String>> {
+/// Add DTS:X for "DTS-X", the lossless DTS codec for "DTS-HD MA"
+pub(crate) fn dts_lossless() -> Transform<Vec<AudioCodec>> {
+    Transform::new("dts_lossless", Vec::new(), |value, result| {
+        let codec = if value.to_lowercase().ends_with('x') {
+            AudioCodec::DtsX
+        } else {
+            AudioCodec::DtsLossless
+        };
+        push_unique(codec, result)
+    })
+}
+
+/// Parse a CRC32 checksum, except for eight digits that read as a date (e.g. "[20190815]" of a daily show)
+pub(crate) fn checksum() -> Transform<Option<Crc32>> {
+    Transform::new("checksum", Vec::new(), |value, _| {
+        let is_date = value.bytes().all(|b| b.is_ascii_digit())
+            && NaiveDate::parse_from_str(value, "%Y%m%d").is_ok_and(|date| (1900..2100).contains(&date.year()));
+        if is_date {
+            return None;
+        }
+        value.parse().ok().map(Some)
+    })
+}
+
+/// Transform the resolution string to a standardized resolution (e.g. 1080p)
+pub(crate) fn resolution_transform() -> Transform<Option<Resolution>> {
+    Transform::new("resolution_transform", Vec::new(), |value, _| standard_resolution(value).map(Some))
+}
+
+fn standard_resolution(value: &str) -> Option<Resolution> {
     let input_value = value.to_lowercase();
 
-    if input_value.contains("2160") || input_value.contains("4k") {
-        return Some(Some("2160p".to_string()));
+    if input_value.contains("4320") || input_value.contains("8k") {
+        return Some(Resolution::P4320);
+    } else if input_value.contains("2160") || input_value.contains("4k") {
+        return Some(Resolution::P2160);
     } else if input_value.contains("1440") || input_value.contains("2k") {
-        return Some(Some("1440p".to_string()));
+        return Some(Resolution::P1440);
     } else if input_value.contains("1080") {
-        return Some(Some("1080p".to_string()));
+        return Some(Resolution::P1080);
     } else if input_value.contains("720") {
-        return Some(Some("720p".to_string()));
+        return Some(Resolution::P720);
+    } else if input_value.contains("576") {
+        return Some(Resolution::P576);
     } else if input_value.contains("480") {
-        return Some(Some("480p".to_string()));
+        return Some(Resolution::P480);
     } else if input_value.contains("360") {
-        return Some(Some("360p".to_string()));
+        return Some(Resolution::P360);
     } else if input_value.contains("240") {
-        return Some(Some("240p".to_string()));
+        return Some(Resolution::P240);
     }
     None
 }
 
+/// Parse a pixel height (e.g. "567") into a resolution
+pub(crate) fn resolution_from_height() -> Transform<Option<Resolution>> {
+    Transform::new("resolution_from_height", Vec::new(), |value, _| {
+        let height = value.trim().parse::<u32>().ok()?;
+        Some(Some(Resolution::from_height(height)))
+    })
+}
+
+/// Parse a frame size (e.g. "720x576") into a resolution
+pub(crate) fn resolution_from_dimensions() -> Transform<Option<Resolution>> {
+    Transform::new("resolution_from_dimensions", Vec::new(), |value, _| {
+        let (width, height) = value
+            .to_lowercase()
+            .split_once('x')
+            .map(|(w, h)| (w.trim().parse::<u32>(), h.trim().parse::<u32>()))?;
+        Some(Some(Resolution::from_dimensions(width.ok()?, height.ok()?)))
+    })
+}
+
+/// Add Dolby Vision with the profile number from the input (e.g. "8" or "8.1")
+pub(crate) fn dolby_vision_profile() -> Transform<Vec<HdrFormat>> {
+    Transform::new("dolby_vision_profile", Vec::new(), |value, result| {
+        let profile = value.split('.').next()?.trim().parse::<u8>().ok()?;
+        push_unique(HdrFormat::DolbyVision(Some(profile)), result)
+    })
+}
+
+/// Add Dolby Vision without a profile, unless it was already found with one
+pub(crate) fn dolby_vision() -> Transform<Vec<HdrFormat>> {
+    Transform::new("dolby_vision", Vec::new(), |_, result: &Vec<HdrFormat>| {
+        if result.iter().any(HdrFormat::is_dolby_vision) {
+            return Some(result.clone());
+        }
+        push_unique(HdrFormat::DolbyVision(None), result)
+    })
+}
+
 lazy_static! {
     static ref RANGE_REGEX: Regex = Regex::new(r"\d+").unwrap();
 }
 
+/// Parse a single number into a one-element list (e.g. the season in "S01")
+pub(crate) fn array_integer() -> Transform<Vec<i32>> {
+    Transform::new("array_integer", Vec::new(), |value, _| Some(vec![value.parse().ok()?]))
+}
+
 // Parse a range of numbers from the input string
-pub fn range_func(value: &str, _: &Vec<i32>) -> Option<Vec<i32>> {
+pub fn range_func() -> Transform<Vec<i32>> {
+    Transform::new("range_func", Vec::new(), |value, _| parse_range(value))
+}
+
+fn parse_range(value: &str) -> Option<Vec<i32>> {
     let numbers: Vec<i32> = RANGE_REGEX.find_iter(value).flat_map(|m| m.as_str().parse::<i32>()).collect();
 
     if numbers.len() == 2 && numbers[0] < numbers[1] {
@@ -185,19 +320,3 @@ pub fn range_func(value: &str, _: &Vec<i32>) -> Option<Vec<i32>> {
         None
     }
 }
-
-// region: Chaining
-
-pub fn chain_transforms<T, F1, F2, R1>(transform1: F1, transform2: F2) -> impl Fn(&str, &T) -> Option<T>
-where
-    F1: Fn(&str) -> R1,
-    F2: Fn(R1, &T) -> Option<T>,
-    R1: Clone,
-{
-    move |value: &str, state: &T| {
-        let intermediate = transform1(value);
-        transform2(intermediate, state)
-    }
-}
-
-// endregion
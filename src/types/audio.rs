@@ -0,0 +1,140 @@
+use std::str::FromStr;
+
+use super::UnknownVariantError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AudioCodec {
+    DolbyDigital,
+    DolbyDigitalPlus,
+    DtsLossless,
+    DtsLossy,
+    DtsX,
+    Atmos,
+    TrueHD,
+    Flac,
+    Eac3,
+    Ac3,
+    Aac,
+    Mp3,
+    HqCleanAudio,
+}
+
+impl AudioCodec {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AudioCodec::DolbyDigital => "Dolby Digital",
+            AudioCodec::DolbyDigitalPlus => "Dolby Digital Plus",
+            AudioCodec::DtsLossless => "DTS Lossless",
+            AudioCodec::DtsLossy => "DTS Lossy",
+            AudioCodec::DtsX => "DTS:X",
+            AudioCodec::Atmos => "Atmos",
+            AudioCodec::TrueHD => "TrueHD",
+            AudioCodec::Flac => "FLAC",
+            AudioCodec::Eac3 => "EAC3",
+            AudioCodec::Ac3 => "AC3",
+            AudioCodec::Aac => "AAC",
+            AudioCodec::Mp3 => "MP3",
+            AudioCodec::HqCleanAudio => "HQ Clean Audio",
+        }
+    }
+
+    /// Lossless codecs (DTS-HD MA, DTS:X, TrueHD, FLAC)
+    pub fn is_lossless(&self) -> bool {
+        matches!(
+            self,
+            AudioCodec::DtsLossless | AudioCodec::DtsX | AudioCodec::TrueHD | AudioCodec::Flac
+        )
+    }
+
+    /// Lossy codecs. Atmos and "HQ Clean Audio" are neither lossless nor lossy, as they don't say anything about the underlying codec.
+    pub fn is_lossy(&self) -> bool {
+        matches!(
+            self,
+            AudioCodec::DolbyDigital
+                | AudioCodec::DolbyDigitalPlus
+                | AudioCodec::DtsLossy
+                | AudioCodec::Eac3
+                | AudioCodec::Ac3
+                | AudioCodec::Aac
+                | AudioCodec::Mp3
+        )
+    }
+
+    /// Object-based surround formats (Atmos, DTS:X)
+    pub fn is_object_based(&self) -> bool {
+        matches!(self, AudioCodec::Atmos | AudioCodec::DtsX)
+    }
+}
+
+impl FromStr for AudioCodec {
+    type Err = UnknownVariantError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Dolby Digital" => Ok(AudioCodec::DolbyDigital),
+            "Dolby Digital Plus" => Ok(AudioCodec::DolbyDigitalPlus),
+            "DTS Lossless" => Ok(AudioCodec::DtsLossless),
+            "DTS Lossy" => Ok(AudioCodec::DtsLossy),
+            "DTS:X" => Ok(AudioCodec::DtsX),
+            "Atmos" => Ok(AudioCodec::Atmos),
+            "TrueHD" => Ok(AudioCodec::TrueHD),
+            "FLAC" => Ok(AudioCodec::Flac),
+            "EAC3" => Ok(AudioCodec::Eac3),
+            "AC3" => Ok(AudioCodec::Ac3),
+            "AAC" => Ok(AudioCodec::Aac),
+            "MP3" => Ok(AudioCodec::Mp3),
+            "HQ Clean Audio" => Ok(AudioCodec::HqCleanAudio),
+            _ => Err(UnknownVariantError::new("audio", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AudioChannels {
+    Mono,
+    Stereo,
+    TwoPointZero,
+    FivePointOne,
+    SevenPointOne,
+}
+
+impl AudioChannels {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AudioChannels::Mono => "mono",
+            AudioChannels::Stereo => "stereo",
+            AudioChannels::TwoPointZero => "2.0",
+            AudioChannels::FivePointOne => "5.1",
+            AudioChannels::SevenPointOne => "7.1",
+        }
+    }
+
+    /// Total number of channels, including LFE
+    pub fn channel_count(&self) -> u8 {
+        match self {
+            AudioChannels::Mono => 1,
+            AudioChannels::Stereo | AudioChannels::TwoPointZero => 2,
+            AudioChannels::FivePointOne => 6,
+            AudioChannels::SevenPointOne => 8,
+        }
+    }
+
+    pub fn is_surround(&self) -> bool {
+        self.channel_count() > 2
+    }
+}
+
+impl FromStr for AudioChannels {
+    type Err = UnknownVariantError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mono" => Ok(AudioChannels::Mono),
+            "stereo" => Ok(AudioChannels::Stereo),
+            "2.0" => Ok(AudioChannels::TwoPointZero),
+            "5.1" => Ok(AudioChannels::FivePointOne),
+            "7.1" => Ok(AudioChannels::SevenPointOne),
+            _ => Err(UnknownVariantError::new("channels", s)),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use std::fmt;
+use std::str::FromStr;
+
+use super::UnknownVariantError;
+
+/// CRC32 of the file, as fansub groups put it in brackets at the end of the name (e.g. "[A1B2C3D4]")
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Crc32(pub u32);
+
+impl fmt::Display for Crc32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:08X}", self.0)
+    }
+}
+
+impl FromStr for Crc32 {
+    type Err = UnknownVariantError;
+
+    /// Exactly eight hex digits, in either case
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 8 || !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(UnknownVariantError::new("checksum", s));
+        }
+        u32::from_str_radix(s, 16)
+            .map(Crc32)
+            .map_err(|_| UnknownVariantError::new("checksum", s))
+    }
+}
@@ -1,3 +1,7 @@
+use std::str::FromStr;
+
+use super::UnknownVariantError;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Codec {
     Avc,
@@ -18,3 +22,18 @@ impl Codec {
         }
     }
 }
+
+impl FromStr for Codec {
+    type Err = UnknownVariantError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "avc" => Ok(Codec::Avc),
+            "hevc" => Ok(Codec::Hevc),
+            "xvid" => Ok(Codec::Xvid),
+            "mpeg" => Ok(Codec::Mpeg),
+            "av1" => Ok(Codec::Av1),
+            _ => Err(UnknownVariantError::new("codec", s)),
+        }
+    }
+}
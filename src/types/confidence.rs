@@ -0,0 +1,47 @@
+use std::str::FromStr;
+
+use super::UnknownVariantError;
+
+/// How reliable a handler match is, ordered from least to most reliable
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Confidence {
+    /// The value isn't in the title itself but implied by something else (e.g. "5.1" implying AC3 audio)
+    Inferred,
+    /// The match is based on a fuzzy rule that is often, but not always, right (e.g. a bare "EN")
+    Heuristic,
+    /// The title contains an unambiguous tag for the value
+    #[default]
+    Exact,
+}
+
+impl Confidence {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Confidence::Inferred => "inferred",
+            Confidence::Heuristic => "heuristic",
+            Confidence::Exact => "exact",
+        }
+    }
+
+    /// Numeric score between 0 and 1 used for the aggregate confidence of a parse
+    pub fn score(&self) -> f32 {
+        match self {
+            Confidence::Inferred => 0.3,
+            Confidence::Heuristic => 0.6,
+            Confidence::Exact => 1.0,
+        }
+    }
+}
+
+impl FromStr for Confidence {
+    type Err = UnknownVariantError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "inferred" => Ok(Confidence::Inferred),
+            "heuristic" => Ok(Confidence::Heuristic),
+            "exact" => Ok(Confidence::Exact),
+            _ => Err(UnknownVariantError::new("confidence", s)),
+        }
+    }
+}
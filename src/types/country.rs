@@ -0,0 +1,48 @@
+use std::str::FromStr;
+
+use super::UnknownVariantError;
+
+/// Country suffix that tells versions of the same show apart, like "The Office US" and "The Office UK".
+///
+/// Only the small fixed set of markets that such remakes come from, not the full ISO 3166-1 list: other two-letter
+/// suffixes are far more often part of the title than a country.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RemakeCountry {
+    UnitedStates,
+    UnitedKingdom,
+    Australia,
+    NewZealand,
+    Canada,
+    Ireland,
+}
+
+impl RemakeCountry {
+    /// ISO 3166-1 alpha-2 code
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RemakeCountry::UnitedStates => "US",
+            RemakeCountry::UnitedKingdom => "GB",
+            RemakeCountry::Australia => "AU",
+            RemakeCountry::NewZealand => "NZ",
+            RemakeCountry::Canada => "CA",
+            RemakeCountry::Ireland => "IE",
+        }
+    }
+}
+
+impl FromStr for RemakeCountry {
+    type Err = UnknownVariantError;
+
+    /// Parses the ISO code, plus "UK" as used in titles
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "US" => Ok(RemakeCountry::UnitedStates),
+            "GB" | "UK" => Ok(RemakeCountry::UnitedKingdom),
+            "AU" => Ok(RemakeCountry::Australia),
+            "NZ" => Ok(RemakeCountry::NewZealand),
+            "CA" => Ok(RemakeCountry::Canada),
+            "IE" => Ok(RemakeCountry::Ireland),
+            _ => Err(UnknownVariantError::new("country", s)),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use std::str::FromStr;
+
+use super::UnknownVariantError;
+
+/// What a credited person did, if the title says so
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CreditRole {
+    /// "by Author", only recognized in audiobook titles
+    Author,
+    /// "read by ..." or "narrated by ..."
+    Narrator,
+    /// "(Director: ...)" or "(Режиссёр: ...)"
+    Director,
+}
+
+impl CreditRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CreditRole::Author => "author",
+            CreditRole::Narrator => "narrator",
+            CreditRole::Director => "director",
+        }
+    }
+}
+
+impl FromStr for CreditRole {
+    type Err = UnknownVariantError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "author" => Ok(CreditRole::Author),
+            "narrator" => Ok(CreditRole::Narrator),
+            "director" => Ok(CreditRole::Director),
+            _ => Err(UnknownVariantError::new("credit role", s)),
+        }
+    }
+}
+
+/// A person named in the title, e.g. the director or cast in "Бастер / Buster (Дэвид Грин / David Green)"
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Credit {
+    pub name: String,
+    /// `None` for names in parentheses, which may be actors as well as the director
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub role: Option<CreditRole>,
+    /// The name in another script, e.g. "Bart Layton" in "Барт Лэйтон / Bart Layton"
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub transliteration: Option<String>,
+}
+
+impl Credit {
+    pub fn new(name: impl Into<String>, role: Option<CreditRole>) -> Self {
+        Credit {
+            name: name.into(),
+            role,
+            transliteration: None,
+        }
+    }
+
+    pub fn with_transliteration(mut self, transliteration: impl Into<String>) -> Self {
+        self.transliteration = Some(transliteration.into());
+        self
+    }
+}
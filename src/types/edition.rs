@@ -0,0 +1,60 @@
+use std::convert::Infallible;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Edition {
+    AnniversaryEdition,
+    UltimateEdition,
+    DirectorsCut,
+    ExtendedEdition,
+    CollectorsEdition,
+    Theatrical,
+    Uncut,
+    Imax,
+    DiamondEdition,
+    Remastered,
+    Criterion,
+    /// Any edition without a dedicated variant (e.g. added by a custom handler)
+    Other(String),
+}
+
+impl Edition {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Edition::AnniversaryEdition => "Anniversary Edition",
+            Edition::UltimateEdition => "Ultimate Edition",
+            Edition::DirectorsCut => "Directors Cut",
+            Edition::ExtendedEdition => "Extended Edition",
+            Edition::CollectorsEdition => "Collectors Edition",
+            Edition::Theatrical => "Theatrical",
+            Edition::Uncut => "Uncut",
+            Edition::Imax => "IMAX",
+            Edition::DiamondEdition => "Diamond Edition",
+            Edition::Remastered => "Remastered",
+            Edition::Criterion => "Criterion Collection",
+            Edition::Other(edition) => edition,
+        }
+    }
+}
+
+impl FromStr for Edition {
+    type Err = Infallible;
+
+    /// Parses the representation returned by [`Edition::as_str`], falling back to `Other`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Anniversary Edition" => Edition::AnniversaryEdition,
+            "Ultimate Edition" => Edition::UltimateEdition,
+            "Directors Cut" => Edition::DirectorsCut,
+            "Extended Edition" => Edition::ExtendedEdition,
+            "Collectors Edition" => Edition::CollectorsEdition,
+            "Theatrical" => Edition::Theatrical,
+            "Uncut" => Edition::Uncut,
+            "IMAX" => Edition::Imax,
+            "Diamond Edition" => Edition::DiamondEdition,
+            "Remastered" => Edition::Remastered,
+            "Criterion Collection" => Edition::Criterion,
+            _ => Edition::Other(s.to_string()),
+        })
+    }
+}
@@ -0,0 +1,66 @@
+use std::fmt;
+use std::str::FromStr;
+
+use super::UnknownVariantError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HdrFormat {
+    /// Dolby Vision, with the profile if it is part of the title (e.g. "DV P5", "Dolby Vision Profile 8")
+    DolbyVision(Option<u8>),
+    Hdr10Plus,
+    /// HDR10 or unspecified HDR
+    Hdr,
+    Hlg,
+    Pq10,
+    Sdr,
+}
+
+impl HdrFormat {
+    pub fn is_dolby_vision(&self) -> bool {
+        matches!(self, HdrFormat::DolbyVision(_))
+    }
+
+    /// Whether the formats contain a layer that plays on HDR10 devices without Dolby Vision support.
+    /// This is the case for Dolby Vision profiles 7 and 8 or when HDR10 / HDR10+ is listed next to it (e.g. "DV.HDR10").
+    /// Profile 5 (and Dolby Vision without a known profile) on its own has no fallback.
+    pub fn has_hdr10_fallback(formats: &[HdrFormat]) -> bool {
+        formats
+            .iter()
+            .any(|format| matches!(format, HdrFormat::Hdr | HdrFormat::Hdr10Plus | HdrFormat::DolbyVision(Some(7 | 8))))
+    }
+}
+
+impl fmt::Display for HdrFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HdrFormat::DolbyVision(None) => write!(f, "DV"),
+            HdrFormat::DolbyVision(Some(profile)) => write!(f, "DV P{}", profile),
+            HdrFormat::Hdr10Plus => write!(f, "HDR10+"),
+            HdrFormat::Hdr => write!(f, "HDR"),
+            HdrFormat::Hlg => write!(f, "HLG"),
+            HdrFormat::Pq10 => write!(f, "PQ10"),
+            HdrFormat::Sdr => write!(f, "SDR"),
+        }
+    }
+}
+
+impl FromStr for HdrFormat {
+    type Err = UnknownVariantError;
+
+    /// Parses the representation returned by `to_string` (e.g. "DV P8")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "DV" => Ok(HdrFormat::DolbyVision(None)),
+            "HDR10+" => Ok(HdrFormat::Hdr10Plus),
+            "HDR" => Ok(HdrFormat::Hdr),
+            "HLG" => Ok(HdrFormat::Hlg),
+            "PQ10" => Ok(HdrFormat::Pq10),
+            "SDR" => Ok(HdrFormat::Sdr),
+            _ => s
+                .strip_prefix("DV P")
+                .and_then(|profile| profile.parse::<u8>().ok())
+                .map(|profile| HdrFormat::DolbyVision(Some(profile)))
+                .ok_or_else(|| UnknownVariantError::new("hdr", s)),
+        }
+    }
+}
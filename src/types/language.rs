@@ -1,3 +1,7 @@
+use std::str::FromStr;
+
+use super::UnknownVariantError;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Language {
     English,
@@ -52,7 +56,15 @@ pub enum Language {
 }
 
 impl Language {
-    pub fn to_iso_639_1(&self) -> &str {
+    /// Stable string representation: the ISO 639-1 code, except for Latin American Spanish which uses "es-419"
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Language::LatinAmericanSpanish => "es-419",
+            _ => self.to_iso_639_1(),
+        }
+    }
+
+    pub fn to_iso_639_1(&self) -> &'static str {
         match self {
             Language::English => "en",
             Language::Italian => "it",
@@ -104,3 +116,61 @@ impl Language {
         }
     }
 }
+
+impl FromStr for Language {
+    type Err = UnknownVariantError;
+
+    /// Parses the representation returned by [`Language::as_str`]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "es-419" => Ok(Language::LatinAmericanSpanish),
+            "en" => Ok(Language::English),
+            "it" => Ok(Language::Italian),
+            "fr" => Ok(Language::French),
+            "es" => Ok(Language::Spanish),
+            "de" => Ok(Language::German),
+            "pt" => Ok(Language::Portuguese),
+            "ja" => Ok(Language::Japanese),
+            "ko" => Ok(Language::Korean),
+            "zh" => Ok(Language::Chinese),
+            "ru" => Ok(Language::Russian),
+            "uk" => Ok(Language::Ukrainian),
+            "el" => Ok(Language::Greek),
+            "lt" => Ok(Language::Lithuanian),
+            "lv" => Ok(Language::Latvian),
+            "et" => Ok(Language::Estonian),
+            "pl" => Ok(Language::Polish),
+            "cs" => Ok(Language::Czech),
+            "sk" => Ok(Language::Slovak),
+            "hu" => Ok(Language::Hungarian),
+            "ro" => Ok(Language::Romanian),
+            "bg" => Ok(Language::Bulgarian),
+            "hr" => Ok(Language::Croatian),
+            "sr" => Ok(Language::Serbian),
+            "sl" => Ok(Language::Slovenian),
+            "nl" => Ok(Language::Dutch),
+            "da" => Ok(Language::Danish),
+            "fi" => Ok(Language::Finnish),
+            "no" => Ok(Language::Norwegian),
+            "sv" => Ok(Language::Swedish),
+            "tr" => Ok(Language::Turkish),
+            "ar" => Ok(Language::Arabic),
+            "id" => Ok(Language::Indonesian),
+            "vi" => Ok(Language::Vietnamese),
+            "th" => Ok(Language::Thai),
+            "ms" => Ok(Language::Malay),
+            "he" => Ok(Language::Hebrew),
+            "fa" => Ok(Language::Persian),
+            "hi" => Ok(Language::Hindi),
+            "te" => Ok(Language::Telugu),
+            "ta" => Ok(Language::Tamil),
+            "ml" => Ok(Language::Malayalam),
+            "kn" => Ok(Language::Kannada),
+            "mr" => Ok(Language::Marathi),
+            "gu" => Ok(Language::Gujarati),
+            "bn" => Ok(Language::Bengali),
+            "pa" => Ok(Language::Punjabi),
+            _ => Err(UnknownVariantError::new("language", s)),
+        }
+    }
+}
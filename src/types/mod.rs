@@ -1,9 +1,137 @@
+mod audio;
+mod checksum;
 mod codec;
+mod confidence;
+mod country;
+mod credit;
+mod edition;
+mod field_value;
+mod hdr;
 mod language;
 mod network;
 mod quality;
+mod resolution;
+mod script;
 
+pub use audio::{AudioChannels, AudioCodec};
+pub use checksum::Crc32;
 pub use codec::Codec;
+pub use confidence::Confidence;
+pub use country::RemakeCountry;
+pub use credit::{Credit, CreditRole};
+pub use edition::Edition;
+pub use field_value::FieldValue;
+pub use hdr::HdrFormat;
 pub use language::Language;
 pub use network::Network;
 pub use quality::Quality;
+pub use resolution::Resolution;
+pub use script::{AlternativeTitle, Script};
+
+use thiserror::Error;
+
+/// Returned when parsing one of the value enums from a string that doesn't match any variant
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("unknown {kind} value: {value}")]
+pub struct UnknownVariantError {
+    pub kind: &'static str,
+    pub value: String,
+}
+
+impl UnknownVariantError {
+    pub(crate) fn new(kind: &'static str, value: &str) -> Self {
+        UnknownVariantError {
+            kind,
+            value: value.to_string(),
+        }
+    }
+}
+
+/// Display an enum as the string returned by its `as_str` method
+macro_rules! impl_display_as_str {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl std::fmt::Display for $t {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.write_str(self.as_str())
+                }
+            }
+        )*
+    };
+}
+
+impl_display_as_str!(
+    AudioChannels,
+    AudioCodec,
+    Codec,
+    Confidence,
+    RemakeCountry,
+    CreditRole,
+    Edition,
+    Language,
+    Network,
+    Quality,
+    Script
+);
+
+/// (De)serialize an enum as the string returned by its `as_str` method
+#[cfg(feature = "serde")]
+macro_rules! impl_serde_as_str {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl serde::Serialize for $t {
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.serialize_str(self.as_str())
+                }
+            }
+
+            impl_serde_from_str!($t);
+        )*
+    };
+}
+
+/// (De)serialize a type that carries data as its `Display` representation
+#[cfg(feature = "serde")]
+macro_rules! impl_serde_display {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl serde::Serialize for $t {
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl_serde_from_str!($t);
+        )*
+    };
+}
+
+/// Deserialize through the type's `FromStr` implementation
+#[cfg(feature = "serde")]
+macro_rules! impl_serde_from_str {
+    ($t:ty) => {
+        impl<'de> serde::Deserialize<'de> for $t {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = String::deserialize(deserializer)?;
+                value.parse().map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "serde")]
+impl_serde_as_str!(
+    AudioChannels,
+    AudioCodec,
+    Codec,
+    Confidence,
+    RemakeCountry,
+    CreditRole,
+    Edition,
+    Language,
+    Network,
+    Quality,
+    Script
+);
+#[cfg(feature = "serde")]
+impl_serde_display!(Crc32, HdrFormat, Resolution);
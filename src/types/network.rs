@@ -1,3 +1,7 @@
+use std::str::FromStr;
+
+use super::UnknownVariantError;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Network {
     AppleTV,
@@ -18,3 +22,54 @@ pub enum Network {
     AdultSwim,
     AnimalPlanet,
 }
+
+impl Network {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Network::AppleTV => "Apple TV",
+            Network::Amazon => "Amazon",
+            Network::Netflix => "Netflix",
+            Network::HBO => "HBO",
+            Network::Disney => "Disney",
+            Network::Nickelodeon => "Nickelodeon",
+            Network::Hulu => "Hulu",
+            Network::CBS => "CBS",
+            Network::NBC => "NBC",
+            Network::AMC => "AMC",
+            Network::PBS => "PBS",
+            Network::Crunchyroll => "Crunchyroll",
+            Network::VICE => "VICE",
+            Network::Sony => "Sony",
+            Network::Hallmark => "Hallmark",
+            Network::AdultSwim => "Adult Swim",
+            Network::AnimalPlanet => "Animal Planet",
+        }
+    }
+}
+
+impl FromStr for Network {
+    type Err = UnknownVariantError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Apple TV" => Ok(Network::AppleTV),
+            "Amazon" => Ok(Network::Amazon),
+            "Netflix" => Ok(Network::Netflix),
+            "HBO" => Ok(Network::HBO),
+            "Disney" => Ok(Network::Disney),
+            "Nickelodeon" => Ok(Network::Nickelodeon),
+            "Hulu" => Ok(Network::Hulu),
+            "CBS" => Ok(Network::CBS),
+            "NBC" => Ok(Network::NBC),
+            "AMC" => Ok(Network::AMC),
+            "PBS" => Ok(Network::PBS),
+            "Crunchyroll" => Ok(Network::Crunchyroll),
+            "VICE" => Ok(Network::VICE),
+            "Sony" => Ok(Network::Sony),
+            "Hallmark" => Ok(Network::Hallmark),
+            "Adult Swim" => Ok(Network::AdultSwim),
+            "Animal Planet" => Ok(Network::AnimalPlanet),
+            _ => Err(UnknownVariantError::new("network", s)),
+        }
+    }
+}
@@ -1,3 +1,7 @@
+use std::str::FromStr;
+
+use super::UnknownVariantError;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Quality {
     TeleSync,
@@ -62,3 +66,40 @@ impl Quality {
         }
     }
 }
+
+impl FromStr for Quality {
+    type Err = UnknownVariantError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "TeleSync" => Ok(Quality::TeleSync),
+            "TeleCine" => Ok(Quality::TeleCine),
+            "SCR" => Ok(Quality::SCR),
+            "BluRay" => Ok(Quality::BluRay),
+            "BluRay REMUX" => Ok(Quality::BluRayRemux),
+            "REMUX" => Ok(Quality::Remux),
+            "UHDRip" => Ok(Quality::UHDRip),
+            "HDRip" => Ok(Quality::HDRip),
+            "BRRip" => Ok(Quality::BRRip),
+            "BDRip" => Ok(Quality::BDRip),
+            "DVDRip" => Ok(Quality::DVDRip),
+            "VHSRip" => Ok(Quality::VHSRip),
+            "DVD" => Ok(Quality::DVD),
+            "VHS" => Ok(Quality::VHS),
+            "PPVRip" => Ok(Quality::PPVRip),
+            "HDTVRip" => Ok(Quality::HDTVRip),
+            "HDTV" => Ok(Quality::HDTV),
+            "SATRip" => Ok(Quality::SATRip),
+            "TVRip" => Ok(Quality::TVRip),
+            "R5" => Ok(Quality::R5),
+            "WEBMux" => Ok(Quality::WebMux),
+            "WEBRip" => Ok(Quality::WebRip),
+            "WEB-DL" => Ok(Quality::WebDL),
+            "WEB-DLRip" => Ok(Quality::WebDLRip),
+            "WEB" => Ok(Quality::Web),
+            "CAM" => Ok(Quality::Cam),
+            "PDTV" => Ok(Quality::PDTV),
+            _ => Err(UnknownVariantError::new("quality", s)),
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use super::UnknownVariantError;
+
+/// Video resolution, ordered by pixel height
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Resolution {
+    P240,
+    P360,
+    P480,
+    P576,
+    P720,
+    P1080,
+    P1440,
+    P2160,
+    P4320,
+    /// Any other height (e.g. "567p")
+    Other(u32),
+}
+
+const STANDARD_RESOLUTIONS: [(Resolution, u32, u32); 9] = [
+    (Resolution::P240, 240, 426),
+    (Resolution::P360, 360, 640),
+    (Resolution::P480, 480, 854),
+    (Resolution::P576, 576, 1024),
+    (Resolution::P720, 720, 1280),
+    (Resolution::P1080, 1080, 1920),
+    (Resolution::P1440, 1440, 2560),
+    (Resolution::P2160, 2160, 3840),
+    (Resolution::P4320, 4320, 7680),
+];
+
+impl Resolution {
+    /// Pixel height (e.g. 1080 for 1080p)
+    pub fn height(&self) -> u32 {
+        match self {
+            Resolution::P240 => 240,
+            Resolution::P360 => 360,
+            Resolution::P480 => 480,
+            Resolution::P576 => 576,
+            Resolution::P720 => 720,
+            Resolution::P1080 => 1080,
+            Resolution::P1440 => 1440,
+            Resolution::P2160 => 2160,
+            Resolution::P4320 => 4320,
+            Resolution::Other(height) => *height,
+        }
+    }
+
+    /// Standard resolution with the given height, or `Other` if there is none
+    pub fn from_height(height: u32) -> Resolution {
+        STANDARD_RESOLUTIONS
+            .iter()
+            .find(|(_, h, _)| *h == height)
+            .map(|(resolution, _, _)| *resolution)
+            .unwrap_or(Resolution::Other(height))
+    }
+
+    /// Resolution of a frame size like 720x576, matching the height first and the (16:9) width second.
+    /// Slightly cropped widths (within 5%, e.g. 1896x1048) count as the closest standard width.
+    pub fn from_dimensions(width: u32, height: u32) -> Resolution {
+        STANDARD_RESOLUTIONS
+            .iter()
+            .find(|(_, h, _)| *h == height)
+            .or_else(|| {
+                STANDARD_RESOLUTIONS
+                    .iter()
+                    .filter(|(_, _, w)| w.abs_diff(width) * 20 <= *w)
+                    .min_by_key(|(_, _, w)| w.abs_diff(width))
+            })
+            .map(|(resolution, _, _)| *resolution)
+            .unwrap_or(Resolution::Other(height))
+    }
+}
+
+impl Ord for Resolution {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.height()
+            .cmp(&other.height())
+            .then_with(|| matches!(self, Resolution::Other(_)).cmp(&matches!(other, Resolution::Other(_))))
+    }
+}
+
+impl PartialOrd for Resolution {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Resolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}p", self.height())
+    }
+}
+
+impl FromStr for Resolution {
+    type Err = UnknownVariantError;
+
+    /// Parses the representation returned by `to_string` (e.g. "1080p")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.strip_suffix('p')
+            .and_then(|height| height.parse::<u32>().ok())
+            .map(Resolution::from_height)
+            .ok_or_else(|| UnknownVariantError::new("resolution", s))
+    }
+}
@@ -0,0 +1,94 @@
+use std::str::FromStr;
+
+use super::UnknownVariantError;
+
+/// Writing system of a non-Latin title, covering the same ranges the title cleanup treats as non-English
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Script {
+    Cyrillic,
+    /// Contains kana, possibly mixed with kanji
+    Japanese,
+    /// Han characters without any kana
+    Chinese,
+    Arabic,
+    Kannada,
+    Malayalam,
+    Thai,
+    /// Contains hangul
+    Korean,
+}
+
+impl Script {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Script::Cyrillic => "cyrillic",
+            Script::Japanese => "japanese",
+            Script::Chinese => "chinese",
+            Script::Arabic => "arabic",
+            Script::Kannada => "kannada",
+            Script::Malayalam => "malayalam",
+            Script::Thai => "thai",
+            Script::Korean => "korean",
+        }
+    }
+
+    /// Script of a single character, `None` for Latin and everything else
+    pub fn of_char(c: char) -> Option<Script> {
+        match c {
+            '\u{3040}'..='\u{30ff}' | '\u{ff66}'..='\u{ff9f}' => Some(Script::Japanese),
+            '\u{3400}'..='\u{4dbf}' | '\u{4e00}'..='\u{9fff}' | '\u{f900}'..='\u{faff}' => Some(Script::Chinese),
+            '\u{0400}'..='\u{04ff}' => Some(Script::Cyrillic),
+            '\u{0600}'..='\u{06ff}' | '\u{0750}'..='\u{077f}' => Some(Script::Arabic),
+            '\u{0c80}'..='\u{0cff}' => Some(Script::Kannada),
+            '\u{0d00}'..='\u{0d7f}' => Some(Script::Malayalam),
+            '\u{0e00}'..='\u{0e7f}' => Some(Script::Thai),
+            '\u{1100}'..='\u{11ff}' | '\u{3130}'..='\u{318f}' | '\u{ac00}'..='\u{d7af}' => Some(Script::Korean),
+            _ => None,
+        }
+    }
+
+    /// The non-Latin script a text is written in: the first one found, except that any kana makes it Japanese
+    pub fn detect(text: &str) -> Option<Script> {
+        let mut scripts = text.chars().filter_map(Script::of_char);
+        let first = scripts.next()?;
+        if first == Script::Chinese && scripts.any(|script| script == Script::Japanese) {
+            return Some(Script::Japanese);
+        }
+        Some(first)
+    }
+}
+
+impl FromStr for Script {
+    type Err = UnknownVariantError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "cyrillic" => Ok(Script::Cyrillic),
+            "japanese" => Ok(Script::Japanese),
+            "chinese" => Ok(Script::Chinese),
+            "arabic" => Ok(Script::Arabic),
+            "kannada" => Ok(Script::Kannada),
+            "malayalam" => Ok(Script::Malayalam),
+            "thai" => Ok(Script::Thai),
+            "korean" => Ok(Script::Korean),
+            _ => Err(UnknownVariantError::new("script", s)),
+        }
+    }
+}
+
+/// A title in another script that was cut from the primary title, e.g. the Russian name before a `/`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlternativeTitle {
+    pub title: String,
+    pub script: Script,
+}
+
+impl AlternativeTitle {
+    /// `None` if the text has no non-Latin characters
+    pub fn new(title: impl Into<String>) -> Option<Self> {
+        let title = title.into();
+        let script = Script::detect(&title)?;
+        Some(AlternativeTitle { title, script })
+    }
+}
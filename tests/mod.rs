@@ -1,33 +1,50 @@
 mod test_adult;
+mod test_alternative_titles;
+mod test_anime;
 mod test_audio;
+mod test_cli;
 mod test_codec;
 mod test_collection;
+mod test_confidence;
 mod test_container;
 mod test_convert;
+mod test_country;
+mod test_crc;
+mod test_credits;
+mod test_custom_handler;
 mod test_date;
 mod test_dubbed;
 mod test_edition;
 mod test_episode_code;
 mod test_episodes;
+mod test_errors;
 mod test_extras;
 mod test_group;
+mod test_handler_info;
 mod test_hardcoded;
 mod test_hdr;
 mod test_language;
 mod test_main;
 mod test_network;
+mod test_no_panic;
+mod test_parse_options;
 mod test_parser;
+mod test_prefilter;
 mod test_proper;
 mod test_quality;
 mod test_region;
 mod test_repack;
 mod test_resolution;
 mod test_retail;
+mod test_rules;
 mod test_season;
+mod test_serde;
 mod test_site;
 mod test_size;
+mod test_spans;
 mod test_sports;
 mod test_title;
+mod test_trace;
 mod test_trash;
 mod test_unrated;
 mod test_volume;
@@ -0,0 +1,141 @@
+use torrent_title_parser::{parse_title, AlternativeTitle, ParseOptions, Parser, Script};
+
+fn alternative(title: &str, script: Script) -> AlternativeTitle {
+    AlternativeTitle {
+        title: title.to_string(),
+        script,
+    }
+}
+
+#[test]
+fn test_alternative_titles() {
+    let test_cases = vec![
+        (
+            "Голубая волна / Blue Crush (2002) DVDRip",
+            "Blue Crush",
+            vec![alternative("Голубая волна", Script::Cyrillic)],
+        ),
+        (
+            "Трон: Наследие / TRON: Legacy (2010) WEB-DL 1080p | D | Open Matte",
+            "TRON: Legacy",
+            vec![alternative("Трон: Наследие", Script::Cyrillic)],
+        ),
+        (
+            "[NC-Raws] 间谍过家家 / SPY×FAMILY - 04 (B-Global 1920x1080 HEVC AAC MKV)",
+            "SPY×FAMILY",
+            vec![alternative("间谍过家家", Script::Chinese)],
+        ),
+        (
+            "超能警探.Memorist.S01E01.2160p.WEB-DL.H265.AAC-FLTTH.mkv",
+            "Memorist",
+            vec![alternative("超能警探", Script::Chinese)],
+        ),
+        (
+            "[Seed-Raws] 劇場版 ペンギン・ハイウェイ Penguin Highway The Movie (BD 1280x720 AVC AACx4 [5.1+2.0+2.0+2.0]).mp4",
+            "Penguin Highway The Movie",
+            vec![alternative("劇場版 ペンギン・ハイウェイ", Script::Japanese)],
+        ),
+        (
+            "【喵萌奶茶屋】★01月新番★[別對映像研出手！/Eizouken ni wa Te wo Dasu na!/映像研には手を出すな！][01][1080p][繁體]",
+            "Eizouken ni wa Te wo Dasu na!",
+            vec![
+                alternative("別對映像研出手！", Script::Chinese),
+                alternative("映像研には手を出すな！", Script::Japanese),
+            ],
+        ),
+        (
+            "[www.arabp2p.net]_-_تركي مترجم ومدبلج Last.Call.for.Istanbul.2023.1080p.NF.WEB-DL.DDP5.1.H.264.MKV.torrent",
+            "Last Call for Istanbul",
+            vec![alternative("تركي مترجم ومدبلج", Script::Arabic)],
+        ),
+        (
+            "The Simpsons S01E01 1080p BluRay x265 HEVC 10bit AAC 5.1 Tigole",
+            "The Simpsons",
+            vec![],
+        ),
+        // only one script, nothing is cut
+        ("Жихарка (2007) DVDRip", "Жихарка", vec![]),
+    ];
+
+    for (release_name, expected_title, expected_alternatives) in test_cases {
+        let result = parse_title(release_name).unwrap();
+        assert_eq!(result.title, expected_title, "Incorrect title for {}", release_name);
+        assert_eq!(
+            result.alternative_titles, expected_alternatives,
+            "Incorrect alternative titles for {}",
+            release_name
+        );
+    }
+}
+
+#[test]
+fn test_alternative_titles_only() {
+    let options = ParseOptions::new().fields(["alternative_titles"]);
+    let result = Parser::default()
+        .parse_with("Андор / Andor [01x01-03 из 12] (2022) WEB-DL-HEVC 2160p", &options)
+        .unwrap();
+    assert_eq!(result.alternative_titles, vec![alternative("Андор", Script::Cyrillic)]);
+}
+
+#[test]
+fn test_script_detection() {
+    assert_eq!(Script::detect("Blue Crush"), None);
+    assert_eq!(Script::detect("進撃の巨人"), Some(Script::Japanese));
+    assert_eq!(Script::detect("进击的巨人"), Some(Script::Chinese));
+    assert_eq!(Script::detect("ฉลาดเกมส์โกง"), Some(Script::Thai));
+    assert_eq!(Script::detect("사랑의 불시착"), Some(Script::Korean));
+    assert_eq!(Script::detect("Кнiганошы"), Some(Script::Cyrillic));
+}
+
+#[test]
+fn test_native_title() {
+    let test_cases = vec![
+        (
+            "[Group] Shingeki no Kyojin 進撃の巨人 - 01",
+            "Shingeki no Kyojin",
+            Some("進撃の巨人"),
+        ),
+        (
+            "[Group] 進撃の巨人 Shingeki no Kyojin - 01 [1080p]",
+            "Shingeki no Kyojin",
+            Some("進撃の巨人"),
+        ),
+        (
+            "[NC-Raws] 间谍过家家 / SPY×FAMILY - 04 (B-Global 1920x1080 HEVC AAC MKV)",
+            "SPY×FAMILY",
+            Some("间谍过家家"),
+        ),
+        // kana or hangul win over a Chinese translation
+        (
+            "【喵萌奶茶屋】★01月新番★[別對映像研出手！/Eizouken ni wa Te wo Dasu na!/映像研には手を出すな！][01][1080p][繁體]",
+            "Eizouken ni wa Te wo Dasu na!",
+            Some("映像研には手を出すな！"),
+        ),
+        (
+            "愛的迫降 / 사랑의 불시착 / Crash Landing on You S01E01 1080p WEB-DL",
+            "Crash Landing on You",
+            Some("사랑의 불시착"),
+        ),
+        ("ฉลาดเกมส์โกง Bad Genius 2017 1080p", "Bad Genius", Some("ฉลาดเกมส์โกง")),
+        ("Голубая волна / Blue Crush (2002) DVDRip", "Blue Crush", Some("Голубая волна")),
+        // without a Latin title the native one is the title
+        ("ฉลาดเกมส์โกง 2017 1080p", "ฉลาดเกมส์โกง", None),
+        ("Жихарка (2007) DVDRip", "Жихарка", None),
+        (
+            "The Simpsons S01E01 1080p BluRay x265 HEVC 10bit AAC 5.1 Tigole",
+            "The Simpsons",
+            None,
+        ),
+    ];
+
+    for (release_name, expected_title, expected_native_title) in test_cases {
+        let result = parse_title(release_name).unwrap();
+        assert_eq!(result.title, expected_title, "Incorrect title for {}", release_name);
+        assert_eq!(
+            result.native_title.as_deref(),
+            expected_native_title,
+            "Incorrect native title for {}",
+            release_name
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use torrent_title_parser::{parse_title, Crc32};
+
+#[test]
+fn test_checksum() {
+    let test_cases = vec![
+        ("[SubsPlease] Frieren - 12v2 (1080p) [A1B2C3D4].mkv", Some(0xA1B2C3D4)),
+        ("[Exiled-Destiny]_Tokyo_Underground_Ep02v2_(41858470).mkv", Some(0x41858470)),
+        (
+            "Gankutsuou.-.The.Count.Of.Monte.Cristo[2005].-.04.-.[720p.BD.HEVC.x265].[FLAC].[Jd].[DHD].[b6e6e648].mkv",
+            Some(0xB6E6E648),
+        ),
+        (
+            "[Golumpa] Fairy Tail - 214 [FuniDub 720p x264 AAC] [5E46AC39].mkv",
+            Some(0x5E46AC39),
+        ),
+        (
+            "[D0ugyB0y] Nanatsu no Taizai Fundo no Shinpan - 01 (1080p WEB NF x264 AAC[9CC04E06]).mkv",
+            Some(0x9CC04E06),
+        ),
+        // an episode code, but not hexadecimal
+        ("[Group] Some Show - 01 [ABCDEFGZ].mkv", None),
+        // only at the end of the title
+        ("[Group] Some Show [A1B2C3D4] - 01 [1080p]", None),
+        ("[Group] Some Show - 01 [12345678] [1080p]", None),
+        ("The Simpsons S01E01 1080p BluRay x265 HEVC 10bit AAC 5.1 Tigole", None),
+    ];
+
+    for (release_name, expected) in test_cases {
+        let result = parse_title(release_name).unwrap();
+        assert_eq!(result.checksum, expected.map(Crc32), "Incorrect checksum for {}", release_name);
+    }
+}
+
+#[test]
+fn test_dates_are_not_checksums() {
+    let test_cases = vec![
+        ("Jimmy Kimmel Live [20190815] 720p HDTV x264", "Jimmy Kimmel Live"),
+        ("The Daily Show [20231102] 1080p WEB h264-EDITH", "The Daily Show"),
+        (
+            "The Late Show with Stephen Colbert [20240131].mkv",
+            "The Late Show with Stephen Colbert",
+        ),
+        ("[Group] WWE Raw (20190815).mkv", "WWE Raw"),
+    ];
+
+    for (release_name, expected_title) in test_cases {
+        let result = parse_title(release_name).unwrap();
+        assert_eq!(result.checksum, None, "Incorrect checksum for {}", release_name);
+        assert!(
+            result.absolute_episodes.is_empty(),
+            "Incorrect absolute episodes for {}",
+            release_name
+        );
+        assert_eq!(result.title, expected_title, "Incorrect title for {}", release_name);
+    }
+}
+
+#[test]
+fn test_checksum_keeps_episode_code() {
+    let result = parse_title("[SubsPlease] Frieren - 12v2 (1080p) [a1b2c3d4].mkv").unwrap();
+    assert_eq!(result.episode_code, Some("A1B2C3D4".to_string()));
+    assert_eq!(result.checksum.unwrap().to_string(), "A1B2C3D4");
+    assert_eq!(result.title, "Frieren");
+}
+
+#[test]
+fn test_version() {
+    let test_cases = vec![
+        ("[SubsPlease] Frieren - 12v2 (1080p) [A1B2C3D4].mkv", Some(2)),
+        ("[Exiled-Destiny]_Tokyo_Underground_Ep02v2_(41858470).mkv", Some(2)),
+        ("(Hi10)_Re_Zero_Shin_Henshuu-ban_-_02v2_(720p)_(DDY)_(72006E34).mkv", Some(2)),
+        ("[Group] Some Show - 05 [v3] [1080p]", Some(3)),
+        ("The.Simpsons.S01E01v2.1080p.WEB.x264", Some(2)),
+        ("Movie.2019.1080p.BluRay.x264v2", None),
+        (
+            "Fallout.S01E03.The.Head.2160p.DV.HDR10Plus.Ai-Enhanced.H265.DDP.5.1.MULTI.RIFE.4.15v2-60fps-DirtyHippie.mkv",
+            None,
+        ),
+        ("[SubsPlease] Frieren - 12 (1080p) [A1B2C3D4].mkv", None),
+    ];
+
+    for (release_name, expected) in test_cases {
+        let result = parse_title(release_name).unwrap();
+        assert_eq!(result.version, expected, "Incorrect version for {}", release_name);
+    }
+}
+
+#[test]
+fn test_absolute_episodes() {
+    let test_cases = vec![
+        (
+            "[HR] Boku no Hero Academia 87 (S4-24) [1080p HEVC Multi-Subs] HR-GZ",
+            "Boku no Hero Academia",
+            vec![87],
+            vec![24],
+        ),
+        ("[Judas] One Piece - 1000 (S21E108) [1080p]", "One Piece", vec![1000], vec![108]),
+        ("[SubsPlease] Frieren - 12v2 (1080p) [A1B2C3D4].mkv", "Frieren", vec![12], vec![12]),
+        (
+            "[Golumpa] Fairy Tail - 214 [FuniDub 720p x264 AAC] [5E46AC39].mkv",
+            "Fairy Tail",
+            vec![214],
+            vec![214],
+        ),
+        // seasonal numbering
+        ("[Erai-raws] Kingdom 3rd Season - 02 [1080p].mkv", "Kingdom", vec![], vec![2]),
+        (
+            "The Simpsons S01E01 1080p BluRay x265 HEVC 10bit AAC 5.1 Tigole",
+            "The Simpsons",
+            vec![],
+            vec![1],
+        ),
+        // not an anime release, so nothing says how it's numbered
+        ("One Piece - 1071 [1080p]", "One Piece", vec![], vec![1071]),
+    ];
+
+    for (release_name, expected_title, expected_absolute, expected_episodes) in test_cases {
+        let result = parse_title(release_name).unwrap();
+        assert_eq!(result.title, expected_title, "Incorrect title for {}", release_name);
+        assert_eq!(
+            result.absolute_episodes, expected_absolute,
+            "Incorrect absolute episodes for {}",
+            release_name
+        );
+        assert_eq!(result.episodes, expected_episodes, "Incorrect episodes for {}", release_name);
+    }
+}
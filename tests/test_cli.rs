@@ -0,0 +1,63 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+fn ttp(args: &[&str], stdin: &str) -> (String, bool) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_ttp"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+    let output = child.wait_with_output().unwrap();
+    (String::from_utf8(output.stdout).unwrap(), output.status.success())
+}
+
+#[test]
+fn test_cli_json_from_args() {
+    let (stdout, success) = ttp(&["The.Matrix.1999.1080p.BluRay.x264"], "");
+    assert!(success);
+    assert_eq!(
+        stdout,
+        "{\"title\":\"The Matrix\",\"resolution\":\"1080p\",\"year\":1999,\"quality\":\"BluRay\",\"codec\":\"avc\"}\n"
+    );
+}
+
+#[test]
+fn test_cli_tsv_from_stdin() {
+    let (stdout, success) = ttp(
+        &["--format", "tsv", "--columns", "title,seasons,episodes", "--header"],
+        "The Simpsons S01E01 1080p BluRay x265 HEVC 10bit AAC 5.1 Tigole\n\nHouse MD All Seasons (1-8) 720p Ultra-Compressed\n",
+    );
+    assert!(success);
+    assert_eq!(
+        stdout,
+        "title\tseasons\tepisodes\nThe Simpsons\t1\t1\nHouse MD\t1,2,3,4,5,6,7,8\t\n"
+    );
+}
+
+#[test]
+fn test_cli_table() {
+    let (stdout, success) = ttp(&["--format", "table", "--columns", "title,year"], "Some.girls.1998.DVDRip\n");
+    assert!(success);
+    assert_eq!(stdout, "Some.girls.1998.DVDRip\n  title  Some girls\n  year   1998\n\n");
+}
+
+#[test]
+fn test_cli_rules() {
+    let (stdout, success) = ttp(&["rules"], "");
+    assert!(success);
+    let first: serde_json::Value = serde_json::from_str(stdout.lines().next().unwrap()).unwrap();
+    assert_eq!(first["order"], 0);
+    assert_eq!(first["name"], "adult");
+    assert_eq!(first["pattern"], r"\b(?:xxx|xx)\b");
+    assert_eq!(first["options"]["remove"], true);
+
+    let (stdout, success) = ttp(&["rules", "--format", "tsv", "--columns", "name,flags,transform", "--header"], "");
+    assert!(success);
+    let mut lines = stdout.lines();
+    assert_eq!(lines.next(), Some("name\tflags\ttransform"));
+    assert_eq!(lines.next(), Some("adult\ti\ttrue_if_found"));
+}
@@ -0,0 +1,66 @@
+use torrent_title_parser::{parse_title, parse_title_detailed, Confidence};
+
+#[test]
+fn test_field_confidence() {
+    let test_cases = vec![
+        ("The.Matrix.1999.1080p.BluRay.x264", "resolution", Some(Confidence::Exact)),
+        ("The.Matrix.1999.1080p.BluRay.x264", "languages", None),
+        ("Movie 2020 1080p WEB-DL EN x264-GRP", "languages", Some(Confidence::Heuristic)),
+        ("Movie 2020 1080p WEB-DL ENG x264-GRP", "languages", Some(Confidence::Heuristic)),
+        ("Movie 2020 1080p WEB-DL 5.1 x264-GRP", "audio", Some(Confidence::Inferred)),
+        ("Movie 2020 1080p WEB-DL DD5.1 x264-GRP", "audio", Some(Confidence::Exact)),
+        ("Movie 2020 1080p WEB-DL AAC 5.1 x264-GRP", "audio", Some(Confidence::Exact)),
+        ("Serie Completa 1080p WEB-DL x264", "languages", Some(Confidence::Inferred)),
+        ("Movie 2009 DVDRip R5 XviD-GRP", "region", Some(Confidence::Heuristic)),
+    ];
+
+    for (test_case, field, expected) in test_cases {
+        let result = parse_title_detailed(test_case).unwrap();
+        assert_eq!(
+            result.field_confidence(field),
+            expected,
+            "Incorrect confidence for {} in {}",
+            field,
+            test_case
+        );
+    }
+}
+
+#[test]
+fn test_confidence_score() {
+    let exact = parse_title_detailed("The.Matrix.1999.1080p.BluRay.x264").unwrap();
+    assert_eq!(exact.confidence_score(), 1.0);
+
+    let guessed = parse_title_detailed("The.Matrix.1999.1080p.BluRay.5.1.EN.x264").unwrap();
+    assert!(guessed.confidence_score() < 1.0);
+    assert!(guessed.confidence_score() > 0.0);
+
+    assert!(Confidence::Inferred < Confidence::Heuristic);
+    assert!(Confidence::Heuristic < Confidence::Exact);
+}
+
+#[test]
+fn test_plain_parse_confidence() {
+    let title = "Movie 2020 1080p WEB-DL 5.1 EN x264-GRP";
+    let result = parse_title(title).unwrap();
+    assert_eq!(result.field_confidence("resolution"), Some(Confidence::Exact));
+    assert_eq!(result.field_confidence("audio"), Some(Confidence::Inferred));
+    assert_eq!(result.field_confidence("languages"), Some(Confidence::Heuristic));
+    assert_eq!(result.field_confidence("episodes"), None);
+    assert!(result.confidence_score() < 1.0);
+    assert_eq!(result.confidence_score(), parse_title_detailed(title).unwrap().confidence_score());
+
+    assert!(parse_title("The.Matrix.1999.1080p.BluRay.x264").unwrap().low_confidence.is_empty());
+}
+
+#[test]
+fn test_custom_handler_confidence() {
+    // Portuguese is only inferred from "dublado"
+    let result = parse_title("Velozes e Furiosos 2001 1080p Dublado").unwrap();
+    assert_eq!(result.field_confidence("languages"), Some(Confidence::Inferred));
+
+    // absolute numbering is guessed from the fansub naming
+    let result = parse_title("[SubsPlease] Frieren - 12 (1080p) [A1B2C3D4].mkv").unwrap();
+    assert_eq!(result.absolute_episodes, vec![12]);
+    assert_eq!(result.field_confidence("absolute_episodes"), Some(Confidence::Heuristic));
+}
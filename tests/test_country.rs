@@ -0,0 +1,59 @@
+use torrent_title_parser::{parse_title, parse_title_detailed, RemakeCountry};
+
+#[test]
+fn test_country_detection() {
+    let test_cases = vec![
+        (
+            "The.Office.US.S01E01.720p.HDTV.x264",
+            Some(RemakeCountry::UnitedStates),
+            "The Office US",
+        ),
+        ("Shameless UK S01E01 720p", Some(RemakeCountry::UnitedKingdom), "Shameless UK"),
+        (
+            "Shameless.US.S11E01.1080p.WEB.H264-GGEZ",
+            Some(RemakeCountry::UnitedStates),
+            "Shameless US",
+        ),
+        ("Utopia AU S01 1080p", Some(RemakeCountry::Australia), "Utopia AU"),
+        (
+            "The Office (US) - Complete Series 720p",
+            Some(RemakeCountry::UnitedStates),
+            "The Office (US)",
+        ),
+        (
+            "Queer as Folk UK 1999 DVDRip",
+            Some(RemakeCountry::UnitedKingdom),
+            "Queer as Folk UK",
+        ),
+        // only a suffix of the title counts
+        ("Skins Season S01-S07 COMPLETE UK Soundtrack 720p WEB-DL", None, "Skins"),
+        ("US Marshals 1998 1080p BluRay", None, "US Marshals"),
+        ("Us.2019.1080p.BluRay.x264", None, "Us"),
+        ("Doctor Who (2005) S01E01 720p", None, "Doctor Who"),
+        // without release metadata after it the code may be part of the title
+        ("Sherlock Holmes CA", None, "Sherlock Holmes CA"),
+        ("Sherlock.Holmes.CA.mkv", None, "Sherlock Holmes CA"),
+    ];
+
+    for (release_name, expected_country, expected_title) in test_cases {
+        let result = parse_title(release_name).unwrap();
+        assert_eq!(result.country, expected_country, "Incorrect country for {}", release_name);
+        assert_eq!(result.title, expected_title, "Incorrect title for {}", release_name);
+    }
+}
+
+#[test]
+fn test_country_codes() {
+    assert_eq!(RemakeCountry::UnitedKingdom.as_str(), "GB");
+    assert_eq!("UK".parse::<RemakeCountry>(), Ok(RemakeCountry::UnitedKingdom));
+    assert_eq!("GB".parse::<RemakeCountry>(), Ok(RemakeCountry::UnitedKingdom));
+    assert!("XX".parse::<RemakeCountry>().is_err());
+}
+
+#[test]
+fn test_country_span() {
+    let title = "The.Office.US.S01E01.720p.HDTV.x264";
+    let result = parse_title_detailed(title).unwrap();
+    let span = result.spans_for("country").next().unwrap();
+    assert_eq!(&title[span.span.clone()], "US");
+}
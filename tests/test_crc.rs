@@ -0,0 +1,28 @@
+#![cfg(feature = "crc")]
+
+use std::fs;
+
+use torrent_title_parser::{parse_title, Crc32};
+
+#[test]
+fn test_crc32() {
+    assert_eq!(Crc32::of_bytes(b""), Crc32(0));
+    assert_eq!(Crc32::of_bytes(b"123456789"), Crc32(0xCBF43926));
+    assert_eq!(Crc32::of_reader(&b"123456789"[..]).unwrap(), Crc32(0xCBF43926));
+}
+
+#[test]
+fn test_verify_checksum() {
+    let path = std::env::temp_dir().join(format!("ttp-crc-{}.mkv", std::process::id()));
+    fs::write(&path, b"123456789").unwrap();
+
+    let matching = parse_title("[Group] Some Show - 01 (1080p) [CBF43926].mkv").unwrap();
+    let other = parse_title("[Group] Some Show - 01 (1080p) [A1B2C3D4].mkv").unwrap();
+    let without = parse_title("[Group] Some Show - 01 (1080p).mkv").unwrap();
+    assert_eq!(matching.verify_checksum(&path).unwrap(), Some(true));
+    assert_eq!(other.verify_checksum(&path).unwrap(), Some(false));
+    assert_eq!(without.verify_checksum(&path).unwrap(), None);
+
+    fs::remove_file(&path).unwrap();
+    assert!(matching.verify_checksum(&path).is_err());
+}
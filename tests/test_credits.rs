@@ -0,0 +1,102 @@
+use torrent_title_parser::{parse_title, Credit, CreditRole};
+
+fn names(credits: &[Credit]) -> Vec<&str> {
+    credits.iter().map(|credit| credit.name.as_str()).collect()
+}
+
+#[test]
+fn test_credits_in_parentheses() {
+    let test_cases = vec![
+        (
+            "Американские животные / American Animals (Барт Лэйтон / Bart Layton) [2018, Великобритания, США, драма, криминал, BDRip] MVO (СВ Студия)",
+            "American Animals",
+            vec!["Барт Лэйтон"],
+        ),
+        (
+            "Греческая смоковница / Griechische Feigen / The Fruit Is Ripe (Зиги Ротемунд / Sigi Rothemund (as Siggi Götz)) [1976, Германия (ФРГ), эротика, комедия, приключения, DVDRip] 2 VO",
+            "Griechische Feigen / The Fruit Is Ripe",
+            vec!["Зиги Ротемунд"],
+        ),
+        ("Соперницы (Алексей Дмитриев) [1929, драма, WEB-DLRip]", "Соперницы", vec!["Алексей Дмитриев"]),
+        ("Пираты Карибского моря (Джонни Депп, Хелена Бонэм Картер) 2003 BDRip", "Пираты Карибского моря", vec!["Джонни Депп", "Хелена Бонэм Картер"]),
+        ("The Simpsons S01E01 1080p BluRay x265 HEVC 10bit AAC 5.1 Tigole", "The Simpsons", vec![]),
+    ];
+
+    for (release_name, expected_title, expected_names) in test_cases {
+        let result = parse_title(release_name).unwrap();
+        assert_eq!(result.title, expected_title, "Incorrect title for {}", release_name);
+        assert_eq!(names(&result.credits), expected_names, "Incorrect credits for {}", release_name);
+        assert!(result.credits.iter().all(|credit| credit.role.is_none()));
+    }
+}
+
+#[test]
+fn test_credit_transliterations() {
+    let result = parse_title("Американские животные / American Animals (Барт Лэйтон / Bart Layton) [2018, BDRip]").unwrap();
+    assert_eq!(
+        result.credits,
+        vec![Credit::new("Барт Лэйтон", None).with_transliteration("Bart Layton")]
+    );
+
+    let result =
+        parse_title("Греческая смоковница / Griechische Feigen (Зиги Ротемунд / Sigi Rothemund (as Siggi Götz)) [1976, DVDRip]").unwrap();
+    assert_eq!(
+        result.credits,
+        vec![Credit::new("Зиги Ротемунд", None).with_transliteration("Sigi Rothemund (as Siggi Götz)")]
+    );
+
+    // names in the same script are different people
+    let result = parse_title("Пираты Карибского моря (Джонни Депп / Орландо Блум) 2003 BDRip").unwrap();
+    assert_eq!(names(&result.credits), vec!["Джонни Депп", "Орландо Блум"]);
+    assert!(result.credits.iter().all(|credit| credit.transliteration.is_none()));
+}
+
+#[test]
+fn test_audiobook_credits() {
+    let result = parse_title("The Hobbit by J.R.R. Tolkien read by Andy Serkis (2020) [M4B]").unwrap();
+    assert_eq!(result.title, "The Hobbit");
+    assert_eq!(
+        result.credits,
+        vec![
+            Credit::new("Andy Serkis", Some(CreditRole::Narrator)),
+            Credit::new("J.R.R. Tolkien", Some(CreditRole::Author)),
+        ]
+    );
+
+    let result = parse_title("Stephen King - It (Read by Steven Weber) [Unabridged] mp3").unwrap();
+    assert_eq!(result.title, "Stephen King - It");
+    assert_eq!(result.credits, vec![Credit::new("Steven Weber", Some(CreditRole::Narrator))]);
+
+    let result = parse_title("Dune - Frank Herbert - Narrated by Scott Brick, Euan Morton and Simon Vance - Audiobook 64kbps").unwrap();
+    assert_eq!(result.title, "Dune - Frank Herbert - Audiobook");
+    assert_eq!(names(&result.credits), vec!["Scott Brick", "Euan Morton", "Simon Vance"]);
+}
+
+#[test]
+fn test_only_names_are_credits() {
+    let result = parse_title("Бегущий по лезвию / Blade Runner (Final Cut) 1982 BDRip").unwrap();
+    assert_eq!(result.title, "Blade Runner");
+    assert!(result.credits.is_empty());
+
+    let result = parse_title("Грань будущего / Edge of Tomorrow (Tom Cruise, Emily Blunt) 2014 BDRip").unwrap();
+    assert_eq!(names(&result.credits), vec!["Tom Cruise", "Emily Blunt"]);
+
+    let result = parse_title("В синей бездне / Into the Blue (Director: John Stockwell) 2005 DVDRip").unwrap();
+    assert_eq!(result.title, "Into the Blue");
+    assert_eq!(result.credits, vec![Credit::new("John Stockwell", Some(CreditRole::Director))]);
+}
+
+#[test]
+fn test_by_is_only_an_author_in_audiobooks() {
+    let result = parse_title("Stand by Me 1986 1080p BluRay").unwrap();
+    assert_eq!(result.title, "Stand by Me");
+    assert!(result.credits.is_empty());
+
+    // release groups after the title aren't credits either
+    let result = parse_title("Once Upon a Time [S01-07] (2011-2017) WEB-DLRip by Generalfilm").unwrap();
+    assert!(result.credits.is_empty());
+
+    // neither are narrators outside of audiobooks
+    let result = parse_title("Stand by Me (Read by the Fire) 1986 1080p BluRay").unwrap();
+    assert!(result.credits.is_empty());
+}
@@ -0,0 +1,152 @@
+use torrent_title_parser::regress::Regex;
+use torrent_title_parser::{transforms, Codec, FieldValue, Handler, HandlerResult, Parser, RegexHandlerOptions, RegexStringExt};
+
+#[test]
+fn test_custom_regex_handler() {
+    let mut parser = Parser::new();
+    parser.add_handler(Handler::from_regex(
+        "site",
+        |t| &mut t.site,
+        Regex::case_insensitive(r"\bTrackerX\b").unwrap(),
+        transforms::value("trackerx"),
+        RegexHandlerOptions {
+            remove: true,
+            ..Default::default()
+        },
+    ));
+    parser.add_handler(Handler::from_regex(
+        "episodes",
+        |t| &mut t.episodes,
+        Regex::case_insensitive(r"\bEp\.?[ ]?(\d{1,3}-\d{1,3})\b").unwrap(),
+        transforms::range_func(),
+        RegexHandlerOptions::default(),
+    ));
+
+    let result = parser.parse("Some Show Ep 01-03 TrackerX").unwrap();
+    assert_eq!(result.title, "Some Show");
+    assert_eq!(result.site, Some("trackerx".to_string()));
+    assert_eq!(result.episodes, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_custom_closure_handler() {
+    let mut parser = Parser::new();
+    parser.add_handler(Handler::new("group", |context| {
+        let index = context.title.find("-INTERNAL")?;
+        context.result.group = Some("INTERNAL".to_string());
+        Some(HandlerResult {
+            raw_match: "-INTERNAL".to_string(),
+            match_index: index,
+            remove: true,
+            skip_from_title: false,
+        })
+    }));
+
+    let result = parser.parse("Movie Name-INTERNAL").unwrap();
+    assert_eq!(result.title, "Movie Name");
+    assert_eq!(result.group, Some("INTERNAL".to_string()));
+}
+
+#[test]
+fn test_extend_default_parser() {
+    let title = "[www.Torrenting.com] - The.Matrix.1999.1080p.BluRay.x264-[TrkX]";
+    let mut parser = Parser::with_default_handlers();
+    assert_eq!(parser.parse(title).unwrap(), Parser::default().parse(title).unwrap());
+
+    assert!(parser.remove_handlers("site") > 0);
+    assert_eq!(parser.handler_names().filter(|name| *name == "site").count(), 0);
+    assert_eq!(parser.parse(title).unwrap().site, None);
+
+    let title = "The.Matrix.1999.1080p.BluRay.x264-[TrkX]";
+
+    let inserted = parser.insert_handler_before(
+        "group",
+        Handler::from_regex(
+            "group",
+            |t| &mut t.group,
+            Regex::new(r"-\[(TrkX)\]$").unwrap(),
+            transforms::value("$1"),
+            RegexHandlerOptions {
+                remove: true,
+                ..Default::default()
+            },
+        ),
+    );
+    assert!(inserted);
+    assert_eq!(parser.parse(title).unwrap().group, Some("TrkX".to_string()));
+}
+
+#[test]
+fn test_replace_and_insert_after() {
+    let mut parser = Parser::with_default_handlers();
+    let handler_count = parser.handler_names().count();
+    let replaced = parser.replace_handlers(
+        "codec",
+        Handler::from_regex(
+            "codec",
+            |t| &mut t.codec,
+            Regex::case_insensitive(r"\bx264\b").unwrap(),
+            |_: &str, _: &Option<Codec>| Some(Some(Codec::Hevc)),
+            RegexHandlerOptions {
+                remove: true,
+                ..Default::default()
+            },
+        ),
+    );
+    assert!(replaced);
+    assert_eq!(parser.handler_names().filter(|name| *name == "codec").count(), 1);
+    assert!(parser.handler_names().count() < handler_count);
+    assert_eq!(parser.parse("The.Matrix.1999.1080p.BluRay.x264").unwrap().codec, Some(Codec::Hevc));
+
+    assert!(parser.insert_handler_after("codec", Handler::new("custom", |_| None)));
+    let names: Vec<&str> = parser.handler_names().collect();
+    let codec_index = names.iter().position(|name| *name == "codec").unwrap();
+    assert_eq!(names[codec_index + 1], "custom");
+
+    assert!(!parser.insert_handler_before("unknown", Handler::new("custom", |_| None)));
+}
+
+#[test]
+fn test_extra_fields() {
+    let mut parser = Parser::with_default_handlers();
+    let inserted = parser.insert_handler_before(
+        "group",
+        Handler::new("freeleech", |mut context| {
+            let index = context.title.find("[FL]")?;
+            context.set_extra("freeleech", true, index..index + 4);
+            Some(HandlerResult {
+                raw_match: "[FL]".to_string(),
+                match_index: index,
+                remove: true,
+                skip_from_title: true,
+            })
+        }),
+    );
+    assert!(inserted);
+
+    let title = "The.Matrix.1999.1080p.BluRay.x264 [FL]";
+    let result = parser.parse_detailed(title).unwrap();
+    assert_eq!(result.parsed.extra.get("freeleech"), Some(&FieldValue::Bool(true)));
+    let span = result.spans_for("freeleech").next().unwrap();
+    assert_eq!(&title[span.span.clone()], "[FL]");
+
+    assert!(Parser::default().parse(title).unwrap().extra.is_empty());
+}
+
+#[test]
+fn test_extra_fields_without_match() {
+    let mut parser = Parser::with_default_handlers();
+    parser.add_handler(Handler::new("tracker", |mut context| {
+        let index = context.title.find("TrkX")?;
+        context.set_extra("tracker", "trkx", index..index + 4);
+        context.set_extra("internal", true, index..index + 4);
+        None
+    }));
+
+    let title = "The.Matrix.1999.1080p.BluRay.x264-TrkX";
+    let result = parser.parse_detailed(title).unwrap();
+    assert_eq!(result.parsed.extra.get("internal"), Some(&FieldValue::Bool(true)));
+    let span = result.spans_for("internal").next().unwrap();
+    assert_eq!(&title[span.span.clone()], "TrkX");
+    assert_eq!(result.spans_for("tracker").count(), 1);
+}
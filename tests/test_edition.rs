@@ -1,55 +1,70 @@
-use torrent_title_parser::parse_title;
+use torrent_title_parser::{parse_title, Edition};
 
 #[test]
 fn test_edition_detection() {
     let test_cases = vec![
         (
             "Have I Got News For You S53E02 EXTENDED 720p HDTV x264-QPEL",
-            Some("Extended Edition"),
+            vec![Edition::ExtendedEdition],
         ),
         (
             "Mary.Poppins.1964.50th.ANNIVERSARY.EDITION.REMUX.1080p.Bluray.AVC.DTS-HD.MA.5.1-LEGi0N",
-            Some("Anniversary Edition"),
+            vec![Edition::AnniversaryEdition],
         ),
         (
             "The.Lord.of.the.Rings.The.Fellowship.of.the.Ring.2001.EXTENDED.2160p.UHD.BluRay.x265.10bit.HDR.TrueHD.7.1.Atmos-BOREDOR",
-            Some("Extended Edition"),
+            vec![Edition::ExtendedEdition],
         ),
         (
             "The.Lord.of.the.Rings.The.Motion.Picture.Trilogy.Extended.Editions.2001-2003.1080p.BluRay.x264.DTS-WiKi",
-            Some("Extended Edition"),
+            vec![Edition::ExtendedEdition],
         ),
-        ("Better.Call.Saul.S03E04.CONVERT.720p.WEB.h264-TBS", None),
+        ("Better.Call.Saul.S03E04.CONVERT.720p.WEB.h264-TBS", vec![]),
         (
             "The Fifth Element 1997 REMASTERED MULTi 1080p BluRay HDLight AC3 x264 Zone80",
-            Some("Remastered"),
+            vec![Edition::Remastered],
         ),
-        ("Predator 1987 REMASTER MULTi 1080p BluRay x264 FiDELiO", Some("Remastered")),
+        ("Predator 1987 REMASTER MULTi 1080p BluRay x264 FiDELiO", vec![Edition::Remastered]),
         (
             "Have I Got News For You S53E02 EXTENDED 720p HDTV x264-QPEL",
-            Some("Extended Edition"),
+            vec![Edition::ExtendedEdition],
+        ),
+        (
+            "The.Lord.of.the.Rings.The.Return.of.the.King.2003.IMAX.Extended.Director's.Cut.2160p.UHD.BluRay.x265-GRP",
+            vec![Edition::ExtendedEdition, Edition::DirectorsCut, Edition::Imax],
+        ),
+        (
+            "Seven.Samurai.1954.Criterion.Collection.Theatrical.1080p.BluRay.x264-GRP",
+            vec![Edition::Theatrical, Edition::Criterion],
+        ),
+        (
+            "Blade.Runner.1982.Directors.Cut.Remastered.1080p.BluRay.x264-GRP",
+            vec![Edition::DirectorsCut, Edition::Remastered],
         ),
     ];
 
-    for (input, expected_edition) in test_cases {
+    for (input, expected_editions) in test_cases {
         let result = parse_title(input).unwrap();
-        match expected_edition {
-            Some(edition) => {
-                assert_eq!(
-                    result.edition.as_deref(),
-                    Some(edition),
-                    "Incorrect edition detection for {}: Got {:?}, expected {:?}",
-                    input,
-                    result.edition,
-                    expected_edition
-                );
-            }
-            None => assert!(
-                result.edition.is_none(),
-                "Incorrectly detected edition for {}: Got {:?}",
-                input,
-                result.edition
-            ),
-        }
+        assert_eq!(
+            result.editions, expected_editions,
+            "Incorrect edition detection for {}: Got {:?}, expected {:?}",
+            input, result.editions, expected_editions
+        );
     }
 }
+
+#[test]
+fn test_criterion_only_sets_edition() {
+    let result = parse_title("Fifty-Fifty.1982.Criterion.Collection.1080p.BluRay.x264").unwrap();
+    assert_eq!(result.title, "Fifty-Fifty");
+    assert_eq!(result.editions, vec![Edition::Criterion]);
+    assert_eq!(
+        result.complete,
+        parse_title("Fifty-Fifty.1982.Collection.1080p.BluRay.x264").unwrap().complete
+    );
+
+    // a hyphenated name isn't an edition
+    let result = parse_title("Fifty-Criterion-Fifty 1992 1080p BluRay").unwrap();
+    assert_eq!(result.title, "Fifty-Criterion-Fifty");
+    assert!(result.editions.is_empty());
+}
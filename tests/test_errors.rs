@@ -0,0 +1,119 @@
+use torrent_title_parser::{parse_title, ParseOptions, Parser, ParserError, RejectedInput, Resolution, DEFAULT_MAX_LENGTH};
+
+#[test]
+fn test_empty_input() {
+    assert!(matches!(parse_title(""), Err(ParserError::Empty)));
+    assert!(matches!(parse_title(" \t\n "), Err(ParserError::Empty)));
+    assert!(matches!(Parser::default().parse_traced("   "), Err(ParserError::Empty)));
+}
+
+#[test]
+fn test_too_long() {
+    let title = format!("The Movie 2019 {}", "x".repeat(DEFAULT_MAX_LENGTH));
+    match parse_title(&title) {
+        Err(ParserError::TooLong { length, limit }) => {
+            assert_eq!(length, title.len());
+            assert_eq!(limit, DEFAULT_MAX_LENGTH);
+        }
+        other => panic!("expected TooLong, got {:?}", other),
+    }
+
+    let parser = Parser::default();
+    let options = ParseOptions::new().max_length(10);
+    assert!(matches!(
+        parser.parse_with("The Movie 2019", &options),
+        Err(ParserError::TooLong { .. })
+    ));
+    assert!(parser.parse_with("Movie 2019", &options).is_ok());
+}
+
+#[test]
+fn test_not_a_release_name() {
+    let cases = [
+        ("https://example.com/torrents/12345", RejectedInput::Url),
+        (
+            "  magnet:?xt=urn:btih:c12fe1c06bba254a9dc9f519b335aa7c1367a88a  ",
+            RejectedInput::Url,
+        ),
+        ("FTP://files.example.org/pub", RejectedInput::Url),
+        ("c12fe1c06bba254a9dc9f519b335aa7c1367a88a", RejectedInput::Hash),
+        ("D41D8CD98F00B204E9800998ECF8427E", RejectedInput::Hash),
+    ];
+    for (title, kind) in cases {
+        match parse_title(title) {
+            Err(ParserError::NotAReleaseName(rejected)) => assert_eq!(rejected, kind, "{}", title),
+            other => panic!("expected {:?} for {}, got {:?}", kind, title, other),
+        }
+    }
+}
+
+#[test]
+fn test_release_names_with_links_or_hashes_are_parsed() {
+    assert_eq!(
+        parse_title("www.Torrenting.com - Anatomy Of A Fall (2023)").unwrap().title,
+        "Anatomy Of A Fall"
+    );
+    assert_eq!(parse_title("The Movie 2019 https://example.com").unwrap().year, Some(2019));
+    // too short for a hash
+    assert_eq!(
+        parse_title("[SubsPlease] Frieren - 12 (1080p) [A1B2C3D4].mkv").unwrap().title,
+        "Frieren"
+    );
+}
+
+#[test]
+fn test_no_title() {
+    let parser = Parser::default();
+    let title = "Subs/Brazilian.por.srt";
+    assert_eq!(parser.parse(title).unwrap().title, "");
+
+    let options = ParseOptions::new().require_title(true);
+    assert!(matches!(parser.parse_with(title, &options), Err(ParserError::NoTitle)));
+    assert!(parser.parse_with("The Movie 2019", &options).is_ok());
+    // the title isn't computed, so there's nothing to check
+    assert!(parser.parse_with(title, &options.fields(["languages"])).is_ok());
+}
+
+#[test]
+fn test_lenient() {
+    let parser = Parser::default();
+    let options = ParseOptions::new().lenient(true).max_length(4).require_title(true);
+    assert_eq!(parser.parse_with("", &options).unwrap().title, "");
+    assert_eq!(parser.parse_with("The Movie 2019", &options).unwrap().year, Some(2019));
+    assert!(parser.parse_with("c12fe1c06bba254a9dc9f519b335aa7c1367a88a", &options).is_ok());
+    assert!(parser.parse_with("Subs/Brazilian.por.srt", &options).is_ok());
+}
+
+#[test]
+fn test_magnet_display_name() {
+    let link = "magnet:?xt=urn:btih:c12fe1c06bba254a9dc9f519b335aa7c1367a88a&dn=The.Matrix.1999.1080p.BluRay.x264%5BGRP%5D&tr=udp%3A%2F%2Ftracker.example.org%3A1337";
+    let parsed = parse_title(link).unwrap();
+    assert_eq!(parsed.title, "The Matrix");
+    assert_eq!(parsed.year, Some(1999));
+    assert_eq!(parsed.resolution, Some(Resolution::P1080));
+
+    let parsed = parse_title("magnet:?dn=Some+Show+S01E02+720p&xt=urn:btih:c12fe1c06bba254a9dc9f519b335aa7c1367a88a").unwrap();
+    assert_eq!(parsed.title, "Some Show");
+    assert_eq!(parsed.episodes, vec![2]);
+
+    let parsed = parse_title("magnet:?xt=urn:btih:c12fe1c06bba254a9dc9f519b335aa7c1367a88a&dn=100%G1+Beef%20Show+2019").unwrap();
+    assert_eq!(parsed.title, "100%G1 Beef Show");
+
+    assert!(matches!(parse_title("magnet:?xt=urn:btih:c12f&dn="), Err(ParserError::Empty)));
+}
+
+#[test]
+fn test_lenient_detailed_and_traced() {
+    let parser = Parser::default();
+    let lenient = ParseOptions::new().lenient(true);
+    let title = "https://example.com/The.Matrix.1999.1080p";
+    assert!(parser.parse_detailed(title).is_err());
+
+    let detailed = parser.parse_detailed_with(title, &lenient).unwrap();
+    let span = detailed.spans_for("year").next().unwrap();
+    assert_eq!(&title[span.span.clone()], "1999");
+
+    let (parsed, trace) = parser.parse_traced_with(title, &lenient).unwrap();
+    assert_eq!(parsed, detailed.parsed);
+    assert!(trace.iter().any(|handler| handler.name == "resolution" && handler.matched));
+}
@@ -0,0 +1,87 @@
+use torrent_title_parser::regress::Regex;
+use torrent_title_parser::{transforms, Handler, Parser, Pattern, RegexHandlerOptions, Transform, TransformInfo};
+
+#[test]
+fn test_default_handler_infos() {
+    let infos = Parser::default().handler_infos();
+    assert_eq!(infos.len(), Parser::default().handler_names().count());
+    assert!(infos.iter().enumerate().all(|(order, info)| info.order == order));
+
+    let adult = &infos[0];
+    assert_eq!(adult.name, "adult");
+    assert_eq!(adult.kind, "regex");
+    assert_eq!(adult.pattern.as_deref(), Some(r"\b(?:xxx|xx)\b"));
+    assert_eq!(adult.flags.as_deref(), Some("i"));
+    let transform = adult.transform.as_ref().unwrap();
+    assert_eq!(transform.kind, "true_if_found");
+    assert!(transform.params.is_empty());
+    assert!(adult.options.unwrap().remove);
+
+    // every built-in regex handler knows its pattern
+    assert!(infos.iter().filter(|info| info.kind == "regex").all(|info| info.pattern.is_some()));
+    let french = TransformInfo {
+        kind: "uniq_concat",
+        params: vec!["fr".to_string()],
+    };
+    assert!(infos
+        .iter()
+        .any(|info| info.name == "languages" && info.transform.as_ref() == Some(&french)));
+    assert_eq!(french.to_string(), "uniq_concat(fr)");
+    assert!(infos.iter().any(|info| info.kind == "custom" && info.pattern.is_none()));
+
+    // only handlers written as plain functions are custom, every regex handler names its transform
+    let custom_transforms: Vec<(usize, &str)> = infos
+        .iter()
+        .filter(|info| info.transform.as_ref().is_some_and(|transform| transform.kind == "custom"))
+        .map(|info| (info.order, info.name.as_str()))
+        .collect();
+    assert_eq!(custom_transforms, vec![]);
+    assert!(infos
+        .iter()
+        .any(|info| info.name == "seasons" && info.transform.as_ref().is_some_and(|transform| transform.kind == "array_integer")));
+}
+
+#[test]
+fn test_custom_handler_info() {
+    let mut parser = Parser::new();
+    parser.add_handler(Handler::from_regex(
+        "site",
+        |t| &mut t.site,
+        Pattern::case_insensitive(r"\bTrkX\b").unwrap(),
+        transforms::value("trkx"),
+        RegexHandlerOptions::default(),
+    ));
+    parser.add_handler(Handler::from_regex(
+        "group",
+        |t| &mut t.group,
+        Regex::new(r"-(\w+)$").unwrap(),
+        |value: &str, _: &Option<String>| Some(Some(value.to_lowercase())),
+        RegexHandlerOptions::default(),
+    ));
+    parser.add_handler(Handler::new("group", |_| None));
+    parser.add_handler(Handler::from_regex(
+        "date",
+        |t| &mut t.date,
+        Regex::new(r"\d{8}").unwrap(),
+        transforms::date_from_formats(&["%Y%m%d", "%d%m%Y"]),
+        RegexHandlerOptions::default(),
+    ));
+    parser.add_handler(Handler::from_regex(
+        "group",
+        |t| &mut t.group,
+        Regex::new(r"-(\w+)$").unwrap(),
+        Transform::new("lowercase", Vec::new(), |value, _| Some(Some(value.to_lowercase()))),
+        RegexHandlerOptions::default(),
+    ));
+
+    let infos = parser.handler_infos();
+    assert_eq!(infos[0].pattern.as_deref(), Some(r"\bTrkX\b"));
+    assert_eq!(infos[0].transform.as_ref().unwrap().to_string(), "value(trkx)");
+    // compiled regexes don't keep their source
+    assert_eq!(infos[1].pattern, None);
+    assert_eq!(infos[1].transform.as_ref().unwrap().kind, "custom");
+    assert_eq!(infos[2].kind, "custom");
+    assert_eq!(infos[2].options, None);
+    assert_eq!(infos[3].transform.as_ref().unwrap().params, vec!["%Y%m%d", "%d%m%Y"]);
+    assert_eq!(infos[4].transform.as_ref().unwrap().to_string(), "lowercase");
+}
@@ -1,36 +1,59 @@
-use torrent_title_parser::parse_title;
+use torrent_title_parser::{parse_title, HdrFormat};
 
 #[test]
 fn test_hdr_detection() {
     let test_cases = vec![
-        ("The.Mandalorian.S01E06.4K.HDR.2160p 4.42GB", vec!["HDR"]),
+        ("The.Mandalorian.S01E06.4K.HDR.2160p 4.42GB", vec![HdrFormat::Hdr]),
         (
             "Spider-Man - Complete Movie Collection (2002-2022) 1080p.HEVC.HDR10.1920x800.x265. DTS-HD",
-            vec!["HDR"],
+            vec![HdrFormat::Hdr],
         ),
         (
             "Bullet.Train.2022.2160p.AMZN.WEB-DL.x265.10bit.HDR10Plus.DDP5.1-SMURF",
-            vec!["HDR10+"],
+            vec![HdrFormat::Hdr10Plus],
+        ),
+        (
+            "Belle (2021) 2160p 10bit 4KLight DOLBY VISION BluRay DDP 7.1 x265-QTZ",
+            vec![HdrFormat::DolbyVision(None)],
         ),
-        ("Belle (2021) 2160p 10bit 4KLight DOLBY VISION BluRay DDP 7.1 x265-QTZ", vec!["DV"]),
         (
             "Андор / Andor [01x01-03 из 12] (2022) WEB-DL-HEVC 2160p | 4K | Dolby Vision TV | NewComers, HDRezka Studio",
-            vec!["DV"],
+            vec![HdrFormat::DolbyVision(None)],
+        ),
+        (
+            "АBullet.Train.2022.2160p.WEB-DL.DDP5.1.DV.MKV.x265-NOGRP",
+            vec![HdrFormat::DolbyVision(None)],
+        ),
+        (
+            "Bullet.Train.2022.2160p.WEB-DL.DoVi.DD5.1.HEVC-EVO[TGx]",
+            vec![HdrFormat::DolbyVision(None)],
         ),
-        ("АBullet.Train.2022.2160p.WEB-DL.DDP5.1.DV.MKV.x265-NOGRP", vec!["DV"]),
-        ("Bullet.Train.2022.2160p.WEB-DL.DoVi.DD5.1.HEVC-EVO[TGx]", vec!["DV"]),
         (
             "Спайдерхед / Spiderhead (2022) WEB-DL-HEVC 2160p | 4K | HDR | Dolby Vision Profile 8 | P | NewComers, Jaskier",
-            vec!["DV", "HDR"],
+            vec![HdrFormat::DolbyVision(Some(8)), HdrFormat::Hdr],
         ),
         (
             "House.of.the.Dragon.S01E07.2160p.10bit.HDR.DV.WEBRip.6CH.x265.HEVC-PSA",
-            vec!["DV", "HDR"],
+            vec![HdrFormat::DolbyVision(None), HdrFormat::Hdr],
         ),
         (
             "Флешбэк / Memory (2022) WEB-DL-HEVC 2160p | 4K | HDR | HDR10+ | Dolby Vision Profile 8 | Pazl Voice",
-            vec!["DV", "HDR10+", "HDR"],
+            vec![HdrFormat::DolbyVision(Some(8)), HdrFormat::Hdr10Plus, HdrFormat::Hdr],
+        ),
+        (
+            "The.Batman.2022.2160p.WEB-DL.DV.P5.DDP5.1.Atmos.H.265-GRP",
+            vec![HdrFormat::DolbyVision(Some(5))],
+        ),
+        (
+            "Dune.2021.2160p.BluRay.REMUX.DoVi.P8.HEVC-GRP",
+            vec![HdrFormat::DolbyVision(Some(8))],
+        ),
+        (
+            "Oppenheimer.2023.2160p.WEB-DL.DV.HDR10.DDP5.1.H.265-GRP",
+            vec![HdrFormat::DolbyVision(None), HdrFormat::Hdr],
         ),
+        ("Planet.Earth.III.S01E01.2160p.iP.WEB-DL.HLG.H.265-GRP", vec![HdrFormat::Hlg]),
+        ("Some.Movie.2023.2160p.UHD.BluRay.PQ10.HEVC-GRP", vec![HdrFormat::Pq10]),
     ];
 
     for (input, expected_hdr) in test_cases {
@@ -42,3 +65,23 @@ fn test_hdr_detection() {
         );
     }
 }
+
+#[test]
+fn test_hdr10_fallback() {
+    let test_cases = vec![
+        ("The.Batman.2022.2160p.WEB-DL.DV.P5.DDP5.1.Atmos.H.265-GRP", false),
+        ("Dune.2021.2160p.BluRay.REMUX.DoVi.P8.HEVC-GRP", true),
+        ("Oppenheimer.2023.2160p.WEB-DL.DV.HDR10.DDP5.1.H.265-GRP", true),
+        ("Bullet.Train.2022.2160p.WEB-DL.DoVi.DD5.1.HEVC-EVO[TGx]", false),
+    ];
+
+    for (input, expected) in test_cases {
+        let result = parse_title(input).unwrap();
+        assert_eq!(
+            HdrFormat::has_hdr10_fallback(&result.hdr),
+            expected,
+            "Incorrect HDR10 fallback for {}",
+            input
+        );
+    }
+}
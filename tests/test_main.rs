@@ -1,4 +1,9 @@
-use torrent_title_parser::{parse_title, Codec, Language, Network, ParsedTitle, Quality};
+use std::collections::BTreeMap;
+
+use torrent_title_parser::{
+    parse_title, AlternativeTitle, AudioChannels, AudioCodec, Codec, Confidence, Crc32, Edition, HdrFormat, Language, Network, ParsedTitle,
+    Quality, RemakeCountry, Resolution, Script,
+};
 
 #[test]
 fn test_main_parsing() {
@@ -7,7 +12,7 @@ fn test_main_parsing() {
             "sons.of.anarchy.s05e10.480p.BluRay.x264-GAnGSteR",
             ParsedTitle {
                 title: "sons of anarchy".to_string(),
-                resolution: Some("480p".to_string()),
+                resolution: Some(Resolution::P480),
                 seasons: vec![5],
                 episodes: vec![10],
                 quality: Some(Quality::BluRay),
@@ -52,7 +57,7 @@ fn test_main_parsing() {
                 year: Some(1954),
                 dubbed: true,
                 codec: Some(Codec::Avc),
-                audio: vec!["AC3".to_string()],
+                audio: vec![AudioCodec::Ac3],
                 group: Some("gismo65".to_string()),
                 ..Default::default()
             },
@@ -62,7 +67,7 @@ fn test_main_parsing() {
             ParsedTitle {
                 title: "2019 After The Fall Of New York".to_string(),
                 quality: Some(Quality::BDRip),
-                edition: Some("Remastered".to_string()),
+                editions: vec![Edition::Remastered],
                 year: Some(1983),
                 codec: Some(Codec::Avc),
                 group: Some("GHOULS".to_string()),
@@ -76,9 +81,9 @@ fn test_main_parsing() {
                 quality: Some(Quality::HDRip),
                 hardcoded: true,
                 year: Some(2017),
-                resolution: Some("720p".to_string()),
+                resolution: Some(Resolution::P720),
                 codec: Some(Codec::Avc),
-                audio: vec!["AC3".to_string()],
+                audio: vec![AudioCodec::Ac3],
                 group: Some("EVO".to_string()),
                 ..Default::default()
             },
@@ -89,7 +94,7 @@ fn test_main_parsing() {
                 title: "Rogue One".to_string(),
                 quality: Some(Quality::BluRay),
                 year: Some(2016),
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 codec: Some(Codec::Avc),
                 group: Some("SPARKS".to_string()),
                 ..Default::default()
@@ -104,6 +109,7 @@ fn test_main_parsing() {
                 dubbed: true,
                 region: Some("R9".to_string()),
                 group: Some("TBW1973".to_string()),
+                low_confidence: low_confidence(&[("region", Confidence::Heuristic)]),
                 ..Default::default()
             },
         ),
@@ -113,8 +119,8 @@ fn test_main_parsing() {
                 title: "Maman, j'ai raté l'avion".to_string(),
                 quality: Some(Quality::BluRay),
                 year: Some(1990),
-                audio: vec!["DTS Lossy".to_string()],
-                resolution: Some("1080p".to_string()),
+                audio: vec![AudioCodec::DtsLossy],
+                resolution: Some(Resolution::P1080),
                 languages: vec![Language::French],
                 codec: Some(Codec::Hevc),
                 group: Some("HTG".to_string()),
@@ -144,7 +150,7 @@ fn test_main_parsing() {
             ParsedTitle {
                 title: "Skins".to_string(),
                 seasons: vec![1, 2, 3, 4, 5, 6, 7],
-                resolution: Some("720p".to_string()),
+                resolution: Some(Resolution::P720),
                 quality: Some(Quality::WebDL),
                 ..Default::default()
             },
@@ -154,7 +160,7 @@ fn test_main_parsing() {
             ParsedTitle {
                 title: "Futurama".to_string(),
                 seasons: vec![1, 2, 3, 4, 5, 6, 7],
-                resolution: Some("720p".to_string()),
+                resolution: Some(Resolution::P720),
                 quality: Some(Quality::BluRay),
                 codec: Some(Codec::Hevc),
                 group: Some("HETeam".to_string()),
@@ -165,10 +171,10 @@ fn test_main_parsing() {
             "You.[Uncut].S01.SweSub.1080p.x264-Justiso",
             ParsedTitle {
                 title: "You".to_string(),
-                edition: Some("Uncut".to_string()),
+                editions: vec![Edition::Uncut],
                 seasons: vec![1],
                 languages: vec![Language::Swedish],
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 codec: Some(Codec::Avc),
                 group: Some("Justiso".to_string()),
                 ..Default::default()
@@ -179,7 +185,7 @@ fn test_main_parsing() {
             ParsedTitle {
                 title: "Stephen Colbert".to_string(),
                 date: Some("2019-10-25".to_string()),
-                resolution: Some("480p".to_string()),
+                resolution: Some(Resolution::P480),
                 codec: Some(Codec::Avc),
                 ..Default::default()
             },
@@ -208,9 +214,9 @@ fn test_main_parsing() {
             ParsedTitle {
                 title: "Makeruna!! Aku no Gundan!".to_string(),
                 episodes: vec![4],
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 codec: Some(Codec::Avc),
-                audio: vec!["AAC".to_string()],
+                audio: vec![AudioCodec::Aac],
                 languages: vec![Language::Chinese],
                 trash: true,
                 ..Default::default()
@@ -222,7 +228,7 @@ fn test_main_parsing() {
                 title: "The Westward Ⅱ".to_string(),
                 year: Some(2019),
                 episodes: vec![17],
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 codec: Some(Codec::Avc),
                 group: Some("GM-Team".to_string()),
                 languages: vec![Language::Chinese],
@@ -233,11 +239,16 @@ fn test_main_parsing() {
             "Черное зеркало / Black Mirror / Сезон 4 / Серии 1-6 (6) [2017, США, WEBRip 1080p] MVO + Eng Sub",
             ParsedTitle {
                 title: "Black Mirror".to_string(),
+                alternative_titles: vec![AlternativeTitle {
+                    title: "Черное зеркало".to_string(),
+                    script: Script::Cyrillic,
+                }],
+                native_title: Some("Черное зеркало".to_string()),
                 year: Some(2017),
                 seasons: vec![4],
                 episodes: vec![1, 2, 3, 4, 5, 6],
                 languages: vec![Language::English, Language::Russian],
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 quality: Some(Quality::WebRip),
                 subbed: true,
                 ..Default::default()
@@ -248,9 +259,9 @@ fn test_main_parsing() {
             ParsedTitle {
                 title: "Student Council's Discretion / Seitokai no Ichizon".to_string(),
                 seasons: vec![1],
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 quality: Some(Quality::BDRip),
-                audio: vec!["AAC".to_string()],
+                audio: vec![AudioCodec::Aac],
                 codec: Some(Codec::Hevc),
                 group: Some("neoHEVC".to_string()),
                 ..Default::default()
@@ -261,12 +272,13 @@ fn test_main_parsing() {
             ParsedTitle {
                 title: "Chihayafuru 3".to_string(),
                 episodes: vec![21],
-                resolution: Some("720p".to_string()),
+                resolution: Some(Resolution::P720),
                 quality: Some(Quality::BDRip),
-                audio: vec!["AAC".to_string()],
+                audio: vec![AudioCodec::Aac],
                 container: Some("mkv".to_string()),
                 extension: Some("mkv".to_string()),
                 episode_code: Some("5F1911ED".to_string()),
+                checksum: Some(Crc32(0x5F1911ED)),
                 group: Some("Commie".to_string()),
                 ..Default::default()
             },
@@ -285,9 +297,9 @@ fn test_main_parsing() {
             ParsedTitle {
                 title: "Hostel".to_string(),
                 year: Some(2005),
-                resolution: Some("720p".to_string()),
+                resolution: Some(Resolution::P720),
                 quality: Some(Quality::BRRip),
-                audio: vec!["AC3".to_string()],
+                audio: vec![AudioCodec::Ac3],
                 languages: vec![Language::English, Language::Italian],
                 group: Some("LIFE".to_string()),
                 subbed: true,
@@ -300,7 +312,7 @@ fn test_main_parsing() {
                 title: "Soul Land".to_string(),
                 episodes: vec![121, 122, 123, 124, 125],
                 languages: vec![Language::English, Language::Chinese],
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 quality: Some(Quality::WebDL),
                 subbed: true,
                 ..Default::default()
@@ -311,7 +323,7 @@ fn test_main_parsing() {
             ParsedTitle {
                 title: "The God of Highschool".to_string(),
                 seasons: vec![1],
-                resolution: Some("720p".to_string()),
+                resolution: Some(Resolution::P720),
                 ..Default::default()
             },
         ),
@@ -345,7 +357,7 @@ fn test_main_parsing() {
                 year: Some(2024),
                 seasons: vec![1],
                 quality: Some(Quality::Web),
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 codec: Some(Codec::Avc),
                 group: Some("EDITH".to_string()),
                 scene: true,
@@ -358,11 +370,11 @@ fn test_main_parsing() {
                 title: "Madame Web".to_string(),
                 year: Some(2024),
                 quality: Some(Quality::BluRayRemux),
-                resolution: Some("2160p".to_string()),
-                channels: vec!["7.1".to_string()],
-                audio: vec!["Atmos".to_string(), "TrueHD".to_string()],
+                resolution: Some(Resolution::P2160),
+                channels: vec![AudioChannels::SevenPointOne],
+                audio: vec![AudioCodec::Atmos, AudioCodec::TrueHD],
                 codec: Some(Codec::Hevc),
-                hdr: vec!["DV".to_string()],
+                hdr: vec![HdrFormat::DolbyVision(None)],
                 group: Some("FraMeSToR".to_string()),
                 ..Default::default()
             },
@@ -371,9 +383,10 @@ fn test_main_parsing() {
             "The.Witcher.US.S01.INTERNAL.1080p.WEB.x264-STRiFE",
             ParsedTitle {
                 title: "The Witcher US".to_string(),
+                country: Some(RemakeCountry::UnitedStates),
                 seasons: vec![1],
                 quality: Some(Quality::Web),
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 codec: Some(Codec::Avc),
                 group: Some("STRiFE".to_string()),
                 scene: true,
@@ -387,12 +400,13 @@ fn test_main_parsing() {
                 year: Some(2024),
                 languages: vec![Language::English, Language::Hindi],
                 quality: Some(Quality::WebRip),
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 bit_depth: Some("10bit".to_string()),
-                audio: vec!["Dolby Digital Plus".to_string()],
-                channels: vec!["5.1".to_string()],
+                audio: vec![AudioCodec::DolbyDigitalPlus],
+                channels: vec![AudioChannels::FivePointOne],
                 codec: Some(Codec::Hevc),
                 network: Some(Network::Amazon),
+                low_confidence: low_confidence(&[("audio", Confidence::Heuristic)]),
                 ..Default::default()
             },
         ),
@@ -402,12 +416,12 @@ fn test_main_parsing() {
                 title: "The Simpsons".to_string(),
                 seasons: vec![1],
                 episodes: vec![1],
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 quality: Some(Quality::BluRay),
                 codec: Some(Codec::Hevc),
                 bit_depth: Some("10bit".to_string()),
-                audio: vec!["AC3".to_string(), "AAC".to_string()],
-                channels: vec!["5.1".to_string()],
+                audio: vec![AudioCodec::Ac3, AudioCodec::Aac],
+                channels: vec![AudioChannels::FivePointOne],
                 ..Default::default()
             },
         ),
@@ -418,8 +432,11 @@ fn test_main_parsing() {
                 container: Some("avi".to_string()),
                 extension: Some("avi".to_string()),
                 episode_code: Some("012073FE".to_string()),
+                checksum: Some(Crc32(0x012073FE)),
                 episodes: vec![264],
+                absolute_episodes: vec![264],
                 group: Some("DB".to_string()),
+                low_confidence: low_confidence(&[("absolute_episodes", Confidence::Heuristic)]),
                 ..Default::default()
             },
         ),
@@ -428,11 +445,14 @@ fn test_main_parsing() {
             ParsedTitle {
                 title: "One Piece".to_string(),
                 container: Some("mkv".to_string()),
-                resolution: Some("480p".to_string()),
+                resolution: Some(Resolution::P480),
                 extension: Some("mkv".to_string()),
                 episode_code: Some("2E05E658".to_string()),
+                checksum: Some(Crc32(0x2E05E658)),
                 episodes: vec![1111],
+                absolute_episodes: vec![1111],
                 group: Some("SubsPlease".to_string()),
+                low_confidence: low_confidence(&[("absolute_episodes", Confidence::Heuristic)]),
                 ..Default::default()
             },
         ),
@@ -444,11 +464,11 @@ fn test_main_parsing() {
                 episodes: vec![1056],
                 languages: vec![Language::French],
                 container: Some("mkv".to_string()),
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 scene: true,
                 quality: Some(Quality::Web),
                 codec: Some(Codec::Avc),
-                audio: vec!["AAC".to_string()],
+                audio: vec![AudioCodec::Aac],
                 ..Default::default()
             },
         ),
@@ -457,11 +477,11 @@ fn test_main_parsing() {
             ParsedTitle {
                 title: "Mary Poppins".to_string(),
                 year: Some(1964),
-                edition: Some("Anniversary Edition".to_string()),
+                editions: vec![Edition::AnniversaryEdition],
                 quality: Some(Quality::BluRayRemux),
-                resolution: Some("1080p".to_string()),
-                audio: vec!["DTS Lossless".to_string()],
-                channels: vec!["5.1".to_string()],
+                resolution: Some(Resolution::P1080),
+                audio: vec![AudioCodec::DtsLossless],
+                channels: vec![AudioChannels::FivePointOne],
                 codec: Some(Codec::Avc),
                 group: Some("LEGi0N".to_string()),
                 ..Default::default()
@@ -472,14 +492,14 @@ fn test_main_parsing() {
             ParsedTitle {
                 title: "The Lord of the Rings The Fellowship of the Ring".to_string(),
                 year: Some(2001),
-                resolution: Some("2160p".to_string()),
-                edition: Some("Extended Edition".to_string()),
+                resolution: Some(Resolution::P2160),
+                editions: vec![Edition::ExtendedEdition],
                 quality: Some(Quality::BluRay),
                 codec: Some(Codec::Hevc),
                 bit_depth: Some("10bit".to_string()),
-                audio: vec!["Atmos".to_string(), "TrueHD".to_string()],
-                channels: vec!["7.1".to_string()],
-                hdr: vec!["HDR".to_string()],
+                audio: vec![AudioCodec::Atmos, AudioCodec::TrueHD],
+                channels: vec![AudioChannels::SevenPointOne],
+                hdr: vec![HdrFormat::Hdr],
                 group: Some("BOREDOR".to_string()),
                 ..Default::default()
             },
@@ -491,9 +511,9 @@ fn test_main_parsing() {
                 year: Some(2000),
                 quality: Some(Quality::BDRip),
                 codec: Some(Codec::Hevc),
-                resolution: Some("1896x1048p".to_string()),
-                audio: vec!["TrueHD".to_string(), "FLAC".to_string(), "AC3".to_string()],
-                channels: vec!["5.1".to_string()],
+                resolution: Some(Resolution::P1080),
+                audio: vec![AudioCodec::TrueHD, AudioCodec::Flac, AudioCodec::Ac3],
+                channels: vec![AudioChannels::FivePointOne],
                 dubbed: true,
                 container: Some("mkv".to_string()),
                 extension: Some("mkv".to_string()),
@@ -507,16 +527,16 @@ fn test_main_parsing() {
                 year: Some(2023),
                 languages: vec![Language::English, Language::Tamil],
                 quality: Some(Quality::WebDL),
-                resolution: Some("2160p".to_string()),
-                hdr: vec!["SDR".to_string()],
+                resolution: Some(Resolution::P2160),
+                hdr: vec![HdrFormat::Sdr],
                 codec: Some(Codec::Hevc),
                 site: Some("www.1TamilMV.pics".to_string()),
                 size: Some("3.2GB".to_string()),
                 container: Some("mkv".to_string()),
                 extension: Some("mkv".to_string()),
                 bitrate: Some("384kbps".to_string()),
-                audio: vec!["TrueHD".to_string(), "Dolby Digital Plus".to_string(), "AAC".to_string()],
-                channels: vec!["5.1".to_string()],
+                audio: vec![AudioCodec::TrueHD, AudioCodec::DolbyDigitalPlus, AudioCodec::Aac],
+                channels: vec![AudioChannels::FivePointOne],
                 ..Default::default()
             },
         ),
@@ -528,8 +548,8 @@ fn test_main_parsing() {
                 languages: vec![Language::Hindi],
                 quality: Some(Quality::SCR),
                 codec: Some(Codec::Avc),
-                audio: vec!["AAC".to_string()],
-                resolution: Some("1080p".to_string()),
+                audio: vec![AudioCodec::Aac],
+                resolution: Some(Resolution::P1080),
                 container: Some("mkv".to_string()),
                 extension: Some("mkv".to_string()),
                 size: Some("2.3GB".to_string()),
@@ -542,13 +562,18 @@ fn test_main_parsing() {
             "超能警探.Memorist.S01E01.2160p.WEB-DL.H265.AAC-FLTTH.mkv",
             ParsedTitle {
                 title: "Memorist".to_string(),
+                alternative_titles: vec![AlternativeTitle {
+                    title: "超能警探".to_string(),
+                    script: Script::Chinese,
+                }],
+                native_title: Some("超能警探".to_string()),
                 seasons: vec![1],
                 episodes: vec![1],
                 languages: vec![Language::Chinese],
                 quality: Some(Quality::WebDL),
                 codec: Some(Codec::Hevc),
-                audio: vec!["AAC".to_string()],
-                resolution: Some("2160p".to_string()),
+                audio: vec![AudioCodec::Aac],
+                resolution: Some(Resolution::P2160),
                 container: Some("mkv".to_string()),
                 extension: Some("mkv".to_string()),
                 group: Some("FLTTH".to_string()),
@@ -565,11 +590,12 @@ fn test_main_parsing() {
                 codec: Some(Codec::Avc),
                 container: Some("mkv".to_string()),
                 extension: Some("mkv".to_string()),
-                audio: vec!["Dolby Digital Plus".to_string()],
-                channels: vec!["5.1".to_string()],
+                audio: vec![AudioCodec::DolbyDigitalPlus],
+                channels: vec![AudioChannels::FivePointOne],
                 quality: Some(Quality::WebDL),
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 group: Some("FLUX".to_string()),
+                low_confidence: low_confidence(&[("audio", Confidence::Heuristic)]),
                 ..Default::default()
             },
         ),
@@ -580,11 +606,11 @@ fn test_main_parsing() {
                 year: Some(2013),
                 quality: Some(Quality::BDRip),
                 codec: Some(Codec::Hevc),
-                audio: vec!["DTS Lossless".to_string()],
-                channels: vec!["5.1".to_string()],
+                audio: vec![AudioCodec::DtsLossless],
+                channels: vec![AudioChannels::FivePointOne],
                 container: Some("mkv".to_string()),
                 extension: Some("mkv".to_string()),
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 ..Default::default()
             },
         ),
@@ -594,11 +620,12 @@ fn test_main_parsing() {
                 title: "Love, Death & Robots".to_string(),
                 seasons: vec![1],
                 languages: vec![Language::Spanish],
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 year: Some(2019),
                 complete: true,
                 site: Some("WWW.BLUDV.TV".to_string()),
                 trash: true,
+                low_confidence: low_confidence(&[("languages", Confidence::Inferred)]),
                 ..Default::default()
             },
         ),
@@ -609,7 +636,7 @@ fn test_main_parsing() {
                 year: Some(2019),
                 languages: vec![Language::English, Language::Hindi],
                 quality: Some(Quality::WebDL),
-                resolution: Some("720p".to_string()),
+                resolution: Some(Resolution::P720),
                 codec: Some(Codec::Avc),
                 container: Some("mkv".to_string()),
                 extension: Some("mkv".to_string()),
@@ -625,7 +652,7 @@ fn test_main_parsing() {
             ParsedTitle {
                 title: "28 days".to_string(),
                 year: Some(2000),
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 quality: Some(Quality::BluRay),
                 codec: Some(Codec::Avc),
                 container: Some("mkv".to_string()),
@@ -639,10 +666,10 @@ fn test_main_parsing() {
             ParsedTitle {
                 title: "4 20 Massacre".to_string(),
                 year: Some(2018),
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 quality: Some(Quality::BluRay),
                 codec: Some(Codec::Avc),
-                audio: vec!["AAC".to_string()],
+                audio: vec![AudioCodec::Aac],
                 container: Some("mp4".to_string()),
                 extension: Some("mp4".to_string()),
                 site: Some("YTS.MX".to_string()),
@@ -711,13 +738,13 @@ fn test_main_parsing() {
             ParsedTitle {
                 title: "Dune Part Two".to_string(),
                 year: Some(2024),
-                resolution: Some("2160p".to_string()),
+                resolution: Some(Resolution::P2160),
                 quality: Some(Quality::WebDL),
                 codec: Some(Codec::Hevc),
-                audio: vec!["Dolby Digital Plus".to_string(), "Atmos".to_string()],
-                channels: vec!["5.1".to_string()],
+                audio: vec![AudioCodec::DolbyDigitalPlus, AudioCodec::Atmos],
+                channels: vec![AudioChannels::FivePointOne],
                 group: Some("FLUX".to_string()),
-                hdr: vec!["DV".to_string(), "HDR".to_string()],
+                hdr: vec![HdrFormat::DolbyVision(None), HdrFormat::Hdr],
                 ..Default::default()
             },
         ),
@@ -727,10 +754,10 @@ fn test_main_parsing() {
                 title: "Saw 3D".to_string(),
                 year: Some(2010),
                 languages: vec![Language::English, Language::Italian],
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 quality: Some(Quality::BluRay),
                 codec: Some(Codec::Hevc),
-                audio: vec!["AAC".to_string()],
+                audio: vec![AudioCodec::Aac],
                 container: Some("mkv".to_string()),
                 extension: Some("mkv".to_string()),
                 group: Some("V3SP4EV3R".to_string()),
@@ -742,9 +769,10 @@ fn test_main_parsing() {
             ParsedTitle {
                 title: "Dead Before Dawn 3D".to_string(),
                 year: Some(2012),
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 quality: Some(Quality::BluRay),
                 is_3d: true,
+                low_confidence: low_confidence(&[("3d", Confidence::Heuristic)]),
                 ..Default::default()
             },
         ),
@@ -753,12 +781,13 @@ fn test_main_parsing() {
             ParsedTitle {
                 title: "Wonder Woman 1984".to_string(),
                 year: Some(2020),
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 quality: Some(Quality::BluRay),
                 codec: Some(Codec::Avc),
                 group: Some("SURCODE".to_string()),
                 scene: true,
                 is_3d: true,
+                low_confidence: low_confidence(&[("3d", Confidence::Heuristic)]),
                 ..Default::default()
             },
         ),
@@ -768,7 +797,7 @@ fn test_main_parsing() {
                 title: "The Last of Us".to_string(),
                 seasons: vec![1],
                 episodes: vec![8],
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 quality: Some(Quality::Web),
                 codec: Some(Codec::Avc),
                 group: Some("CAKES".to_string()),
@@ -780,11 +809,12 @@ fn test_main_parsing() {
             "The.Office.UK.S01.1080P.BLURAY.REMUX.AVC.DD5.1-NOGRP",
             ParsedTitle {
                 title: "The Office UK".to_string(),
+                country: Some(RemakeCountry::UnitedKingdom),
                 seasons: vec![1],
                 quality: Some(Quality::BluRayRemux),
-                resolution: Some("1080p".to_string()),
-                audio: vec!["Dolby Digital".to_string()],
-                channels: vec!["5.1".to_string()],
+                resolution: Some(Resolution::P1080),
+                audio: vec![AudioCodec::DolbyDigital],
+                channels: vec![AudioChannels::FivePointOne],
                 codec: Some(Codec::Avc),
                 group: Some("NOGRP".to_string()),
                 languages: vec![],
@@ -795,9 +825,10 @@ fn test_main_parsing() {
             "The.Office.US.S01-09.COMPLETE.SERIES.1080P.BLURAY.X265-HIQVE",
             ParsedTitle {
                 title: "The Office US".to_string(),
+                country: Some(RemakeCountry::UnitedStates),
                 seasons: vec![1, 2, 3, 4, 5, 6, 7, 8, 9],
                 quality: Some(Quality::BluRay),
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 codec: Some(Codec::Hevc),
                 group: Some("HIQVE".to_string()),
                 complete: true,
@@ -813,9 +844,9 @@ fn test_main_parsing() {
                 seasons: vec![23],
                 episodes: vec![1],
                 quality: Some(Quality::WebDL),
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 codec: Some(Codec::Avc),
-                audio: vec!["Dolby Digital Plus".to_string()],
+                audio: vec![AudioCodec::DolbyDigitalPlus],
                 group: Some("NTb".to_string()),
                 extension: Some("mkv".to_string()),
                 container: Some("mkv".to_string()),
@@ -829,15 +860,15 @@ fn test_main_parsing() {
                 title: "Fallout".to_string(),
                 seasons: vec![1],
                 episodes: vec![3],
-                resolution: Some("2160p".to_string()),
+                resolution: Some(Resolution::P2160),
                 codec: Some(Codec::Hevc),
-                audio: vec!["AC3".to_string(), "Dolby Digital Plus".to_string()],
-                channels: vec!["5.1".to_string()],
+                audio: vec![AudioCodec::Ac3, AudioCodec::DolbyDigitalPlus],
+                channels: vec![AudioChannels::FivePointOne],
                 group: Some("DirtyHippie".to_string()),
                 container: Some("mkv".to_string()),
                 dubbed: true,
                 extension: Some("mkv".to_string()),
-                hdr: vec!["DV".to_string(), "HDR10+".to_string()],
+                hdr: vec![HdrFormat::DolbyVision(None), HdrFormat::Hdr10Plus],
                 upscaled: true,
                 ..Default::default()
             },
@@ -848,7 +879,7 @@ fn test_main_parsing() {
                 title: "BoJack Horseman".to_string(),
                 seasons: vec![6],
                 episodes: vec![1, 2, 3, 4, 5, 6, 7, 8],
-                resolution: Some("720p".to_string()),
+                resolution: Some(Resolution::P720),
                 quality: Some(Quality::WebDLRip),
                 complete: true,
                 ..Default::default()
@@ -858,9 +889,14 @@ fn test_main_parsing() {
             "Трон: Наследие / TRON: Legacy (2010) WEB-DL 1080p | D | Open Matte",
             ParsedTitle {
                 title: "TRON: Legacy".to_string(),
+                alternative_titles: vec![AlternativeTitle {
+                    title: "Трон: Наследие".to_string(),
+                    script: Script::Cyrillic,
+                }],
+                native_title: Some("Трон: Наследие".to_string()),
                 year: Some(2010),
                 languages: vec![Language::Russian],
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 quality: Some(Quality::WebDL),
                 ..Default::default()
             },
@@ -873,7 +909,7 @@ fn test_main_parsing() {
                 episodes: vec![6],
                 quality: Some(Quality::PDTV),
                 codec: Some(Codec::Avc),
-                audio: vec!["AAC".to_string()],
+                audio: vec![AudioCodec::Aac],
                 group: Some("BTN".to_string()),
                 ..Default::default()
             },
@@ -886,15 +922,16 @@ fn test_main_parsing() {
                 languages: vec![Language::English, Language::Hindi, Language::Telugu, Language::Tamil, Language::Malayalam],
                 quality: Some(Quality::BDRip),
                 codec: Some(Codec::Hevc),
-                audio: vec!["Dolby Digital Plus".to_string()],
-                channels: vec!["5.1".to_string()],
-                resolution: Some("2160p".to_string()),
+                audio: vec![AudioCodec::DolbyDigitalPlus],
+                channels: vec![AudioChannels::FivePointOne],
+                resolution: Some(Resolution::P2160),
                 container: Some("mkv".to_string()),
                 extension: Some("mkv".to_string()),
                 site: Some("www.1Tamilblasters.co".to_string()),
                 bitrate: Some("192kbps".to_string()),
-                edition: Some("IMAX".to_string()),
+                editions: vec![Edition::Imax],
                 size: Some("8.3GB".to_string()),
+                low_confidence: low_confidence(&[("audio", Confidence::Heuristic), ("languages", Confidence::Heuristic)]),
                 ..Default::default()
             },
         ),
@@ -906,13 +943,14 @@ fn test_main_parsing() {
                 languages: vec![Language::Chinese],
                 quality: Some(Quality::WebDL),
                 codec: Some(Codec::Hevc),
-                audio: vec!["Dolby Digital Plus".to_string()],
-                channels: vec!["5.1".to_string()],
-                resolution: Some("2160p".to_string()),
+                audio: vec![AudioCodec::DolbyDigitalPlus],
+                channels: vec![AudioChannels::FivePointOne],
+                resolution: Some(Resolution::P2160),
                 site: Some("www.hdbthd.com".to_string()),
                 group: Some("dreamhd".to_string()),
-                hdr: vec!["DV".to_string()],
+                hdr: vec![HdrFormat::DolbyVision(None)],
                 trash: true,
+                low_confidence: low_confidence(&[("audio", Confidence::Heuristic)]),
                 ..Default::default()
             },
         ),
@@ -923,9 +961,9 @@ fn test_main_parsing() {
                 year: Some(2018),
                 languages: vec![Language::Hindi],
                 quality: Some(Quality::TeleSync),
-                resolution: Some("720p".to_string()),
+                resolution: Some(Resolution::P720),
                 codec: Some(Codec::Avc),
-                audio: vec!["HQ Clean Audio".to_string()],
+                audio: vec![AudioCodec::HqCleanAudio],
                 dubbed: true,
                 trash: true,
                 ..Default::default()
@@ -940,13 +978,14 @@ fn test_main_parsing() {
                 episodes: vec![1, 2, 3, 4, 5, 6, 7, 8],
                 languages: vec![Language::Hindi, Language::Telugu, Language::Tamil],
                 quality: Some(Quality::HDRip),
-                resolution: Some("720p".to_string()),
+                resolution: Some(Resolution::P720),
                 codec: Some(Codec::Avc),
-                audio: vec!["Dolby Digital Plus".to_string()],
-                channels: vec!["5.1".to_string()],
+                audio: vec![AudioCodec::DolbyDigitalPlus],
+                channels: vec![AudioChannels::FivePointOne],
                 site: Some("www.Tamilblasters.party".to_string()),
                 size: Some("2.7GB".to_string()),
                 trash: true,
+                low_confidence: low_confidence(&[("audio", Confidence::Heuristic)]),
                 ..Default::default()
             },
         ),
@@ -972,7 +1011,7 @@ fn test_main_parsing() {
                 seasons: vec![5],
                 episodes: vec![3],
                 quality: Some(Quality::Remux),
-                resolution: Some("720p".to_string()),
+                resolution: Some(Resolution::P720),
                 codec: Some(Codec::Avc),
                 group: Some("ASAP".to_string()),
                 ..Default::default()
@@ -985,14 +1024,15 @@ fn test_main_parsing() {
                 year: Some(2021),
                 languages: vec![Language::English, Language::Hindi, Language::Telugu, Language::Tamil],
                 quality: Some(Quality::BDRip),
-                resolution: Some("720p".to_string()),
+                resolution: Some(Resolution::P720),
                 codec: Some(Codec::Avc),
-                audio: vec!["Dolby Digital Plus".to_string()],
-                channels: vec!["5.1".to_string()],
+                audio: vec![AudioCodec::DolbyDigitalPlus],
+                channels: vec![AudioChannels::FivePointOne],
                 site: Some("www.TamilBlasters.vip".to_string()),
                 size: Some("1.4GB".to_string()),
                 extension: Some("mkv".to_string()),
                 container: Some("mkv".to_string()),
+                low_confidence: low_confidence(&[("audio", Confidence::Heuristic)]),
                 ..Default::default()
             },
         ),
@@ -1002,10 +1042,11 @@ fn test_main_parsing() {
                 title: "Game of Thrones".to_string(),
                 seasons: vec![1, 2, 3, 4, 5, 6, 7, 8],
                 languages: vec![Language::Spanish],
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 quality: Some(Quality::BluRay),
                 complete: true,
                 dubbed: true,
+                low_confidence: low_confidence(&[("languages", Confidence::Inferred)]),
                 ..Default::default()
             },
         ),
@@ -1014,11 +1055,11 @@ fn test_main_parsing() {
             ParsedTitle {
                 title: "Kill".to_string(),
                 year: Some(2024),
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 quality: Some(Quality::WebDL),
                 codec: Some(Codec::Avc),
-                audio: vec!["Dolby Digital Plus".to_string(), "Atmos".to_string()],
-                channels: vec!["5.1".to_string()],
+                audio: vec![AudioCodec::DolbyDigitalPlus, AudioCodec::Atmos],
+                channels: vec![AudioChannels::FivePointOne],
                 group: Some("XEBEC".to_string()),
                 container: Some("mkv".to_string()),
                 extension: Some("mkv".to_string()),
@@ -1032,13 +1073,14 @@ fn test_main_parsing() {
             ParsedTitle {
                 title: "Mad Max Fury Road".to_string(),
                 year: Some(2015),
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 codec: Some(Codec::Hevc),
                 bit_depth: Some("10bit".to_string()),
-                audio: vec!["Dolby Digital Plus".to_string()],
-                channels: vec!["5.1".to_string()],
+                audio: vec![AudioCodec::DolbyDigitalPlus],
+                channels: vec![AudioChannels::FivePointOne],
                 group: Some("GalaxyRG265".to_string()),
                 quality: Some(Quality::BluRay),
+                low_confidence: low_confidence(&[("audio", Confidence::Heuristic)]),
                 ..Default::default()
             },
         ),
@@ -1058,9 +1100,14 @@ fn test_main_parsing() {
             "抓娃娃 Successor.2024.TC1080P.国语中字",
             ParsedTitle {
                 title: "Successor".to_string(),
+                alternative_titles: vec![AlternativeTitle {
+                    title: "抓娃娃".to_string(),
+                    script: Script::Chinese,
+                }],
+                native_title: Some("抓娃娃".to_string()),
                 year: Some(2024),
                 languages: vec![Language::Chinese],
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 quality: Some(Quality::TeleCine),
                 trash: true,
                 ..Default::default()
@@ -1072,7 +1119,7 @@ fn test_main_parsing() {
                 title: "True Detective".to_string(),
                 seasons: vec![3],
                 episodes: vec![2],
-                resolution: Some("720p".to_string()),
+                resolution: Some(Resolution::P720),
                 scene: true,
                 quality: Some(Quality::Web),
                 codec: Some(Codec::Hevc),
@@ -1087,7 +1134,7 @@ fn test_main_parsing() {
             ParsedTitle {
                 title: "True Grit".to_string(),
                 year: Some(1969),
-                resolution: Some("720p".to_string()),
+                resolution: Some(Resolution::P720),
                 scene: true,
                 quality: Some(Quality::Web),
                 codec: Some(Codec::Hevc),
@@ -1102,7 +1149,7 @@ fn test_main_parsing() {
             ParsedTitle {
                 title: "Free Samples".to_string(),
                 year: Some(2012),
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 quality: Some(Quality::BluRay),
                 ..Default::default()
             },
@@ -1123,11 +1170,11 @@ fn test_main_parsing() {
                 title: "Adbhut".to_string(),
                 year: Some(2024),
                 languages: vec![Language::Hindi],
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 quality: Some(Quality::HDTVRip),
                 codec: Some(Codec::Avc),
-                audio: vec!["AC3".to_string(), "AAC".to_string()],
-                channels: vec!["5.1".to_string()],
+                audio: vec![AudioCodec::Ac3, AudioCodec::Aac],
+                channels: vec![AudioChannels::FivePointOne],
                 group: Some("QRips".to_string()),
                 size: Some("2.2GB".to_string()),
                 ..Default::default()
@@ -1138,12 +1185,13 @@ fn test_main_parsing() {
             ParsedTitle {
                 title: "Blood Diamond".to_string(),
                 year: Some(2006),
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 quality: Some(Quality::BluRay),
                 codec: Some(Codec::Avc),
-                audio: vec!["Dolby Digital".to_string()],
-                channels: vec!["5.1".to_string()],
+                audio: vec![AudioCodec::DolbyDigital],
+                channels: vec![AudioChannels::FivePointOne],
                 container: Some("mp4".to_string()),
+                low_confidence: low_confidence(&[("audio", Confidence::Heuristic)]),
                 ..Default::default()
             },
         ),
@@ -1165,7 +1213,7 @@ fn test_main_parsing() {
                 quality: Some(Quality::Web),
                 codec: Some(Codec::Avc),
                 group: Some("SuccessfulCrab".to_string()),
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 documentary: true,
                 scene: true,
                 ..Default::default()
@@ -1181,7 +1229,7 @@ fn test_main_parsing() {
                 container: Some("mkv".to_string()),
                 codec: Some(Codec::Avc),
                 group: Some("INFLATE".to_string()),
-                resolution: Some("720p".to_string()),
+                resolution: Some(Resolution::P720),
                 scene: true,
                 ..Default::default()
             },
@@ -1192,13 +1240,13 @@ fn test_main_parsing() {
                 title: "The Penguin".to_string(),
                 seasons: vec![1],
                 episodes: vec![7],
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 quality: Some(Quality::WebDL),
                 network: Some(Network::Amazon),
                 codec: Some(Codec::Avc),
                 dubbed: true,
-                audio: vec!["Dolby Digital Plus".to_string(), "Atmos".to_string()],
-                channels: vec!["5.1".to_string()],
+                audio: vec![AudioCodec::DolbyDigitalPlus, AudioCodec::Atmos],
+                channels: vec![AudioChannels::FivePointOne],
                 site: Some("BEST-TORRENTS.COM".to_string()),
                 ..Default::default()
             },
@@ -1223,7 +1271,7 @@ fn test_main_parsing() {
             ParsedTitle {
                 title: "The O C".to_string(),
                 seasons: vec![1, 2, 3, 4],
-                resolution: Some("1080p".to_string()),
+                resolution: Some(Resolution::P1080),
                 network: Some(Network::Amazon),
                 codec: Some(Codec::Hevc),
                 bit_depth: Some("10bit".to_string()),
@@ -1236,14 +1284,14 @@ fn test_main_parsing() {
             ParsedTitle {
                 title: "The Adam Project".to_string(),
                 year: Some(2022),
-                resolution: Some("2160p".to_string()),
+                resolution: Some(Resolution::P2160),
                 quality: Some(Quality::WebDL),
                 network: Some(Network::Netflix),
                 codec: Some(Codec::Hevc),
                 container: Some("mkv".to_string()),
-                audio: vec!["Atmos".to_string(), "Dolby Digital Plus".to_string()],
-                channels: vec!["5.1".to_string()],
-                hdr: vec!["DV".to_string(), "HDR".to_string()],
+                audio: vec![AudioCodec::Atmos, AudioCodec::DolbyDigitalPlus],
+                channels: vec![AudioChannels::FivePointOne],
+                hdr: vec![HdrFormat::DolbyVision(None), HdrFormat::Hdr],
                 ..Default::default()
             },
         )
@@ -1254,3 +1302,7 @@ fn test_main_parsing() {
         assert_eq!(result, expected_output, "Failed for {}", release_name);
     }
 }
+
+fn low_confidence(fields: &[(&str, Confidence)]) -> BTreeMap<String, Confidence> {
+    fields.iter().map(|(field, confidence)| (field.to_string(), *confidence)).collect()
+}
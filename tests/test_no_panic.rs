@@ -0,0 +1,71 @@
+use std::fs;
+use std::path::Path;
+
+use torrent_title_parser::{parse_title, parse_title_detailed, Handler, HandlerResult, ParseOptions, Parser, Resolution};
+
+#[test]
+fn test_fuzz_regressions() {
+    // inputs that made the parser panic, found by the targets in fuzz/
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("fuzz/regressions");
+    let mut count = 0;
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        let title = fs::read_to_string(&path).unwrap();
+        let parser = Parser::default();
+        // some of them are rejected (e.g. the empty one), that's fine as long as nothing panics
+        let _ = parse_title(&title);
+        if let Ok(detailed) = parse_title_detailed(&title) {
+            for span in detailed.spans.iter().map(|span| &span.span).chain([&detailed.title_span]) {
+                assert!(
+                    title.get(span.clone()).is_some(),
+                    "span {:?} of {} isn't on char boundaries",
+                    span,
+                    path.display()
+                );
+            }
+        }
+        let _ = parser.parse_traced(&title);
+        let lenient = ParseOptions::new().lenient(true);
+        assert!(parser.parse_with(&title, &lenient).is_ok(), "Failed to parse {}", path.display());
+        assert!(parser.parse_with(&title, &lenient.fields(["volumes"])).is_ok());
+        count += 1;
+    }
+    assert!(count > 0);
+}
+
+#[test]
+fn test_fuzz_regression_fields() {
+    // the byte positions in these land next to multi-byte characters after text was cut out in front of them
+    let regressions = Path::new(env!("CARGO_MANIFEST_DIR")).join("fuzz/regressions");
+    let parse = |name: &str| parse_title(&fs::read_to_string(regressions.join(name)).unwrap()).unwrap();
+    assert_eq!(parse("episodes-find-after-removal").episodes, vec![5]);
+    assert_eq!(parse("volumes-parse-after-year").volumes, vec![7]);
+    let parsed = parse("title-end-after-removals");
+    assert_eq!(parsed.resolution, Some(Resolution::P720));
+    assert_eq!(parsed.year, Some(2019));
+}
+
+#[test]
+fn test_custom_handler_bad_position() {
+    let mut parser = Parser::new();
+    parser.add_handler(Handler::new("site", |_| {
+        Some(HandlerResult {
+            raw_match: "é".to_string(),
+            match_index: 2, // inside the "é" of the title
+            remove: true,
+            skip_from_title: true,
+        })
+    }));
+    parser.add_handler(Handler::new("group", |_| {
+        Some(HandlerResult {
+            raw_match: "GRP".to_string(),
+            match_index: usize::MAX,
+            remove: true,
+            skip_from_title: false,
+        })
+    }));
+
+    let (result, trace) = parser.parse_traced("Aé Movie").unwrap();
+    assert_eq!(result.title, "A Movie");
+    assert_eq!(trace[0].span, Some(1..3));
+}
@@ -0,0 +1,106 @@
+use torrent_title_parser::{transforms, Handler, ParseOptions, Parser, Pattern, RegexHandlerOptions, RemakeCountry, Resolution};
+
+const TITLES: &[&str] = &[
+    "The.Simpsons.S01E01.1080p.BluRay.x265.HEVC.10bit.AAC.5.1-Tigole",
+    "House MD All Seasons (1-8) 720p Ultra-Compressed",
+    "[SubsPlease] Frieren - 12 (1080p) [A1B2C3D4].mkv",
+    "Avatar The Way of Water (2022) 1080p HQ S-Print Dual Audio [Hindi   English] x264 AAC HC-Esub - CineVood.mkv",
+    "Dragon Ball Z Movie - 09 - Bojack Unbound - 1080p BluRay x264 DTS 5.1 -DDR",
+    "Friends.S01-S10.COMPLETE.720p.BluRay.x264-GRP",
+    "Naruto Shippuden Vol. 3 Episodes 101-125 [Dual Audio] 480p",
+    "La Casa de Papel Temporada 2 Completa 720p",
+    "Game of Thrones - S02E07 - A Man Without Honor [2160p] [HDR] [5.1, 7.1, 5.1] [ger, eng, eng] [Vio].mkv",
+    "Marvel's.Agents.of.S.H.I.E.L.D.S02E01.Shadows.1080p.WEB-DL.DD5.1",
+    "The Mandalorian 2x07 Chapter 15 720p",
+    "R2 Movie 2009 DVDRip XviD",
+];
+
+#[test]
+fn test_masked_fields_match_full_parse() {
+    let parser = Parser::default();
+    let options = ParseOptions::new().fields(["resolution", "seasons", "episodes"]);
+
+    for title in TITLES {
+        let full = parser.parse(title).unwrap();
+        let masked = parser.parse_with(title, &options).unwrap();
+        assert_eq!(masked.resolution, full.resolution, "resolution of {}", title);
+        assert_eq!(masked.seasons, full.seasons, "seasons of {}", title);
+        assert_eq!(masked.episodes, full.episodes, "episodes of {}", title);
+        assert_eq!(masked.title, "", "title of {} is only computed on request", title);
+    }
+}
+
+#[test]
+fn test_other_masks_match_full_parse() {
+    let parser = Parser::default();
+    let languages = ParseOptions::new().fields(["languages"]);
+    let quality = ParseOptions::new().fields(["quality", "codec", "group", "volumes", "region"]);
+
+    for title in TITLES {
+        let full = parser.parse(title).unwrap();
+
+        let masked = parser.parse_with(title, &languages).unwrap();
+        assert_eq!(masked.languages, full.languages, "languages of {}", title);
+
+        let masked = parser.parse_with(title, &quality).unwrap();
+        assert_eq!(masked.quality, full.quality, "quality of {}", title);
+        assert_eq!(masked.codec, full.codec, "codec of {}", title);
+        assert_eq!(masked.group, full.group, "group of {}", title);
+        assert_eq!(masked.volumes, full.volumes, "volumes of {}", title);
+        assert_eq!(masked.region, full.region, "region of {}", title);
+    }
+}
+
+#[test]
+fn test_title_or_no_mask_is_a_full_parse() {
+    let parser = Parser::default();
+    for title in TITLES {
+        let full = parser.parse(title).unwrap();
+        assert_eq!(parser.parse_with(title, &ParseOptions::new()).unwrap(), full);
+        assert_eq!(parser.parse_with(title, &ParseOptions::new().fields(["title"])).unwrap(), full);
+    }
+}
+
+#[test]
+fn test_traced_with_fields() {
+    let parser = Parser::default();
+    let options = ParseOptions::new().fields(["resolution"]);
+    let (parsed, trace) = parser.parse_traced_with("The.Matrix.1999.1080p.BluRay.x264", &options).unwrap();
+    assert_eq!(parsed.resolution, Some(Resolution::P1080));
+    assert!(trace.len() < parser.handler_names().count());
+    assert!(trace.iter().all(|handler| handler.name != "codec"));
+}
+
+#[test]
+fn test_country_mask_matches_full_parse() {
+    // the country handler reads where the title ends, which any earlier handler can move
+    let mut parser = Parser::with_default_handlers();
+    parser.insert_handler_after(
+        "extension",
+        Handler::from_regex(
+            "site",
+            |t| &mut t.site,
+            Pattern::case_insensitive(r"\bExtras\b").unwrap(),
+            transforms::value("extras"),
+            RegexHandlerOptions::default(),
+        ),
+    );
+    let options = ParseOptions::new().fields(["country"]);
+    let titles = [
+        "Shameless UK Extras 720p",
+        "The.Office.US.S01E01.720p.HDTV.x264",
+        "Skins Season S01-S07 COMPLETE UK Soundtrack 720p WEB-DL",
+        "Queer as Folk UK 1999 DVDRip",
+        "Sherlock.Holmes.CA.mkv",
+    ];
+
+    for title in TITLES.iter().chain(&titles) {
+        let full = parser.parse(title).unwrap();
+        let masked = parser.parse_with(title, &options).unwrap();
+        assert_eq!(masked.country, full.country, "country of {}", title);
+    }
+    assert_eq!(
+        parser.parse("Shameless UK Extras 720p").unwrap().country,
+        Some(RemakeCountry::UnitedKingdom)
+    );
+}
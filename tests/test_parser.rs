@@ -1,10 +1,10 @@
-use torrent_title_parser::{parse_title, Codec, Quality};
+use torrent_title_parser::{parse_title, Codec, Quality, Resolution};
 
 #[test]
 fn test_parsed_output() {
     let test_case = "[Golumpa] Fairy Tail - 214 [FuniDub 720p x264 AAC] [5E46AC39]";
     let result = parse_title(test_case).unwrap();
-    assert!(result.title.len() > 0);
+    assert!(!result.title.is_empty());
     assert!(result.episode_code.is_some());
     assert!(result.resolution.is_some());
     assert!(result.codec.is_some());
@@ -16,7 +16,7 @@ fn test_basic_parsed() {
     let test_case = "The.Matrix.1999.1080p.BluRay.x264";
     let result = parse_title(test_case).unwrap();
     assert_eq!(result.title, "The Matrix");
-    assert_eq!(result.resolution, Some("1080p".to_string()));
+    assert_eq!(result.resolution, Some(Resolution::P1080));
     assert_eq!(result.year, Some(1999));
     assert_eq!(result.quality, Some(Quality::BluRay));
     assert_eq!(result.codec, Some(Codec::Avc));
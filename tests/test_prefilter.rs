@@ -0,0 +1,93 @@
+use torrent_title_parser::regress::{Flags, Regex};
+use torrent_title_parser::{
+    transforms, AudioChannels, AudioCodec, Codec, Handler, HdrFormat, Parser, Pattern, RegexHandlerOptions, Resolution,
+};
+
+fn site_handler(pattern: &str, value: &'static str, remove: bool) -> Handler {
+    Handler::from_regex(
+        "site",
+        |t| &mut t.site,
+        Pattern::case_insensitive(pattern).unwrap(),
+        transforms::value(value),
+        RegexHandlerOptions {
+            remove,
+            skip_if_already_found: false,
+            ..Default::default()
+        },
+    )
+}
+
+#[test]
+fn test_prefilter_literal_joined_by_removal() {
+    let mut parser = Parser::new();
+    parser.add_handler(site_handler(r"\[junk\]", "junk", true));
+    parser.add_handler(site_handler(r"\btrackerx\b", "trackerx", false));
+
+    // "TRACKERX" only exists once "[junk]" is cut out of the title
+    let result = parser.parse("Some Show TRACKER[junk]X 720p").unwrap();
+    assert_eq!(result.site, Some("trackerx".to_string()));
+}
+
+#[test]
+fn test_prefilter_rebuilt_after_adding_handlers() {
+    let mut parser = Parser::new();
+    parser.add_handler(site_handler(r"\btrackerx\b", "trackerx", false));
+    assert_eq!(parser.parse("Some Show TrackerY").unwrap().site, None);
+
+    parser.add_handler(site_handler(r"\btrackery\b", "trackery", false));
+    assert_eq!(parser.parse("Some Show TrackerY").unwrap().site, Some("trackery".to_string()));
+}
+
+#[test]
+fn test_prefilter_uppercase_title() {
+    // literals are compared lowercased, so shouting titles still reach the case insensitive patterns
+    let result = Parser::default()
+        .parse("THE.MATRIX.1999.2160P.UHD.BLURAY.X265.HDR10PLUS.DTS-HD.MA.5.1-GROUP")
+        .unwrap();
+    assert_eq!(result.title, "THE MATRIX");
+    assert_eq!(result.year, Some(1999));
+    assert_eq!(result.resolution, Some(Resolution::P2160));
+    assert_eq!(result.codec, Some(Codec::Hevc));
+    assert_eq!(result.hdr, vec![HdrFormat::Hdr10Plus]);
+    assert_eq!(result.audio, vec![AudioCodec::DtsLossless]);
+    assert_eq!(result.channels, vec![AudioChannels::FivePointOne]);
+}
+
+#[test]
+fn test_prefilter_non_ascii_case_folding() {
+    // case insensitive regexes match "ſ" (long s) for "s", so the prefilter must not rule that handler out
+    let with_prefilter = {
+        let mut parser = Parser::new();
+        parser.add_handler(site_handler(r"\btrackers", "trackers", false));
+        parser
+    };
+    let without_prefilter = {
+        let flags = Flags {
+            icase: true,
+            ..Default::default()
+        };
+        // a compiled regex has no source to take literals from, so it always runs
+        let mut parser = Parser::new();
+        parser.add_handler(Handler::from_regex(
+            "site",
+            |t| &mut t.site,
+            Regex::with_flags(r"\btrackers", flags).unwrap(),
+            transforms::value("trackers"),
+            RegexHandlerOptions::default(),
+        ));
+        parser
+    };
+
+    for title in ["Some Show Trackerſ 720p", "Some Show TRACKERS 720p", "Some Show Tracker 720p"] {
+        assert_eq!(
+            with_prefilter.parse(title).unwrap(),
+            without_prefilter.parse(title).unwrap(),
+            "{}",
+            title
+        );
+    }
+    assert_eq!(
+        with_prefilter.parse("Some Show Trackerſ 720p").unwrap().site,
+        Some("trackers".to_string())
+    );
+}
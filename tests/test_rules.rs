@@ -0,0 +1,193 @@
+#![cfg(feature = "rules")]
+
+use torrent_title_parser::{Language, Parser, RuleError, RulePack};
+
+const PACK: &str = r#"
+[[rules]]
+id = "trkx-group"
+field = "group"
+pattern = '-\[(TrkX)\]$'
+remove = true
+
+[[rules]]
+id = "trkx-freeleech"
+field = "scene"
+pattern = '\bFLEECH\b'
+case_insensitive = true
+transform = "boolean"
+remove = true
+
+[[rules]]
+id = "vostfr-alias"
+field = "languages"
+pattern = '\bSTFR\b'
+transform = { uniq_concat = "fr" }
+skip_if_already_found = false
+remove = true
+
+[[rules]]
+id = "episode-range"
+field = "episodes"
+pattern = '\bTeile[ .](\d+-\d+)\b'
+transform = "range"
+remove = true
+
+[[rules]]
+id = "compact-date"
+field = "date"
+pattern = '\bD(\d{8})\b'
+transform = { date = ["%d%m%Y"] }
+remove = true
+"#;
+
+#[test]
+fn test_toml_rule_pack() {
+    let pack = RulePack::from_toml(PACK).unwrap();
+    assert_eq!(pack.rules.len(), 5);
+
+    let mut parser = Parser::with_default_handlers();
+    parser.add_rule_pack(&pack).unwrap();
+
+    let result = parser.parse("The.Matrix.1999.1080p.BluRay.fleech.STFR.x264-[TrkX]").unwrap();
+    assert_eq!(result.title, "The Matrix");
+    assert_eq!(result.group, Some("TrkX".to_string()));
+    assert!(result.scene);
+    assert_eq!(result.languages, vec![Language::French]);
+
+    let result = parser.parse("Some.Show.Teile.4-6.D15012024.720p.WEB.x264").unwrap();
+    assert_eq!(result.title, "Some Show");
+    assert_eq!(result.episodes, vec![4, 5, 6]);
+    assert_eq!(result.date, Some("2024-01-15".to_string()));
+}
+
+#[test]
+fn test_json_rule_pack() {
+    let json =
+        r#"{"rules": [{"id": "trkx-site", "field": "site", "pattern": "\\bTrkX\\b", "transform": {"value": "trackerx"}, "remove": true}]}"#;
+    let pack = RulePack::from_json(json).unwrap();
+
+    let mut parser = Parser::with_default_handlers();
+    parser.add_rule_pack(&pack).unwrap();
+    let result = parser.parse("The.Matrix.1999.1080p.BluRay.x264.TrkX").unwrap();
+    assert_eq!(result.site, Some("trackerx".to_string()));
+}
+
+#[test]
+fn test_invalid_rules_are_reported() {
+    let pack = RulePack::from_toml(
+        r#"
+        [[rules]]
+        id = "broken-pattern"
+        field = "group"
+        pattern = '(unclosed'
+
+        [[rules]]
+        id = "unknown-field"
+        field = "catalog"
+        pattern = 'x'
+
+        [[rules]]
+        id = "bad-language"
+        field = "languages"
+        pattern = 'x'
+        transform = { uniq_concat = "klingon" }
+
+        [[rules]]
+        id = "flag-value"
+        field = "group"
+        pattern = 'x'
+        transform = "boolean"
+
+        [[rules]]
+        id = "bad-placement"
+        field = "group"
+        pattern = 'x'
+        before = "nope"
+        "#,
+    )
+    .unwrap();
+
+    let mut parser = Parser::with_default_handlers();
+    let handler_count = parser.handler_names().count();
+    let errors = parser.add_rule_pack(&pack).unwrap_err();
+    assert_eq!(parser.handler_names().count(), handler_count);
+
+    let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+    assert_eq!(messages.len(), 5, "{:?}", messages);
+    assert!(matches!(&errors[0], RuleError::InvalidPattern { id, .. } if id == "broken-pattern"));
+    assert!(matches!(&errors[1], RuleError::UnknownField { id, .. } if id == "unknown-field"));
+    assert!(matches!(&errors[2], RuleError::InvalidValue { id, .. } if id == "bad-language"));
+    assert!(matches!(&errors[3], RuleError::UnsupportedTransform { id, .. } if id == "flag-value"));
+    assert!(matches!(&errors[4], RuleError::UnknownHandler { id, .. } if id == "bad-placement"));
+    assert!(messages[0].starts_with("rule broken-pattern:"));
+}
+
+#[test]
+fn test_before_and_after_conflict() {
+    let pack = RulePack::from_toml(
+        r#"
+        [[rules]]
+        id = "both-sides"
+        field = "group"
+        pattern = '\bTrkX\b'
+        before = "group"
+        after = "site"
+        "#,
+    )
+    .unwrap();
+
+    let mut parser = Parser::with_default_handlers();
+    let errors = parser.add_rule_pack(&pack).unwrap_err();
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert!(matches!(&errors[0], RuleError::ConflictingPlacement { id } if id == "both-sides"));
+}
+
+#[test]
+fn test_rules_run_in_declaration_order() {
+    let pack = RulePack::from_toml(
+        r#"
+        [[rules]]
+        id = "first"
+        field = "group"
+        pattern = '-(TrkX)$'
+        transform = { value = "FIRST" }
+
+        [[rules]]
+        id = "second"
+        field = "group"
+        pattern = '-(TrkX)$'
+        transform = { value = "SECOND" }
+
+        [[rules]]
+        id = "third"
+        field = "site"
+        pattern = '\bTrkX\b'
+        transform = { value = "trkx" }
+        after = "group"
+
+        [[rules]]
+        id = "fourth"
+        field = "site"
+        pattern = '\bTrkX\b'
+        transform = { value = "other" }
+        after = "group"
+        "#,
+    )
+    .unwrap();
+
+    let mut parser = Parser::with_default_handlers();
+    parser.add_rule_pack(&pack).unwrap();
+    let result = parser.parse("The.Matrix.1999.1080p.BluRay.x264-TrkX").unwrap();
+    assert_eq!(result.group, Some("FIRST".to_string()));
+    assert_eq!(result.site, Some("trkx".to_string()));
+
+    let names: Vec<&str> = parser.handler_names().collect();
+    let first_group = names.iter().position(|name| *name == "group").unwrap();
+    assert_eq!(names[first_group + 1], "group");
+}
+
+#[test]
+fn test_malformed_rule_pack() {
+    assert!(matches!(RulePack::from_toml("[[rules]]\nid = 1"), Err(RuleError::Format(_))));
+    assert!(matches!(RulePack::from_json("{"), Err(RuleError::Format(_))));
+}
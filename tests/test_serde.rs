@@ -0,0 +1,70 @@
+#![cfg(feature = "serde")]
+
+use torrent_title_parser::{parse_title, Codec, FieldValue, HdrFormat, Language, Network, ParsedTitle, Quality};
+
+#[test]
+fn test_serialize_parsed_title() {
+    let result = parse_title("The.Mandalorian.S01E06.1080p.DSNP.WEB-DL.DDP5.1.H.264-NTb").unwrap();
+    let json = serde_json::to_value(&result).unwrap();
+
+    assert_eq!(json["title"], "The Mandalorian");
+    assert_eq!(json["resolution"], "1080p");
+    assert_eq!(json["quality"], "WEB-DL");
+    assert_eq!(json["codec"], "avc");
+    assert_eq!(json["network"], "Disney");
+    assert_eq!(json["seasons"], serde_json::json!([1]));
+
+    // empty vecs, false booleans and missing values are omitted
+    let object = json.as_object().unwrap();
+    assert!(!object.contains_key("languages"));
+    assert!(!object.contains_key("trash"));
+    assert!(!object.contains_key("year"));
+}
+
+#[test]
+fn test_serde_round_trip() {
+    let parsed = ParsedTitle {
+        title: "Some Title".to_string(),
+        quality: Some(Quality::BluRayRemux),
+        codec: Some(Codec::Hevc),
+        hdr: vec![HdrFormat::DolbyVision(Some(5)), HdrFormat::Hdr],
+        network: Some(Network::AppleTV),
+        languages: vec![Language::English, Language::LatinAmericanSpanish, Language::Spanish],
+        is_3d: true,
+        ..Default::default()
+    };
+
+    let json = serde_json::to_string(&parsed).unwrap();
+    assert_eq!(
+        json,
+        r#"{"title":"Some Title","quality":"BluRay REMUX","hdr":["DV P5","HDR"],"codec":"hevc","languages":["en","es-419","es"],"3d":true,"network":"Apple TV"}"#
+    );
+    assert_eq!(serde_json::from_str::<ParsedTitle>(&json).unwrap(), parsed);
+}
+
+#[test]
+fn test_deserialize_unknown_value() {
+    assert!(serde_json::from_str::<Quality>(r#""NotAQuality""#).is_err());
+    assert!(serde_json::from_str::<Language>(r#""xx""#).is_err());
+}
+
+#[test]
+fn test_serde_extra_fields() {
+    let mut parsed = ParsedTitle {
+        title: "Some Title".to_string(),
+        ..Default::default()
+    };
+    parsed.extra.insert("catalog_id".to_string(), FieldValue::Int(4711));
+    parsed.extra.insert("freeleech".to_string(), FieldValue::Bool(true));
+    parsed.extra.insert("source".to_string(), FieldValue::Text("scene".to_string()));
+    parsed
+        .extra
+        .insert("tags".to_string(), FieldValue::List(vec!["a".to_string(), "b".to_string()]));
+
+    let json = serde_json::to_string(&parsed).unwrap();
+    assert_eq!(
+        json,
+        r#"{"title":"Some Title","extra":{"catalog_id":4711,"freeleech":true,"source":"scene","tags":["a","b"]}}"#
+    );
+    assert_eq!(serde_json::from_str::<ParsedTitle>(&json).unwrap(), parsed);
+}
@@ -0,0 +1,96 @@
+use torrent_title_parser::parse_title_detailed;
+
+#[test]
+fn test_field_spans() {
+    let test_case = "The.Matrix.1999.1080p.BluRay.x264";
+    let result = parse_title_detailed(test_case).unwrap();
+
+    let span_of = |field: &str| result.spans_for(field).next().map(|s| &test_case[s.span.clone()]);
+    assert_eq!(span_of("year"), Some("1999"));
+    assert_eq!(span_of("resolution"), Some("1080p"));
+    assert_eq!(span_of("quality"), Some("BluRay"));
+    assert_eq!(span_of("codec"), Some("x264"));
+    assert_eq!(&test_case[result.title_span.clone()], "The.Matrix.");
+}
+
+#[test]
+fn test_field_spans_after_removal() {
+    let test_cases = vec![
+        ("[SubsPlease] Frieren - 12 (1080p) [A1B2C3D4].mkv", "resolution", vec!["1080p"]),
+        (
+            "[SubsPlease] Frieren - 12 (1080p) [A1B2C3D4].mkv",
+            "episode_code",
+            vec!["[A1B2C3D4]"],
+        ),
+        ("Some_Show__S01E02_720p_HDTV_x264-GRP", "resolution", vec!["720p"]),
+        ("Some_Show__S01E02_720p_HDTV_x264-GRP", "group", vec!["-GRP"]),
+        ("Movie 2020 MULTi VFF ENG 1080p WEB-DL", "languages", vec!["ENG", "ENG", "VFF"]),
+    ];
+
+    for (test_case, field, expected) in test_cases {
+        let result = parse_title_detailed(test_case).unwrap();
+        let spans: Vec<&str> = result.spans_for(field).map(|s| &test_case[s.span.clone()]).collect();
+        assert_eq!(spans, expected, "Incorrect spans for {} in {}", field, test_case);
+    }
+}
+
+#[test]
+fn test_cleared_field_has_no_span() {
+    let result = parse_title_detailed("[Group] Show - 01 [1080p].mkv").unwrap();
+    assert_eq!(result.spans_for("group").next().is_some(), result.parsed.group.is_some());
+    assert!(result.spans_for("title").next().is_none());
+}
+
+#[test]
+fn test_empty_match_has_no_span() {
+    // scene is detected from a lookahead for "1080p" and ".WEB.", the match itself is empty
+    let result = parse_title_detailed("Some.Show.S01E01.1080p.WEB.h264-GRP").unwrap();
+    assert!(result.parsed.scene);
+    assert_eq!(result.spans_for("scene").count(), 0);
+    assert!(result.spans.iter().all(|span| !span.span.is_empty()));
+}
+
+#[test]
+fn test_leftover_tokens() {
+    let test_cases = vec![
+        ("Movie.Name.2020.1080p.BluRay.FOOBAR.x264-GRP", vec!["FOOBAR"]),
+        ("Show.S01E02.1080p.XYZW.WEB-DL.DDP5.1.H.264-NTb", vec!["XYZW"]),
+        ("The.Matrix.1999.1080p.BluRay.x264", vec![]),
+    ];
+
+    for (test_case, expected) in test_cases {
+        let result = parse_title_detailed(test_case).unwrap();
+        let leftovers: Vec<&str> = result.leftovers.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(leftovers, expected, "Incorrect leftovers for {}", test_case);
+        for token in &result.leftovers {
+            assert_eq!(&test_case[token.span.clone()], token.text);
+        }
+    }
+}
+
+#[test]
+fn test_title_span_after_leading_removal() {
+    let test_cases = vec![
+        (
+            "[HD-ELITE.NET] -  The.Art.Of.The.Steal.2014.DVDRip.XviD.Dual.Aud",
+            "The Art Of The Steal",
+            " -  The.Art.Of.The.Steal.",
+        ),
+        (
+            "[Mr. Kimiko] Oh My Goddess! - Vol. 7 [Kobo][2048px][CBZ]",
+            "Oh My Goddess!",
+            " Oh My Goddess! - ",
+        ),
+    ];
+
+    for (test_case, title, title_span) in test_cases {
+        let result = parse_title_detailed(test_case).unwrap();
+        assert_eq!(result.parsed.title, title, "Incorrect title for {}", test_case);
+        assert_eq!(
+            &test_case[result.title_span.clone()],
+            title_span,
+            "Incorrect title span for {}",
+            test_case
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use torrent_title_parser::{Handler, Parser, Pattern, RegexHandlerOptions, SkipReason};
+
+#[test]
+fn test_trace_matches() {
+    let parser = Parser::default();
+    let title = "The.Matrix.1999.1080p.BluRay.x264";
+    let (parsed, trace) = parser.parse_traced(title).unwrap();
+    assert_eq!(parsed, parser.parse(title).unwrap());
+    assert_eq!(trace.len(), parser.handler_names().count());
+    assert!(trace.iter().enumerate().all(|(index, step)| step.index == index));
+
+    let resolution = trace.iter().find(|step| step.name == "resolution" && step.matched).unwrap();
+    assert_eq!(resolution.raw_match.as_deref(), Some("1080p"));
+    assert_eq!(resolution.match_index, Some(16));
+    assert!(resolution.removed);
+    assert_eq!(resolution.title_after.as_deref(), Some("The.Matrix.1999..BluRay.x264"));
+    assert_eq!((resolution.end_of_title_before, resolution.end_of_title_after), (title.len(), 16));
+    assert!(resolution.end_of_title_moved());
+
+    let year = trace.iter().find(|step| step.name == "year" && step.matched).unwrap();
+    assert_eq!((year.end_of_title_before, year.end_of_title_after), (16, 11));
+}
+
+#[test]
+fn test_trace_skip_reasons() {
+    let (_, trace) = Parser::default().parse_traced("The.Matrix.1999.1080p.BluRay.x264").unwrap();
+    let first_match = trace.iter().position(|step| step.name == "resolution" && step.matched).unwrap();
+    assert!(trace[first_match + 1..]
+        .iter()
+        .filter(|step| step.name == "resolution")
+        .all(|step| step.skipped == Some(SkipReason::AlreadyFound)));
+
+    // a region tag at the very start is ignored because the handler has skip_if_first
+    let (parsed, trace) = Parser::default().parse_traced("R2 Movie 2009 DVDRip XviD").unwrap();
+    assert_eq!(parsed.region, None);
+    let region = trace.iter().find(|step| step.name == "region").unwrap();
+    assert_eq!(region.skipped, Some(SkipReason::SkipIfFirst));
+    assert!(!region.matched);
+}
+
+#[test]
+fn test_trace_transform_rejected() {
+    let mut parser = Parser::new();
+    parser.add_handler(Handler::from_regex(
+        "year",
+        |t| &mut t.year,
+        Pattern::new(r"\b(\d{4})\b").unwrap(),
+        |value: &str, _: &Option<i32>| value.parse().ok().filter(|year| *year > 1900).map(Some),
+        RegexHandlerOptions::default(),
+    ));
+
+    let (parsed, trace) = parser.parse_traced("Movie 1234").unwrap();
+    assert_eq!(parsed.year, None);
+    assert_eq!(trace[0].skipped, Some(SkipReason::TransformRejected));
+}
+
+#[test]
+fn test_trace_offsets_into_original() {
+    let title = "[HD-ELITE.NET] -  The.Art.Of.The.Steal.2014.DVDRip.XviD";
+    let (_, trace) = Parser::default().parse_traced(title).unwrap();
+
+    // year and quality are cut out before the codec runs, so it is found earlier in the working title than in the input
+    let codec = trace.iter().find(|step| step.name == "codec" && step.matched).unwrap();
+    assert_eq!(codec.match_index, Some(41));
+    assert_eq!(codec.span.clone().map(|span| &title[span]), Some("XviD"));
+
+    // cutting out the site in front of the title doesn't move its end
+    let site = trace.iter().find(|step| step.name == "site" && step.matched).unwrap();
+    assert!(site.removed);
+    assert_eq!((site.end_of_title_before, site.end_of_title_after), (39, 39));
+}